@@ -119,6 +119,7 @@ fn builtin_structs(path: &Path) -> anyhow::Result<()> {
     writeln!(structs_priv, "// This file is auto-generated from {}", file!())?;
     writeln!(structs_priv, "#include \"slint_builtin_structs.h\"")?;
     writeln!(structs_priv, "#include \"slint_enums_internal.h\"")?;
+    writeln!(structs_priv, "#include \"slint_point.h\"")?;
     writeln!(structs_priv, "namespace slint::cbindgen_private {{")?;
     writeln!(structs_priv, "enum class KeyEventType : uint8_t;")?;
     macro_rules! struct_file {
@@ -154,6 +155,7 @@ struct $Name:ident {
                     let pub_type = match stringify!($pub_type) {
                         "i32" => "int32_t",
                         "f32" | "Coord" => "float",
+                        "LogicalPosition" => "slint::LogicalPosition",
                         other => other,
                     };
                     writeln!(file, "    {} {};", pub_type, stringify!($pub_field))?;
@@ -219,7 +221,9 @@ fn default_config() -> cbindgen::Config {
             ("KeyEventArg".into(), "KeyEvent".into()),
             ("PointerEventArg".into(), "PointerEvent".into()),
             ("PointerScrollEventArg".into(), "PointerScrollEvent".into()),
+            ("DropEventArg".into(), "DropEvent".into()),
             ("PointArg".into(), "slint::LogicalPosition".into()),
+            ("StringArg".into(), "SharedString".into()),
             ("FloatArg".into(), "float".into()),
             ("IntArg".into(), "int".into()),
             ("MenuEntryArg".into(), "MenuEntry".into()),
@@ -291,9 +295,13 @@ fn gen_corelib(
         "TouchArea",
         "FocusScope",
         "SwipeGestureHandler",
+        "PinchGestureHandler",
+        "DragArea",
+        "DropArea",
         "Flickable",
         "SimpleText",
         "ComplexText",
+        "StyledText",
         "Path",
         "WindowItem",
         "TextInput",
@@ -329,6 +337,7 @@ fn gen_corelib(
         "PointerEventButton",
         "PointerEvent",
         "PointerScrollEvent",
+        "DropEvent",
         "Rect",
         "SortOrder",
         "BitmapFont",
@@ -381,7 +390,9 @@ fn gen_corelib(
         "KeyEventArg",
         "PointerEventArg",
         "PointerScrollEventArg",
+        "DropEventArg",
         "PointArg",
+        "StringArg",
         "Point",
         "MenuEntryModel",
         "MenuEntryArg",
@@ -397,6 +408,7 @@ fn gen_corelib(
         "slint_image_load_from_path",
         "slint_image_load_from_embedded_data",
         "slint_image_from_embedded_textures",
+        "slint_image_from_embedded_texture_frames",
         "slint_image_compare_equal",
         "slint_image_set_nine_slice_edges",
         "slint_image_to_rgb8",
@@ -440,7 +452,9 @@ fn gen_corelib(
         .with_config(string_config)
         .with_src(crate_dir.join("string.rs"))
         .with_src(crate_dir.join("slice.rs"))
-        .with_after_include("namespace slint { struct SharedString; }")
+        .with_after_include(
+            "namespace slint { struct SharedString; template<typename T> struct SharedVector; }",
+        )
         .generate()
         .context("Unable to generate bindings for slint_string_internal.h")?
         .write_to_file(include_dir.join("slint_string_internal.h"));
@@ -511,6 +525,7 @@ fn gen_corelib(
                 "slint_image_load_from_path",
                 "slint_image_load_from_embedded_data",
                 "slint_image_from_embedded_textures",
+                "slint_image_from_embedded_texture_frames",
                 "slint_image_compare_equal",
                 "slint_image_set_nine_slice_edges",
                 "slint_image_to_rgb8",
@@ -523,7 +538,7 @@ fn gen_corelib(
             ],
             vec!["Color"],
             "slint_image_internal.h",
-            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; using namespace vtable; namespace types{ struct NineSliceImage{}; } }",
+            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; using namespace vtable; namespace types{ struct NineSliceImage{}; struct AnimatedImage{}; } }",
         ),
         (
             vec!["Color", "slint_color_brighter", "slint_color_darker",
@@ -543,7 +558,7 @@ fn gen_corelib(
             "",
         ),
         (
-            vec!["Brush", "LinearGradient", "GradientStop", "RadialGradient"],
+            vec!["Brush", "LinearGradient", "GradientStop", "RadialGradient", "ConicGradient"],
             vec!["Color"],
             "slint_brush_internal.h",
             "",
@@ -581,6 +596,7 @@ fn gen_corelib(
             "slint_windowrc_supports_native_menu_bar",
             "slint_windowrc_setup_native_menu_bar",
             "slint_windowrc_default_font_size",
+            "slint_windowrc_layout_direction",
             "slint_windowrc_dispatch_pointer_event",
             "slint_windowrc_dispatch_key_event",
             "slint_windowrc_dispatch_event",
@@ -609,6 +625,7 @@ fn gen_corelib(
             "slint_image_to_rgba8",
             "slint_image_to_rgba8_premultiplied",
             "slint_image_from_embedded_textures",
+            "slint_image_from_embedded_texture_frames",
             "slint_image_compare_equal",
         ]
         .iter()
@@ -98,6 +98,58 @@ private:
     }
 };
 
+/// \private
+/// ConicGradientBrush represents a gradient where the colors are swept around a center point,
+/// starting at a specific angle.
+class ConicGradientBrush
+{
+public:
+    /// Constructs an empty conic gradient with no color stops.
+    ConicGradientBrush() = default;
+    /// Constructs a new conic gradient starting at \a from_angle and centered at \a center_x and
+    /// \a center_y, expressed as a fraction of the item's size. The color stops will be
+    /// constructed from the stops array pointed to be \a firstStop, with the length \a stopCount.
+    ConicGradientBrush(float from_angle, float center_x, float center_y,
+                       const GradientStop *firstStop, int stopCount)
+        : inner(make_conic_gradient(from_angle, center_x, center_y, firstStop, stopCount))
+    {
+    }
+
+    /// Returns the angle in degrees at which the gradient starts.
+    float from_angle() const
+    {
+        // The gradient's first three stops are fake stops to store the angle and the center
+        return inner[0].position;
+    }
+
+    /// Returns the number of gradient stops.
+    int stopCount() const { return int(inner.size()) - 3; }
+
+    /// Returns a pointer to the first gradient stop; undefined if the gradient has not stops.
+    const GradientStop *stopsBegin() const { return inner.begin() + 3; }
+    /// Returns a pointer past the last gradient stop. The returned pointer cannot be dereferenced,
+    /// it can only be used for comparison.
+    const GradientStop *stopsEnd() const { return inner.end(); }
+
+private:
+    cbindgen_private::types::ConicGradientBrush inner;
+
+    friend class slint::Brush;
+
+    static SharedVector<private_api::GradientStop>
+    make_conic_gradient(float from_angle, float center_x, float center_y,
+                        const GradientStop *firstStop, int stopCount)
+    {
+        SharedVector<private_api::GradientStop> gradient;
+        gradient.push_back({ Color::from_argb_encoded(0).inner, from_angle });
+        gradient.push_back({ Color::from_argb_encoded(0).inner, center_x });
+        gradient.push_back({ Color::from_argb_encoded(0).inner, center_y });
+        for (int i = 0; i < stopCount; ++i, ++firstStop)
+            gradient.push_back(*firstStop);
+        return gradient;
+    }
+};
+
 }
 
 /// Brush is used to declare how to fill or outline shapes, such as rectangles, paths or text. A
@@ -123,6 +175,13 @@ public:
     {
     }
 
+    /// \private
+    /// Constructs a new brush that is the gradient \a gradient.
+    Brush(const private_api::ConicGradientBrush &gradient)
+        : data(Inner::ConicGradient(gradient.inner))
+    {
+    }
+
     /// Returns the color of the brush. If the brush is a gradient, this function returns the color
     /// of the first stop.
     inline Color color() const;
@@ -177,6 +236,11 @@ Color Brush::color() const
             result.inner = data.radial_gradient._0[0].color;
         }
         break;
+    case Tag::ConicGradient:
+        if (data.conic_gradient._0.size() > 3) {
+            result.inner = data.conic_gradient._0[3].color;
+        }
+        break;
     }
     return result;
 }
@@ -201,6 +265,12 @@ inline Brush Brush::brighter(float factor) const
                                                           &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 3; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_brighter(&data.conic_gradient._0[i].color, factor,
+                                                          &result.data.conic_gradient._0[i].color);
+        }
+        break;
     }
     return result;
 }
@@ -225,6 +295,12 @@ inline Brush Brush::darker(float factor) const
                                                         &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 3; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_darker(&data.conic_gradient._0[i].color, factor,
+                                                        &result.data.conic_gradient._0[i].color);
+        }
+        break;
     }
     return result;
 }
@@ -251,6 +327,13 @@ inline Brush Brush::transparentize(float factor) const
                     &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 3; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_transparentize(
+                    &data.conic_gradient._0[i].color, factor,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
     }
     return result;
 }
@@ -277,6 +360,13 @@ inline Brush Brush::with_alpha(float alpha) const
                     &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 3; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_with_alpha(
+                    &data.conic_gradient._0[i].color, alpha,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
     }
     return result;
 }
@@ -285,11 +375,13 @@ namespace private_api {
 
 template<>
 inline void Property<slint::Brush>::set_animated_value(
-        const slint::Brush &new_value,
-        const cbindgen_private::PropertyAnimation &animation_data) const
+        const slint::Brush &new_value, const cbindgen_private::PropertyAnimation &animation_data,
+        std::initializer_list<float> keyframe_offsets,
+        std::initializer_list<slint::Brush> keyframe_values) const
 {
-    cbindgen_private::slint_property_set_animated_value_brush(&inner, &value, &new_value,
-                                                              &animation_data);
+    cbindgen_private::slint_property_set_animated_value_brush(
+            &inner, &value, &new_value, &animation_data, keyframe_offsets.begin(),
+            keyframe_values.begin(), keyframe_values.size());
 }
 
 } // namespace private_api
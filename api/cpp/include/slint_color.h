@@ -12,6 +12,7 @@ namespace slint {
 
 namespace private_api {
 class LinearGradientBrush;
+class ConicGradientBrush;
 }
 
 class Color;
@@ -217,6 +218,7 @@ public:
 private:
     cbindgen_private::types::Color inner;
     friend class private_api::LinearGradientBrush;
+    friend class private_api::ConicGradientBrush;
     friend class Brush;
 };
 
@@ -288,12 +290,14 @@ RgbaColor<float> Color::to_argb_float() const
 namespace private_api {
 
 template<>
-inline void
-Property<Color>::set_animated_value(const Color &new_value,
-                                    const cbindgen_private::PropertyAnimation &animation_data) const
+inline void Property<Color>::set_animated_value(
+        const Color &new_value, const cbindgen_private::PropertyAnimation &animation_data,
+        std::initializer_list<float> keyframe_offsets,
+        std::initializer_list<Color> keyframe_values) const
 {
-    cbindgen_private::slint_property_set_animated_value_color(&inner, value, new_value,
-                                                              &animation_data);
+    cbindgen_private::slint_property_set_animated_value_color(
+            &inner, value, new_value, &animation_data, keyframe_offsets.begin(),
+            keyframe_values.begin(), keyframe_values.size());
 }
 
 } // namespace private_api
@@ -299,6 +299,15 @@ inline Image image_from_embedded_textures(const cbindgen_private::types::StaticT
     cbindgen_private::types::slint_image_from_embedded_textures(textures, &img);
     return Image(img);
 }
+
+inline Image image_from_embedded_texture_frames(
+        cbindgen_private::Slice<cbindgen_private::types::StaticTextures> frames,
+        cbindgen_private::Slice<uint32_t> durations)
+{
+    cbindgen_private::types::Image img(cbindgen_private::types::Image::ImageInner_None());
+    cbindgen_private::types::slint_image_from_embedded_texture_frames(frames, durations, &img);
+    return Image(img);
+}
 }
 
 }
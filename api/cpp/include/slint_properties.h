@@ -4,6 +4,7 @@
 #pragma once
 #include <string_view>
 #include <memory>
+#include <initializer_list>
 
 namespace slint::cbindgen_private {
 struct PropertyAnimation;
@@ -24,40 +25,48 @@ inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, int *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
+        const float *keyframe_offsets, const int *keyframe_values, uintptr_t keyframe_count,
         cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
 {
     cbindgen_private::slint_property_set_animated_binding_int(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+            handle, binding, user_data, drop_user_data, animation_data, keyframe_offsets,
+            keyframe_values, keyframe_count, transition_data);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, float *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
+        const float *keyframe_offsets, const float *keyframe_values, uintptr_t keyframe_count,
         cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
 {
     cbindgen_private::slint_property_set_animated_binding_float(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+            handle, binding, user_data, drop_user_data, animation_data, keyframe_offsets,
+            keyframe_values, keyframe_count, transition_data);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, Color *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
+        const float *keyframe_offsets, const Color *keyframe_values, uintptr_t keyframe_count,
         cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
 {
     cbindgen_private::slint_property_set_animated_binding_color(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+            handle, binding, user_data, drop_user_data, animation_data, keyframe_offsets,
+            keyframe_values, keyframe_count, transition_data);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, Brush *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
+        const float *keyframe_offsets, const Brush *keyframe_values, uintptr_t keyframe_count,
         cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
 {
     cbindgen_private::slint_property_set_animated_binding_brush(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+            handle, binding, user_data, drop_user_data, animation_data, keyframe_offsets,
+            keyframe_values, keyframe_count, transition_data);
 }
 
 template<typename T>
@@ -104,11 +113,17 @@ struct Property
                 nullptr, nullptr);
     }
 
+    /// The animation goes through the keyframes, if any. keyframe_offsets and keyframe_values
+    /// must have the same size.
     inline void set_animated_value(const T &value,
-                                   const cbindgen_private::PropertyAnimation &animation_data) const;
+                                   const cbindgen_private::PropertyAnimation &animation_data,
+                                   std::initializer_list<float> keyframe_offsets = {},
+                                   std::initializer_list<T> keyframe_values = {}) const;
     template<typename F>
-    inline void
-    set_animated_binding(F binding, const cbindgen_private::PropertyAnimation &animation_data) const
+    inline void set_animated_binding(F binding,
+                                     const cbindgen_private::PropertyAnimation &animation_data,
+                                     std::initializer_list<float> keyframe_offsets = {},
+                                     std::initializer_list<T> keyframe_values = {}) const
     {
         private_api::slint_property_set_animated_binding_helper(
                 &inner,
@@ -116,7 +131,8 @@ struct Property
                     *reinterpret_cast<T *>(value) = (*reinterpret_cast<F *>(user_data))();
                 },
                 new F(binding), [](void *user_data) { delete reinterpret_cast<F *>(user_data); },
-                &animation_data, nullptr);
+                &animation_data, keyframe_offsets.begin(), keyframe_values.begin(),
+                keyframe_values.size(), nullptr);
     }
 
     template<typename F, typename Trans>
@@ -135,7 +151,7 @@ struct Property
                 },
                 new UserData { binding, animation },
                 [](void *user_data) { delete reinterpret_cast<UserData *>(user_data); }, nullptr,
-                [](void *user_data, uint64_t *instant) {
+                nullptr, nullptr, 0, [](void *user_data, uint64_t *instant) {
                     return reinterpret_cast<UserData *>(user_data)->animation(instant);
                 });
     }
@@ -197,19 +213,24 @@ private:
 
 template<>
 inline void Property<int32_t>::set_animated_value(
-        const int32_t &new_value, const cbindgen_private::PropertyAnimation &animation_data) const
+        const int32_t &new_value, const cbindgen_private::PropertyAnimation &animation_data,
+        std::initializer_list<float> keyframe_offsets,
+        std::initializer_list<int32_t> keyframe_values) const
 {
-    cbindgen_private::slint_property_set_animated_value_int(&inner, value, new_value,
-                                                            &animation_data);
+    cbindgen_private::slint_property_set_animated_value_int(
+            &inner, value, new_value, &animation_data, keyframe_offsets.begin(),
+            keyframe_values.begin(), keyframe_values.size());
 }
 
 template<>
-inline void
-Property<float>::set_animated_value(const float &new_value,
-                                    const cbindgen_private::PropertyAnimation &animation_data) const
+inline void Property<float>::set_animated_value(
+        const float &new_value, const cbindgen_private::PropertyAnimation &animation_data,
+        std::initializer_list<float> keyframe_offsets,
+        std::initializer_list<float> keyframe_values) const
 {
-    cbindgen_private::slint_property_set_animated_value_float(&inner, value, new_value,
-                                                              &animation_data);
+    cbindgen_private::slint_property_set_animated_value_float(
+            &inner, value, new_value, &animation_data, keyframe_offsets.begin(),
+            keyframe_values.begin(), keyframe_values.size());
 }
 
 template<typename F>
@@ -266,6 +266,11 @@ public:
         return cbindgen_private::slint_windowrc_default_font_size(&inner);
     }
 
+    inline cbindgen_private::LayoutDirection layout_direction() const
+    {
+        return cbindgen_private::slint_windowrc_layout_direction(&inner);
+    }
+
     /// \private
     const cbindgen_private::WindowAdapterRcOpaque &handle() const { return inner; }
 
@@ -571,6 +576,55 @@ public:
         inner.dispatch_pointer_event(event);
     }
 
+    /// Dispatches a touch press event to the scene.
+    ///
+    /// Use this function when you're implementing your own backend and want to forward touch
+    /// events. Unlike the pointer events, touch events can be dispatched for several touch points
+    /// at the same time, to recognize gestures such as pinching.
+    ///
+    /// \a touch_id identifies the touch point until it is released.
+    /// \a pos represents the logical position of the touch point relative to the window.
+    void dispatch_touch_press_event(uint64_t touch_id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_pressed = WindowEvent::TouchPressed_Body {
+                                    .tag = WindowEvent::Tag::TouchPressed,
+                                    .touch_id = touch_id,
+                                    .position = pos } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
+    /// Dispatches a touch move event to the scene.
+    ///
+    /// \a touch_id is the identifier of the touch point passed to dispatch_touch_press_event().
+    /// \a pos represents the logical position of the touch point relative to the window.
+    void dispatch_touch_move_event(uint64_t touch_id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_moved = WindowEvent::TouchMoved_Body {
+                                    .tag = WindowEvent::Tag::TouchMoved,
+                                    .touch_id = touch_id,
+                                    .position = pos } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
+    /// Dispatches a touch release event to the scene.
+    ///
+    /// \a touch_id is the identifier of the touch point passed to dispatch_touch_press_event().
+    /// \a pos represents the logical position of the touch point relative to the window.
+    void dispatch_touch_release_event(uint64_t touch_id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_released = WindowEvent::TouchReleased_Body {
+                                    .tag = WindowEvent::Tag::TouchReleased,
+                                    .touch_id = touch_id,
+                                    .position = pos } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
     /// Set the logical size of this window after a resize event
     ///
     /// The backend must send this event to ensure that the `width` and `height` property of the
@@ -297,6 +297,15 @@ pub fn to_string(&self) -> String {
             Brush::RadialGradient(gradient) => {
                 format!("radial-gradient(circle, {})", gradient_stops_to_string(gradient.stops()))
             }
+            Brush::ConicGradient(gradient) => {
+                format!(
+                    "conic-gradient(from {}deg at {}% {}%, {})",
+                    gradient.from_angle(),
+                    gradient.center().x * 100.,
+                    gradient.center().y * 100.,
+                    gradient_stops_to_string(gradient.stops())
+                )
+            }
             _ => String::default(),
         }
     }
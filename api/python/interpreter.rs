@@ -280,7 +280,8 @@ fn from(value: slint_interpreter::ValueType) -> Self {
             slint_interpreter::ValueType::Struct => PyValueType::Struct,
             slint_interpreter::ValueType::Brush => PyValueType::Brush,
             slint_interpreter::ValueType::Image => PyValueType::Image,
-            _ => unimplemented!(),
+            // Like enumerations, the types without a value type of their own are reported as Void
+            _ => PyValueType::Void,
         }
     }
 }
@@ -0,0 +1,61 @@
+# This file is auto-generated by the Slint compiler from generated-module.slint. Do not edit.
+
+from __future__ import annotations
+
+import enum
+import pathlib
+import typing
+
+import slint
+
+_module = slint.load_file(pathlib.Path(__file__).parent / "generated-module.slint")
+
+
+class Direction(str, enum.Enum):
+    __slint_enum__ = "Direction"
+    left = "left"
+    top_right = "top-right"
+
+
+if typing.TYPE_CHECKING:
+    class Point:
+        x: float
+        y: float
+
+        def __init__(self, *, x: float = ..., y: float = ...) -> None: ...
+
+    class Logic:
+        @property
+        def counter(self) -> float: ...
+        @counter.setter
+        def counter(self, value: float) -> None: ...
+
+    _Logic_global = Logic
+
+    class App(slint.Component):
+        def __init__(self, **kwargs: typing.Any) -> None: ...
+        @property
+        def direction(self) -> Direction: ...
+        @direction.setter
+        def direction(self, value: Direction) -> None: ...
+        def direction_name(self) -> str: ...
+        @property
+        def is_left(self) -> bool: ...
+        @property
+        def number(self) -> float: ...
+        @number.setter
+        def number(self, value: float) -> None: ...
+        @property
+        def point(self) -> Point: ...
+        @point.setter
+        def point(self, value: Point) -> None: ...
+        @property
+        def text(self) -> str: ...
+        @text.setter
+        def text(self, value: str) -> None: ...
+        @property
+        def Logic(self) -> _Logic_global: ...
+
+else:
+    Point = getattr(_module, "Point")
+    App = getattr(_module, "App")
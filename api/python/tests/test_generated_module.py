@@ -0,0 +1,67 @@
+# Copyright © SixtyFPS GmbH <info@slint.dev>
+# SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+import enum
+import http
+import importlib.util
+import types
+from pathlib import Path
+
+
+def base_dir() -> Path:
+    origin = __spec__.origin
+    assert origin is not None
+    base_dir = Path(origin).parent
+    assert base_dir is not None
+    return base_dir
+
+
+def load_generated_module() -> types.ModuleType:
+    spec = importlib.util.spec_from_file_location(
+        "generated_module", base_dir() / "generated_module.py"
+    )
+    assert spec is not None and spec.loader is not None
+    module = importlib.util.module_from_spec(spec)
+    spec.loader.exec_module(module)
+    return module
+
+
+def test_generated_module() -> None:
+    module = load_generated_module()
+    instance = module.App()
+
+    assert instance.direction == module.Direction.top_right
+    assert instance.direction_name() == "top-right"
+    instance.direction = module.Direction.left
+    assert instance.direction == module.Direction.left
+    assert instance.direction != module.Direction.top_right
+    assert instance.is_left
+    assert instance.direction_name() == "left"
+
+    assert instance.point.x == 1
+    instance.point = module.Point(x=3, y=4)
+    assert instance.point.x == 3
+    assert instance.point.y == 4
+
+    instance.Logic.counter = 5
+    assert instance.Logic.counter == 5
+
+
+class Size(enum.IntEnum):
+    big = 100
+
+
+class Greeting(enum.StrEnum):
+    hello = "hello world"
+
+
+def test_other_enums() -> None:
+    instance = load_generated_module().App()
+
+    instance.number = http.HTTPStatus.OK
+    assert instance.number == 200
+    instance.number = Size.big
+    assert instance.number == 100
+
+    instance.text = Greeting.hello
+    assert instance.text == "hello world"
@@ -37,6 +37,7 @@ fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
             slint_interpreter::Value::Brush(brush) => {
                 crate::brush::PyBrush::from(brush.clone()).into_bound_py_any(py)
             }
+            slint_interpreter::Value::EnumerationValue(_, value) => value.into_bound_py_any(py),
             v @ _ => {
                 eprintln!("Python: conversion from slint to python needed for {v:#?} and not implemented yet");
                 ().into_bound_py_any(py)
@@ -51,6 +52,14 @@ fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
             return Ok(slint_interpreter::Value::Void.into());
         }
 
+        // The enums of the generated Python modules carry the name of the Slint enum. The members
+        // of other enums, like `IntEnum` and `StrEnum`, go through the conversions below.
+        if let Ok(name) = ob.get_type().getattr("__slint_enum__") {
+            let name = name.extract::<String>()?;
+            let value = ob.getattr("value")?.extract::<String>()?;
+            return Ok(slint_interpreter::Value::EnumerationValue(name, value).into());
+        }
+
         let interpreter_val = ob
             .extract::<bool>()
             .map(|b| slint_interpreter::Value::Bool(b))
@@ -61,6 +61,7 @@ fn main() {
 #[derive(Clone)]
 pub struct CompilerConfiguration {
     config: i_slint_compiler::CompilerConfiguration,
+    statistics_path: Option<std::path::PathBuf>,
 }
 
 /// How should the slint compiler embed images and fonts
@@ -84,6 +85,7 @@ fn default() -> Self {
             config: i_slint_compiler::CompilerConfiguration::new(
                 i_slint_compiler::generator::OutputFormat::Rust,
             ),
+            statistics_path: None,
         }
     }
 }
@@ -100,7 +102,7 @@ pub fn new() -> Self {
     pub fn with_include_paths(self, include_paths: Vec<std::path::PathBuf>) -> Self {
         let mut config = self.config;
         config.include_paths = include_paths;
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Create a new configuration that sets the library paths used for looking up
@@ -133,7 +135,7 @@ pub fn with_include_paths(self, include_paths: Vec<std::path::PathBuf>) -> Self
     pub fn with_library_paths(self, library_paths: HashMap<String, std::path::PathBuf>) -> Self {
         let mut config = self.config;
         config.library_paths = library_paths;
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Create a new configuration that selects the style to be used for widgets.
@@ -141,7 +143,7 @@ pub fn with_library_paths(self, library_paths: HashMap<String, std::path::PathBu
     pub fn with_style(self, style: String) -> Self {
         let mut config = self.config;
         config.style = Some(style);
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Selects how the resources such as images and font are processed.
@@ -161,7 +163,7 @@ pub fn embed_resources(self, kind: EmbedResourcesKind) -> Self {
                 i_slint_compiler::EmbedResourcesKind::EmbedTextures
             }
         };
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Sets the scale factor to be applied to all `px` to `phx` conversions
@@ -172,7 +174,32 @@ pub fn embed_resources(self, kind: EmbedResourcesKind) -> Self {
     pub fn with_scale_factor(self, factor: f32) -> Self {
         let mut config = self.config;
         config.const_scale_factor = factor as f64;
-        Self { config }
+        Self { config, ..self }
+    }
+
+    /// Configures the compiler to warn about unused private properties, functions, callbacks,
+    /// struct fields and imports.
+    ///
+    /// This is the equivalent to setting `SLINT_WARN_UNUSED=1`. Add a `// slint: allow(unused)`
+    /// comment before a declaration, an element, or a component to silence these warnings.
+    #[must_use]
+    pub fn with_unused_warnings(self, enable: bool) -> Self {
+        let mut config = self.config;
+        config.warn_unused = enable;
+        Self { config, ..self }
+    }
+
+    /// Configures the compiler to write a JSON report to the given path, with the number of items,
+    /// properties, bindings, repeaters and inlined sub-components of each component, the size of
+    /// the embedded resources, and the time spent in each compiler pass.
+    ///
+    /// This is the equivalent of `slint-compiler --stats` and helps tracking the size of the
+    /// generated code and the compilation time in CI.
+    #[must_use]
+    pub fn with_statistics_report(self, path: impl Into<std::path::PathBuf>) -> Self {
+        let mut config = self.config;
+        config.collect_statistics = true;
+        Self { config, statistics_path: Some(path.into()) }
     }
 
     /// Configures the compiler to bundle translations when compiling Slint code.
@@ -188,7 +215,7 @@ pub fn with_bundled_translations(
     ) -> CompilerConfiguration {
         let mut config = self.config;
         config.translation_path_bundle = Some(path.into());
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Configures the compiler to emit additional debug info when compiling Slint code.
@@ -200,7 +227,7 @@ pub fn with_bundled_translations(
     pub fn with_debug_info(self, enable: bool) -> Self {
         let mut config = self.config;
         config.debug_info = enable;
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Configures the compiler to use Signed Distance Field (SDF) encoding for fonts.
@@ -218,7 +245,7 @@ pub fn with_debug_info(self, enable: bool) -> Self {
     pub fn with_sdf_fonts(self, enable: bool) -> Self {
         let mut config = self.config;
         config.use_sdf_fonts = enable;
-        Self { config }
+        Self { config, ..self }
     }
 }
 
@@ -472,6 +499,7 @@ pub fn compile_with_output_path(
         return Err(CompileError::CompileError(vec));
     }
 
+    let statistics_path = config.statistics_path;
     let mut compiler_config = config.config;
     compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
 
@@ -509,6 +537,11 @@ pub fn compile_with_output_path(
     });
 
     write!(code_formatter, "{generated}").map_err(CompileError::SaveError)?;
+
+    if let Some(statistics_path) = statistics_path {
+        let report = i_slint_compiler::statistics::Statistics::collect(&doc).to_json();
+        std::fs::write(statistics_path, report).map_err(CompileError::SaveError)?;
+    }
     dependencies.push(input_slint_file_path.as_ref().to_path_buf());
 
     for resource in doc.embedded_file_resources.borrow().keys() {
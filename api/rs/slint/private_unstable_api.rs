@@ -80,6 +80,24 @@ pub fn set_animated_property_binding<
     )
 }
 
+pub fn set_animated_property_binding_with_keyframes<
+    T: Clone + i_slint_core::properties::InterpolatedPropertyValue + 'static,
+    StrongRef: StrongItemTreeRef + 'static,
+>(
+    property: Pin<&Property<T>>,
+    component_strong: &StrongRef,
+    binding: fn(StrongRef) -> T,
+    animation_data: PropertyAnimation,
+    keyframes: impl IntoIterator<Item = i_slint_core::properties::Keyframe<T>>,
+) {
+    let weak = component_strong.to_weak();
+    property.set_animated_binding_with_keyframes(
+        move || binding(<StrongRef as StrongItemTreeRef>::from_weak(&weak).unwrap()),
+        animation_data,
+        keyframes,
+    )
+}
+
 pub fn set_animated_property_binding_for_transition<
     T: Clone + i_slint_core::properties::InterpolatedPropertyValue + 'static,
     StrongRef: StrongItemTreeRef + 'static,
@@ -194,7 +212,7 @@ pub mod re_exports {
     pub use i_slint_core::graphics::*;
     pub use i_slint_core::input::{
         key_codes::Key, FocusEvent, FocusReason, InputEventResult, KeyEvent, KeyEventResult,
-        KeyboardModifiers, MouseEvent,
+        KeyEventType, KeyboardModifiers, MouseEvent,
     };
     pub use i_slint_core::item_tree::{
         register_item_tree, unregister_item_tree, IndexRange, ItemTree, ItemTreeRefPin,
@@ -212,12 +230,16 @@ pub mod re_exports {
     pub use i_slint_core::menus::{Menu, MenuFromItemTree, MenuVTable};
     pub use i_slint_core::model::*;
     pub use i_slint_core::properties::{
-        set_state_binding, ChangeTracker, Property, PropertyTracker, StateInfo,
+        set_state_binding, ChangeTracker, Keyframe, Property, PropertyTracker, StateInfo,
     };
     pub use i_slint_core::slice::Slice;
     pub use i_slint_core::string::shared_string_from_number;
     pub use i_slint_core::string::shared_string_from_number_fixed;
     pub use i_slint_core::string::shared_string_from_number_precision;
+    pub use i_slint_core::string::{
+        shared_string_find, shared_string_pad, shared_string_replace, shared_string_split,
+        shared_string_substring,
+    };
     pub use i_slint_core::timers::{Timer, TimerMode};
     pub use i_slint_core::translations::{
         set_bundled_languages, translate_from_bundle, translate_from_bundle_with_plural,
@@ -302,8 +302,12 @@ function getHelpUrlForElement(elementName: string): string | null {
         Path: "elements/path",
         Text: "elements/text",
         Rectangle: "elements/rectangle",
+        StyledText: "elements/styledtext",
         // gestures
+        DragArea: "gestures/dragarea",
+        DropArea: "gestures/droparea",
         Flickable: "gestures/flickable",
+        PinchGestureHandler: "gestures/pinchgesturehandler",
         SwipeGestureHandler: "gestures/swipegesturehandler",
         TouchArea: "gestures/toucharea",
         // keyboard-input
@@ -190,6 +190,19 @@ pub fn set_imm_data(
                     env.get_static_field(&class_it, "TYPE_CLASS_NUMBER", "I")?.i()?
                         | env.get_static_field(&class_it, "TYPE_NUMBER_FLAG_DECIMAL", "I")?.i()?
                 }
+                InputType::Email => {
+                    env.get_static_field(&class_it, "TYPE_CLASS_TEXT", "I")?.i()?
+                        | env
+                            .get_static_field(&class_it, "TYPE_TEXT_VARIATION_EMAIL_ADDRESS", "I")?
+                            .i()?
+                }
+                InputType::Url => {
+                    env.get_static_field(&class_it, "TYPE_CLASS_TEXT", "I")?.i()?
+                        | env.get_static_field(&class_it, "TYPE_TEXT_VARIATION_URI", "I")?.i()?
+                }
+                InputType::Phone => {
+                    env.get_static_field(&class_it, "TYPE_CLASS_PHONE", "I")?.i()?
+                }
                 _ => 0 as jint,
             };
             env.delete_local_ref(class_it)?;
@@ -4,7 +4,6 @@
 //! This module contains the code to receive input events from libinput
 
 use std::cell::RefCell;
-#[cfg(feature = "libseat")]
 use std::collections::HashMap;
 #[cfg(not(feature = "libseat"))]
 use std::fs::{File, OpenOptions};
@@ -24,7 +23,7 @@
 use input::LibinputInterface;
 
 use input::event::keyboard::{KeyState, KeyboardEventTrait};
-use input::event::touch::TouchEventPosition;
+use input::event::touch::{TouchEventPosition, TouchEventSlot};
 use xkbcommon::*;
 
 use crate::fullscreenwindowadapter::FullscreenWindowAdapter;
@@ -115,7 +114,8 @@ pub struct LibInputHandler<'a> {
     libinput: input::Libinput,
     token: Option<calloop::Token>,
     mouse_pos: Pin<Rc<Property<Option<LogicalPosition>>>>,
-    last_touch_pos: LogicalPosition,
+    /// The last position of each pressed touch point, by libinput seat slot
+    touch_positions: HashMap<u64, LogicalPosition>,
     window: &'a RefCell<Option<Rc<FullscreenWindowAdapter>>>,
     keystate: Option<xkb::State>,
 }
@@ -137,7 +137,7 @@ pub fn init<T>(
             libinput,
             token: Default::default(),
             mouse_pos: mouse_pos_property.clone(),
-            last_touch_pos: Default::default(),
+            touch_positions: Default::default(),
             window,
             keystate: Default::default(),
         };
@@ -234,25 +234,34 @@ fn process_events<F>(
                 input::Event::Touch(touch_event) => {
                     if let Some(event) = match touch_event {
                         input::event::TouchEvent::Down(touch_down_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
+                            let touch_id = touch_down_event.seat_slot() as u64;
+                            let position = LogicalPosition::new(
                                 touch_down_event.x_transformed(screen_size.width as u32) as _,
                                 touch_down_event.y_transformed(screen_size.height as u32) as _,
                             );
-                            Some(WindowEvent::PointerPressed {
-                                position: self.last_touch_pos,
-                                button: PointerEventButton::Left,
-                            })
+                            self.touch_positions.insert(touch_id, position);
+                            Some(WindowEvent::TouchPressed { touch_id, position })
+                        }
+                        input::event::TouchEvent::Up(touch_up_event) => {
+                            let touch_id = touch_up_event.seat_slot() as u64;
+                            self.touch_positions
+                                .remove(&touch_id)
+                                .map(|position| WindowEvent::TouchReleased { touch_id, position })
                         }
-                        input::event::TouchEvent::Up(..) => Some(WindowEvent::PointerReleased {
-                            position: self.last_touch_pos,
-                            button: PointerEventButton::Left,
-                        }),
                         input::event::TouchEvent::Motion(touch_motion_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
+                            let touch_id = touch_motion_event.seat_slot() as u64;
+                            let position = LogicalPosition::new(
                                 touch_motion_event.x_transformed(screen_size.width as u32) as _,
                                 touch_motion_event.y_transformed(screen_size.height as u32) as _,
                             );
-                            Some(WindowEvent::PointerMoved { position: self.last_touch_pos })
+                            self.touch_positions.insert(touch_id, position);
+                            Some(WindowEvent::TouchMoved { touch_id, position })
+                        }
+                        input::event::TouchEvent::Cancel(touch_cancel_event) => {
+                            let touch_id = touch_cancel_event.seat_slot() as u64;
+                            self.touch_positions
+                                .remove(&touch_id)
+                                .map(|position| WindowEvent::TouchReleased { touch_id, position })
                         }
                         _ => None,
                     } {
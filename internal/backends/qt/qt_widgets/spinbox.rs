@@ -301,6 +301,7 @@ fn focus_event(
             TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
             TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+            TextHorizontalAlignment::Justify => key_generated::Qt_AlignmentFlag_AlignJustify,
         };
 
         cpp!(unsafe [
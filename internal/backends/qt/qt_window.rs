@@ -595,6 +595,31 @@ fn mangle_position(position: f32, idx: usize, count: usize) -> f32 {
                 return QBrush(qrg);
             }}
         }
+        i_slint_core::Brush::ConicGradient(g) => {
+            let center = g.center();
+            let (cx, cy) =
+                (center.x as qttypes::qreal * width, center.y as qttypes::qreal * height);
+            // QConicalGradient goes counter-clockwise from the 3 o'clock position, while the
+            // conic gradient goes clockwise from the top: reverse the stops.
+            let start_angle = (90. - g.from_angle()) as qttypes::qreal;
+            cpp_class!(unsafe struct QConicalGradient as "QConicalGradient");
+            let mut qcg = cpp! {
+                unsafe [cx as "qreal", cy as "qreal", start_angle as "qreal"] -> QConicalGradient as "QConicalGradient" {
+                    return QConicalGradient(cx, cy, start_angle);
+                }
+            };
+            let stops = g.stops().collect::<Vec<_>>();
+            for (idx, s) in stops.iter().rev().enumerate() {
+                let pos: f32 = mangle_position(1. - s.position, idx, stops.len());
+                let color: u32 = s.color.as_argb_encoded();
+                cpp! {unsafe [mut qcg as "QConicalGradient", pos as "float", color as "QRgb"] {
+                    qcg.setColorAt(pos, QColor::fromRgba(color));
+                }};
+            }
+            cpp! {unsafe [qcg as "QConicalGradient"] -> qttypes::QBrush as "QBrush" {
+                return QBrush(qcg);
+            }}
+        }
         _ => qttypes::QBrush::default(),
     }
 }
@@ -709,13 +734,14 @@ fn draw_text(
             TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
             TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+            TextHorizontalAlignment::Justify => key_generated::Qt_AlignmentFlag_AlignJustify,
         } | match vertical_alignment {
             TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
             TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
             TextVerticalAlignment::Bottom => key_generated::Qt_AlignmentFlag_AlignBottom,
         };
         let wrap = text.wrap() != TextWrap::NoWrap;
-        let word_wrap = text.wrap() == TextWrap::WordWrap;
+        let word_wrap = matches!(text.wrap(), TextWrap::WordWrap | TextWrap::Hyphenate);
         let elide = text.overflow() == TextOverflow::Elide;
         let (stroke_brush, stroke_width, stroke_style) = text.stroke();
         let stroke_visible = !stroke_brush.is_transparent();
@@ -884,13 +910,14 @@ fn draw_text_input(
             TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
             TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+            TextHorizontalAlignment::Justify => key_generated::Qt_AlignmentFlag_AlignJustify,
         } | match text_input.vertical_alignment() {
             TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
             TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
             TextVerticalAlignment::Bottom => key_generated::Qt_AlignmentFlag_AlignBottom,
         } | match text_input.wrap() {
             TextWrap::NoWrap => 0,
-            TextWrap::WordWrap => key_generated::Qt_TextFlag_TextWordWrap,
+            TextWrap::WordWrap | TextWrap::Hyphenate => key_generated::Qt_TextFlag_TextWordWrap,
             TextWrap::CharWrap => key_generated::Qt_TextFlag_TextWrapAnywhere,
         };
 
@@ -2259,13 +2286,14 @@ fn text_input_byte_offset_for_position(
             TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
             TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+            TextHorizontalAlignment::Justify => key_generated::Qt_AlignmentFlag_AlignJustify,
         } | match text_input.vertical_alignment() {
             TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
             TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
             TextVerticalAlignment::Bottom => key_generated::Qt_AlignmentFlag_AlignBottom,
         } | match text_input.wrap() {
             TextWrap::NoWrap => 0,
-            TextWrap::WordWrap => key_generated::Qt_TextFlag_TextWordWrap,
+            TextWrap::WordWrap | TextWrap::Hyphenate => key_generated::Qt_TextFlag_TextWordWrap,
             TextWrap::CharWrap => key_generated::Qt_TextFlag_TextWrapAnywhere,
         };
         let single_line: bool = text_input.single_line();
@@ -2317,13 +2345,14 @@ fn text_input_cursor_rect_for_byte_offset(
             TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
             TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+            TextHorizontalAlignment::Justify => key_generated::Qt_AlignmentFlag_AlignJustify,
         } | match text_input.vertical_alignment() {
             TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
             TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
             TextVerticalAlignment::Bottom => key_generated::Qt_AlignmentFlag_AlignBottom,
         } | match text_input.wrap() {
             TextWrap::NoWrap => 0,
-            TextWrap::WordWrap => key_generated::Qt_TextFlag_TextWordWrap,
+            TextWrap::WordWrap | TextWrap::Hyphenate => key_generated::Qt_TextFlag_TextWordWrap,
             TextWrap::CharWrap => key_generated::Qt_TextFlag_TextWrapAnywhere,
         };
         let single_line: bool = text_input.single_line();
@@ -208,6 +208,24 @@ fn text_input_byte_offset_for_position(
         offset + column
     }
 
+    fn styled_text_byte_offset_for_position(
+        &self,
+        styled_text: Pin<&i_slint_core::items::StyledText>,
+        pos: LogicalPoint,
+        _font_request: FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        let styled_string = styled_text.styled_string();
+        let text = &styled_string.text;
+        if pos.x < 0. || pos.y < 0. {
+            return None;
+        }
+        let line = (pos.y / 10.) as usize;
+        let offset: usize = text.split('\n').take(line).map(|l| l.len() + 1).sum();
+        let column = (pos.x / 10.) as usize;
+        (column < text.split('\n').nth(line)?.len()).then_some(offset + column)
+    }
+
     fn text_input_cursor_rect_for_byte_offset(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
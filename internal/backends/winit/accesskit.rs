@@ -497,6 +497,9 @@ fn build_node_without_children(
                                     i_slint_core::items::InputType::Decimal
                                     | i_slint_core::items::InputType::Number => Role::NumberInput,
                                     i_slint_core::items::InputType::Password => Role::PasswordInput,
+                                    i_slint_core::items::InputType::Email => Role::EmailInput,
+                                    i_slint_core::items::InputType::Url => Role::UrlInput,
+                                    i_slint_core::items::InputType::Phone => Role::PhoneNumberInput,
                                     _ => Role::TextInput,
                                 }
                             }
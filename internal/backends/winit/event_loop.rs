@@ -62,7 +62,16 @@ pub struct EventLoopState {
     // last seen cursor position
     cursor_pos: LogicalPoint,
     pressed: bool,
-    current_touch_id: Option<u64>,
+    /// The number of touch points currently pressed
+    pressed_touches: usize,
+    /// The files dragged over the window from another application
+    hovered_files: Vec<std::path::PathBuf>,
+    /// Set when the cursor moved since files started to be dragged over the window. winit doesn't
+    /// report the cursor position during such a drag on most platforms, so `cursor_pos` is stale otherwise
+    /// and the files are dropped on the topmost `DropArea` accepting them.
+    file_drag_position_known: bool,
+    /// The files of a drop that was already dispatched, for which winit still sends a `DroppedFile` event
+    dropped_files: Vec<std::path::PathBuf>,
 
     loop_error: Option<PlatformError>,
     current_resize_direction: Option<ResizeDirection>,
@@ -77,7 +86,10 @@ pub fn new(shared_backend_data: Rc<SharedBackendData>) -> Self {
             shared_backend_data,
             cursor_pos: Default::default(),
             pressed: Default::default(),
-            current_touch_id: Default::default(),
+            pressed_touches: Default::default(),
+            hovered_files: Default::default(),
+            file_drag_position_known: Default::default(),
+            dropped_files: Default::default(),
             loop_error: Default::default(),
             current_resize_direction: Default::default(),
             pumping_events_instantly: Default::default(),
@@ -254,6 +266,7 @@ macro_rules! winit_key_to_char {
                 );
                 let position = position.to_logical(runtime_window.scale_factor() as f64);
                 self.cursor_pos = euclid::point2(position.x, position.y);
+                self.file_drag_position_known = true;
                 runtime_window.process_mouse_input(MouseEvent::Moved { position: self.cursor_pos });
             }
             WindowEvent::CursorLeft { .. } => {
@@ -309,35 +322,67 @@ macro_rules! winit_key_to_char {
                 runtime_window.process_mouse_input(ev);
             }
             WindowEvent::Touch(touch) => {
-                if Some(touch.id) == self.current_touch_id || self.current_touch_id.is_none() {
-                    let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
-                    let position = euclid::point2(location.x, location.y);
-                    let ev = match touch.phase {
-                        winit::event::TouchPhase::Started => {
-                            self.pressed = true;
-                            if self.current_touch_id.is_none() {
-                                self.current_touch_id = Some(touch.id);
-                            }
-                            MouseEvent::Pressed {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
-                        }
-                        winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
+                let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
+                let position = corelib::api::LogicalPosition::new(location.x, location.y);
+                let touch_id = touch.id;
+                let event = match touch.phase {
+                    winit::event::TouchPhase::Started => {
+                        self.pressed = true;
+                        self.pressed_touches += 1;
+                        corelib::platform::WindowEvent::TouchPressed { touch_id, position }
+                    }
+                    winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
+                        self.pressed_touches = self.pressed_touches.saturating_sub(1);
+                        if self.pressed_touches == 0 {
                             self.pressed = false;
-                            self.current_touch_id = None;
-                            MouseEvent::Released {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
                         }
-                        winit::event::TouchPhase::Moved => MouseEvent::Moved { position },
-                    };
-                    runtime_window.process_mouse_input(ev);
+                        corelib::platform::WindowEvent::TouchReleased { touch_id, position }
+                    }
+                    winit::event::TouchPhase::Moved => {
+                        corelib::platform::WindowEvent::TouchMoved { touch_id, position }
+                    }
+                };
+                self.loop_error = window.window().try_dispatch_event(event).err();
+            }
+            WindowEvent::HoveredFile(path) => {
+                // winit sends one event per file, collect them into a single drag.
+                if self.hovered_files.is_empty() {
+                    self.file_drag_position_known = false;
+                }
+                self.hovered_files.push(path);
+                // The mouse move events are turned into DragMove events, if winit sends any.
+                runtime_window.start_drag(file_drop_event(&self.hovered_files));
+            }
+            WindowEvent::DroppedFile(path) => {
+                if let Some(index) = self.dropped_files.iter().position(|p| *p == path) {
+                    // Already part of the drop of all the hovered files
+                    self.dropped_files.swap_remove(index);
+                    return;
+                }
+                let mut files = core::mem::take(&mut self.hovered_files);
+                let position_known = self.file_drag_position_known && files.contains(&path);
+                if !files.contains(&path) {
+                    files = vec![path.clone()];
+                }
+                self.dropped_files = files.iter().filter(|p| **p != path).cloned().collect();
+                runtime_window.start_drag(file_drop_event(&files));
+                // Without a cursor position, drop the files on the topmost DropArea accepting them
+                let position = if position_known {
+                    Some(self.cursor_pos)
+                } else {
+                    runtime_window.find_drop_position()
+                };
+                match position {
+                    Some(position) => {
+                        runtime_window.process_mouse_input(MouseEvent::Drop { position })
+                    }
+                    None => runtime_window.process_mouse_input(MouseEvent::Exit),
                 }
             }
+            WindowEvent::HoveredFileCancelled => {
+                self.hovered_files.clear();
+                runtime_window.process_mouse_input(MouseEvent::Exit);
+            }
             WindowEvent::ScaleFactorChanged { scale_factor, inner_size_writer: _ } => {
                 if std::env::var("SLINT_SCALE_FACTOR").is_err() {
                     self.loop_error = window
@@ -527,3 +572,39 @@ pub fn spawn(self) -> Result<(), corelib::platform::PlatformError> {
         Ok(())
     }
 }
+
+/// Returns the drag and drop data for a file dragged from another application: a `file://` URL
+/// with the `text/uri-list` mime type.
+fn file_drop_event(paths: &[std::path::PathBuf]) -> corelib::items::DropEvent {
+    let mut uri_list = String::new();
+    for path in paths {
+        if !uri_list.is_empty() {
+            uri_list.push_str("\r\n");
+        }
+        let path = path.to_string_lossy().replace('\\', "/");
+        uri_list.push_str("file://");
+        if !path.starts_with('/') {
+            // Windows drive letter
+            uri_list.push('/');
+        }
+        for byte in path.bytes() {
+            match byte {
+                b'A'..=b'Z'
+                | b'a'..=b'z'
+                | b'0'..=b'9'
+                | b'-'
+                | b'.'
+                | b'_'
+                | b'~'
+                | b'/'
+                | b':' => uri_list.push(byte as char),
+                _ => uri_list.push_str(&format!("%{byte:02X}")),
+            }
+        }
+    }
+    corelib::items::DropEvent {
+        mime_type: "text/uri-list".into(),
+        data: uri_list.into(),
+        position: Default::default(),
+    }
+}
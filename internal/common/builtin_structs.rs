@@ -115,6 +115,21 @@ struct KeyEvent {
                 }
             }
 
+            /// This structure is passed to the callbacks of the `DropArea` element.
+            struct DropEvent {
+                @name = "slint::private_api::DropEvent"
+                export {
+                    /// The mime type of the dragged data, as set in the `DragArea`
+                    mime_type: SharedString,
+                    /// The dragged data
+                    data: SharedString,
+                    /// The position of the pointer, relative to the `DropArea`
+                    position: LogicalPosition,
+                }
+                private {
+                }
+            }
+
             /// Represents an item in a StandardListView and a StandardTableView.
             #[non_exhaustive]
             struct StandardListViewItem {
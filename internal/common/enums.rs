@@ -22,12 +22,17 @@ macro_rules! for_each_enums {
         $macro![
             /// This enum describes the different types of alignment of text along the horizontal axis of a `Text` element.
             enum TextHorizontalAlignment {
-                /// The text will be aligned with the left edge of the containing box.
+                /// The text will be aligned with the left edge of the containing box. This also applies to
+                /// right-to-left paragraphs, which must be aligned to the right explicitly.
                 Left,
                 /// The text will be horizontally centered within the containing box.
                 Center,
                 /// The text will be aligned to the right of the containing box.
                 Right,
+                /// The space between words is stretched so that the lines of a wrapped paragraph are aligned with both edges
+                /// of the containing box. The last line of each paragraph is aligned to the left. The FemtoVG renderer aligns all
+                /// lines to the left.
+                Justify,
             }
 
             /// This enum describes the different types of alignment of text along the vertical axis of a `Text` element.
@@ -48,6 +53,10 @@ enum TextWrap {
                 WordWrap,
                 /// The text will be wrapped at any character. Currently only supported by the Qt and Software renderers.
                 CharWrap,
+                /// Like `word-wrap`, but words can also be broken at hyphenation points found by a simple heuristic that
+                /// doesn't use a dictionary, and a hyphen is drawn at the end of the line. Currently only supported by the
+                /// Software renderer, other renderers wrap at word boundaries.
+                Hyphenate,
             }
 
             /// This enum describes the how the text appear if it is too wide to fit in the `Text` width.
@@ -310,6 +319,12 @@ enum InputType {
                 Number,
                 /// This will accept and render characters if it's valid part of a decimal
                 Decimal,
+                /// This will render all characters normally and hint virtual keyboards to show a layout for e-mail addresses
+                Email,
+                /// This will render all characters normally and hint virtual keyboards to show a layout for URLs
+                Url,
+                /// This will render all characters normally and hint virtual keyboards to show a layout for phone numbers
+                Phone,
             }
 
             /// Enum representing the `alignment` property of a
@@ -335,6 +350,17 @@ enum LayoutAlignment {
                 SpaceAround,
             }
 
+            /// This enum describes the direction in which the elements of a `HorizontalLayout`
+            /// or `HorizontalBox` are placed. It is set with the `layout-direction` property of
+            /// the `Window`.
+            enum LayoutDirection {
+                /// The first element is placed on the left, for left-to-right languages.
+                LeftToRight,
+                /// The first element is placed on the right, for right-to-left languages such as
+                /// Arabic or Hebrew.
+                RightToLeft,
+            }
+
             /// PathEvent is a low-level data structure describing the composition of a path. Typically it is
             /// generated at compile time from a higher-level description, such as SVG commands.
             enum PathEvent {
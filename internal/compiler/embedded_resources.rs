@@ -45,6 +45,17 @@ pub fn new_empty() -> Self {
     }
 }
 
+/// The frames of an animated image.
+#[cfg(feature = "software-renderer")]
+#[derive(Debug, Clone)]
+pub struct AnimatedTexture {
+    /// The texture of the first frame. Its `data` contains all the frames, stacked vertically,
+    /// each frame covering the full `rect`.
+    pub texture: Texture,
+    /// How long each frame is displayed, in milliseconds
+    pub frame_durations: Vec<u32>,
+}
+
 #[cfg(feature = "software-renderer")]
 #[derive(Debug, Clone, Default)]
 pub struct BitmapGlyph {
@@ -98,6 +109,9 @@ pub enum EmbeddedResourcesKind {
     /// The data has been processed in a texture
     #[cfg(feature = "software-renderer")]
     TextureData(Texture),
+    /// The frames of an animated image, processed in a strip of textures
+    #[cfg(feature = "software-renderer")]
+    AnimatedTextureData(AnimatedTexture),
     /// A set of pre-rendered glyphs of a TrueType font
     #[cfg(feature = "software-renderer")]
     BitmapFontData(BitmapFont),
@@ -23,6 +23,8 @@
 pub enum BuiltinFunction {
     GetWindowScaleFactor,
     GetWindowDefaultFontSize,
+    /// The `layout-direction` of the window, used by the horizontal layouts
+    GetWindowLayoutDirection,
     AnimationTick,
     Debug,
     Mod,
@@ -67,6 +69,18 @@ pub enum BuiltinFunction {
     StringCharacterCount,
     StringToLowercase,
     StringToUppercase,
+    StringContains,
+    StringStartsWith,
+    StringEndsWith,
+    /// the "abc".find("b"), returns the index of the first occurrence or -1
+    StringFind,
+    /// the "abc".substring(start, length)
+    StringSubstring,
+    StringReplace,
+    StringTrim,
+    StringSplit,
+    StringPadStart,
+    StringPadEnd,
     ColorRgbaStruct,
     ColorHsvaStruct,
     ColorBrighter,
@@ -161,6 +175,9 @@ pub fn ty(&self, function: &BuiltinFunction) -> Rc<Function> {
 declare_builtin_function_types!(
     GetWindowScaleFactor: () -> Type::UnitProduct(vec![(Unit::Phx, 1), (Unit::Px, -1)]),
     GetWindowDefaultFontSize: () -> Type::LogicalLength,
+    GetWindowLayoutDirection: () -> Type::Enumeration(
+        typeregister::BUILTIN.with(|e| e.enums.LayoutDirection.clone()),
+    ),
     AnimationTick: () -> Type::Duration,
     Debug: (Type::String) -> Type::Void,
     Mod: (Type::Int32, Type::Int32) -> Type::Int32,
@@ -195,6 +212,16 @@ pub fn ty(&self, function: &BuiltinFunction) -> Rc<Function> {
     StringCharacterCount: (Type::String) -> Type::Int32,
     StringToLowercase: (Type::String) -> Type::String,
     StringToUppercase: (Type::String) -> Type::String,
+    StringContains: (Type::String, Type::String) -> Type::Bool,
+    StringStartsWith: (Type::String, Type::String) -> Type::Bool,
+    StringEndsWith: (Type::String, Type::String) -> Type::Bool,
+    StringFind: (Type::String, Type::String) -> Type::Int32,
+    StringSubstring: (Type::String, Type::Int32, Type::Int32) -> Type::String,
+    StringReplace: (Type::String, Type::String, Type::String) -> Type::String,
+    StringTrim: (Type::String) -> Type::String,
+    StringSplit: (Type::String, Type::String) -> Type::Array(Rc::new(Type::String)),
+    StringPadStart: (Type::String, Type::Int32, Type::String) -> Type::String,
+    StringPadEnd: (Type::String, Type::Int32, Type::String) -> Type::String,
     ImplicitLayoutInfo(..): (Type::ElementReference) -> Type::Struct(typeregister::layout_info_type()),
     ColorRgbaStruct: (Type::Color) -> Type::Struct(Rc::new(Struct {
         fields: IntoIterator::into_iter([
@@ -276,6 +303,7 @@ fn is_const(&self) -> bool {
         match self {
             BuiltinFunction::GetWindowScaleFactor => false,
             BuiltinFunction::GetWindowDefaultFontSize => false,
+            BuiltinFunction::GetWindowLayoutDirection => false,
             BuiltinFunction::AnimationTick => false,
             BuiltinFunction::ColorScheme => false,
             BuiltinFunction::SupportsNativeMenuBar => false,
@@ -318,7 +346,17 @@ fn is_const(&self) -> bool {
             | BuiltinFunction::StringIsEmpty
             | BuiltinFunction::StringCharacterCount
             | BuiltinFunction::StringToLowercase
-            | BuiltinFunction::StringToUppercase => true,
+            | BuiltinFunction::StringToUppercase
+            | BuiltinFunction::StringContains
+            | BuiltinFunction::StringStartsWith
+            | BuiltinFunction::StringEndsWith
+            | BuiltinFunction::StringFind
+            | BuiltinFunction::StringSubstring
+            | BuiltinFunction::StringReplace
+            | BuiltinFunction::StringTrim
+            | BuiltinFunction::StringSplit
+            | BuiltinFunction::StringPadStart
+            | BuiltinFunction::StringPadEnd => true,
             BuiltinFunction::ColorRgbaStruct
             | BuiltinFunction::ColorHsvaStruct
             | BuiltinFunction::ColorBrighter
@@ -356,6 +394,7 @@ pub fn is_pure(&self) -> bool {
         match self {
             BuiltinFunction::GetWindowScaleFactor => true,
             BuiltinFunction::GetWindowDefaultFontSize => true,
+            BuiltinFunction::GetWindowLayoutDirection => true,
             BuiltinFunction::AnimationTick => true,
             BuiltinFunction::ColorScheme => true,
             BuiltinFunction::SupportsNativeMenuBar => true,
@@ -398,7 +437,17 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::StringIsEmpty
             | BuiltinFunction::StringCharacterCount
             | BuiltinFunction::StringToLowercase
-            | BuiltinFunction::StringToUppercase => true,
+            | BuiltinFunction::StringToUppercase
+            | BuiltinFunction::StringContains
+            | BuiltinFunction::StringStartsWith
+            | BuiltinFunction::StringEndsWith
+            | BuiltinFunction::StringFind
+            | BuiltinFunction::StringSubstring
+            | BuiltinFunction::StringReplace
+            | BuiltinFunction::StringTrim
+            | BuiltinFunction::StringSplit
+            | BuiltinFunction::StringPadStart
+            | BuiltinFunction::StringPadEnd => true,
             BuiltinFunction::ColorRgbaStruct
             | BuiltinFunction::ColorHsvaStruct
             | BuiltinFunction::ColorBrighter
@@ -707,6 +756,15 @@ pub enum Expression {
         stops: Vec<(Expression, Expression)>,
     },
 
+    ConicGradient {
+        from_angle: Box<Expression>,
+        /// The center, as a fraction of the width and height of the item
+        center_x: Box<Expression>,
+        center_y: Box<Expression>,
+        /// First expression in the tuple is a color, second expression is the stop position
+        stops: Vec<(Expression, Expression)>,
+    },
+
     EnumerationValue(EnumerationValue),
 
     ReturnStatement(Option<Box<Expression>>),
@@ -848,6 +906,7 @@ pub fn ty(&self) -> Type {
             Expression::EasingCurve(_) => Type::Easing,
             Expression::LinearGradient { .. } => Type::Brush,
             Expression::RadialGradient { .. } => Type::Brush,
+            Expression::ConicGradient { .. } => Type::Brush,
             Expression::EnumerationValue(value) => Type::Enumeration(value.enumeration.clone()),
             // invalid because the expression is unreachable
             Expression::ReturnStatement(_) => Type::Invalid,
@@ -937,6 +996,15 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
                     visitor(s);
                 }
             }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+                visitor(from_angle);
+                visitor(center_x);
+                visitor(center_y);
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
             Expression::EnumerationValue(_) => {}
             Expression::ReturnStatement(expr) => {
                 expr.as_deref().map(visitor);
@@ -1034,6 +1102,15 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
                     visitor(s);
                 }
             }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+                visitor(from_angle);
+                visitor(center_x);
+                visitor(center_y);
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
             Expression::EnumerationValue(_) => {}
             Expression::ReturnStatement(expr) => {
                 expr.as_deref_mut().map(visitor);
@@ -1120,6 +1197,12 @@ pub fn is_constant(&self) -> bool {
             Expression::RadialGradient { stops } => {
                 stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
             }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+                from_angle.is_constant()
+                    && center_x.is_constant()
+                    && center_y.is_constant()
+                    && stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
+            }
             Expression::EnumerationValue(_) => true,
             Expression::ReturnStatement(expr) => {
                 expr.as_ref().map_or(true, |expr| expr.is_constant())
@@ -1152,7 +1235,9 @@ pub fn maybe_convert_to(
         } else if ty.can_convert(&target_type) {
             let from = match (ty, &target_type) {
                 (Type::Brush, Type::Color) => match self {
-                    Expression::LinearGradient { .. } | Expression::RadialGradient { .. } => {
+                    Expression::LinearGradient { .. }
+                    | Expression::RadialGradient { .. }
+                    | Expression::ConicGradient { .. } => {
                         let message = format!("Narrowing conversion from {0} to {1}. This can lead to unexpected behavior because the {0} is a gradient", Type::Brush, Type::Color);
                         diag.push_warning(message, node);
                         self
@@ -1608,8 +1693,17 @@ pub enum EasingCurve {
 pub enum ImageReference {
     None,
     AbsolutePath(SmolStr),
-    EmbeddedData { resource_id: usize, extension: String },
-    EmbeddedTexture { resource_id: usize },
+    EmbeddedData {
+        resource_id: usize,
+        extension: String,
+    },
+    EmbeddedTexture {
+        resource_id: usize,
+    },
+    /// The frames of an animated image, embedded as a strip of textures
+    EmbeddedAnimatedTexture {
+        resource_id: usize,
+    },
 }
 
 /// Print the expression as a .slint code (not necessarily valid .slint)
@@ -1739,6 +1833,21 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             }
             write!(f, ")")
         }
+        Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+            write!(f, "@conic-gradient(from ")?;
+            pretty_print(f, from_angle)?;
+            write!(f, " at ")?;
+            pretty_print(f, center_x)?;
+            write!(f, " ")?;
+            pretty_print(f, center_y)?;
+            for (c, s) in stops {
+                write!(f, ", ")?;
+                pretty_print(f, c)?;
+                write!(f, "  ")?;
+                pretty_print(f, s)?;
+            }
+            write!(f, ")")
+        }
         Expression::EnumerationValue(e) => match e.enumeration.values.get(e.value) {
             Some(val) => write!(f, "{}.{}", e.enumeration.name, val),
             None => write!(f, "{}.{}", e.enumeration.name, e.value),
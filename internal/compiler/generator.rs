@@ -25,12 +25,17 @@
 #[cfg(feature = "rust")]
 pub mod rust;
 
+pub mod python;
+pub mod typescript;
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum OutputFormat {
     #[cfg(feature = "cpp")]
     Cpp(cpp::Config),
     #[cfg(feature = "rust")]
     Rust,
+    TypeScript,
+    Python,
     Interpreter,
     Llr,
 }
@@ -44,6 +49,8 @@ pub fn guess_from_extension(path: &std::path::Path) -> Option<Self> {
             }
             #[cfg(feature = "rust")]
             Some("rs") => Some(Self::Rust),
+            Some("ts") => Some(Self::TypeScript),
+            Some("py") => Some(Self::Python),
             _ => None,
         }
     }
@@ -57,6 +64,8 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "cpp" => Ok(Self::Cpp(cpp::Config::default())),
             #[cfg(feature = "rust")]
             "rust" => Ok(Self::Rust),
+            "typescript" => Ok(Self::TypeScript),
+            "python" => Ok(Self::Python),
             "llr" => Ok(Self::Llr),
             _ => Err(format!("Unknown output format {s}")),
         }
@@ -83,6 +92,14 @@ pub fn generate(
             let output = rust::generate(doc, compiler_config)?;
             write!(destination, "{output}")?;
         }
+        OutputFormat::TypeScript => {
+            let output = typescript::generate(doc, compiler_config)?;
+            write!(destination, "{output}")?;
+        }
+        OutputFormat::Python => {
+            let output = python::generate(doc, compiler_config)?;
+            write!(destination, "{output}")?;
+        }
         OutputFormat::Interpreter => {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
@@ -510,6 +527,34 @@ pub fn for_each_const_properties(
     });
 }
 
+/// Whether a type in the declarations generated for the interpreter based APIs (Node.js, Python)
+/// is used for a value that is read from Slint, or for a value written to Slint.
+/// These APIs accept more types than they return for some of them (eg, a color for a brush)
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Access {
+    Read,
+    Write,
+}
+
+/// The name of a component, property, struct field or enum value as seen from the interpreter
+/// based APIs (Node.js, Python): `-` are replaced by `_`
+pub(crate) fn interpreter_api_name(name: &str) -> String {
+    name.replace('-', "_")
+}
+
+/// The name of the argument at `index` of a function or callback as seen from the interpreter
+/// based APIs, or `arg_{index}` if it has no name or if `is_reserved` returns true for its name
+pub(crate) fn interpreter_api_arg_name(
+    function: &crate::langtype::Function,
+    index: usize,
+    is_reserved: impl Fn(&str) -> bool,
+) -> String {
+    match function.arg_names.get(index) {
+        Some(name) if !name.is_empty() && !is_reserved(name) => interpreter_api_name(name),
+        _ => format!("arg_{index}"),
+    }
+}
+
 /// Convert a ascii kebab string to pascal case
 pub fn to_pascal_case(str: &str) -> String {
     let mut result = Vec::with_capacity(str.len());
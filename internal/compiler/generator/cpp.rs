@@ -594,15 +594,41 @@ fn property_set_value_code(
     ctx: &EvaluationContext,
 ) -> String {
     let prop = access_member(property, ctx);
-    if let Some((animation, map)) = &ctx.property_info(property).animation {
-        let mut animation = (*animation).clone();
+    if let Some(((animation, keyframes), map)) = &ctx.property_info(property).animation {
+        let mut animation = animation.clone();
         map.map_expression(&mut animation);
         let animation_code = compile_expression(&animation, ctx);
-        return format!("{prop}.set_animated_value({value_expr}, {animation_code})");
+        let mut keyframes = keyframes.clone();
+        for (_, value) in &mut keyframes {
+            map.map_expression(value);
+        }
+        let keyframes_code = compile_keyframes(&keyframes, &ctx.property_ty(property), ctx);
+        return format!(
+            "{prop}.set_animated_value({value_expr}, {animation_code}{keyframes_code})"
+        );
     }
     format!("{prop}.set({value_expr})")
 }
 
+/// Returns the extra arguments to pass the keyframes of an animation to `set_animated_value`
+/// or `set_animated_binding`: the list of offsets and the list of values, or nothing if there are no keyframes
+fn compile_keyframes(
+    keyframes: &llr::Keyframes,
+    prop_type: &Type,
+    ctx: &EvaluationContext,
+) -> String {
+    if keyframes.is_empty() {
+        return String::new();
+    }
+    let cpp_type = prop_type.cpp_type().unwrap_or_default();
+    let offsets = keyframes.iter().map(|(offset, _)| format!("{offset:?}f")).join(", ");
+    let values = keyframes
+        .iter()
+        .map(|(_, value)| format!("static_cast<{cpp_type}>({})", compile_expression(value, ctx)))
+        .join(", ");
+    format!(", {{ {offsets} }}, {{ {values} }}")
+}
+
 fn handle_property_init(
     prop: &llr::PropertyReference,
     binding_expression: &llr::BindingExpression,
@@ -650,9 +676,10 @@ fn handle_property_init(
                 format!("slint::private_api::set_state_binding({prop_access}, {binding_code});")
             } else {
                 match &binding_expression.animation {
-                    Some(llr::Animation::Static(anim)) => {
+                    Some(llr::Animation::Static(anim, keyframes)) => {
                         let anim = compile_expression(anim, ctx);
-                        format!("{prop_access}.set_animated_binding({binding_code}, {anim});")
+                        let keyframes = compile_keyframes(keyframes, &prop_type, ctx);
+                        format!("{prop_access}.set_animated_binding({binding_code}, {anim}{keyframes});")
                     }
                     Some(llr::Animation::Transition (
                         anim
@@ -996,6 +1023,79 @@ fn embed_resource(
             }))
         }
         #[cfg(feature = "software-renderer")]
+        crate::embedded_resources::EmbeddedResourcesKind::AnimatedTextureData(
+            crate::embedded_resources::AnimatedTexture {
+                texture:
+                    crate::embedded_resources::Texture {
+                        data,
+                        format,
+                        rect,
+                        total_size: crate::embedded_resources::Size { width, height },
+                        original_size:
+                            crate::embedded_resources::Size {
+                                width: unscaled_width,
+                                height: unscaled_height,
+                            },
+                    },
+                frame_durations,
+            },
+        ) => {
+            let (r_x, r_y, r_w, r_h) = (rect.x(), rect.y(), rect.width(), rect.height());
+            let count = data.len();
+            let frame_count = frame_durations.len();
+            let frame_size = count / frame_count;
+            let data = data.iter().map(ToString::to_string).join(", ");
+            let data_name = format_smolstr!("slint_embedded_resource_{}_data", resource.id);
+            declarations.push(Declaration::Var(Var {
+                ty: "const uint8_t".into(),
+                name: data_name.clone(),
+                array_size: Some(count),
+                init: Some(format!("{{ {data} }}")),
+                ..Default::default()
+            }));
+            let texture_name = format_smolstr!("slint_embedded_resource_{}_textures", resource.id);
+            let textures = (0..frame_count)
+                .map(|i| {
+                    format!(
+                        "{{
+                            .rect = {{ {r_x}, {r_y}, {r_w}, {r_h} }},
+                            .format = slint::cbindgen_private::types::TexturePixelFormat::{format},
+                            .color = slint::Color{{}},
+                            .index = {index},
+                            }}",
+                        index = i * frame_size
+                    )
+                })
+                .join(", ");
+            declarations.push(Declaration::Var(Var {
+                ty: "const slint::cbindgen_private::types::StaticTexture".into(),
+                name: texture_name.clone(),
+                array_size: Some(frame_count),
+                init: Some(format!("{{ {textures} }}")),
+                ..Default::default()
+            }));
+            let frames = (0..frame_count).map(|i| format!("slint::cbindgen_private::types::StaticTextures {{
+                        .size = {{ {width}, {height} }},
+                        .original_size = {{ {unscaled_width}, {unscaled_height} }},
+                        .data = slint::cbindgen_private::Slice<uint8_t>{{  {data_name} , {count} }},
+                        .textures = slint::cbindgen_private::Slice<slint::cbindgen_private::types::StaticTexture>{{ &{texture_name}[{i}], 1 }}
+                    }}")).join(", ");
+            declarations.push(Declaration::Var(Var {
+                ty: "const slint::cbindgen_private::types::StaticTextures".into(),
+                name: format_smolstr!("slint_embedded_resource_{}", resource.id),
+                array_size: Some(frame_count),
+                init: Some(format!("{{ {frames} }}")),
+                ..Default::default()
+            }));
+            declarations.push(Declaration::Var(Var {
+                ty: "const uint32_t".into(),
+                name: format_smolstr!("slint_embedded_resource_{}_durations", resource.id),
+                array_size: Some(frame_count),
+                init: Some(format!("{{ {} }}", frame_durations.iter().join(", "))),
+                ..Default::default()
+            }))
+        }
+        #[cfg(feature = "software-renderer")]
         crate::embedded_resources::EmbeddedResourcesKind::BitmapFontData(
             crate::embedded_resources::BitmapFont {
                 family_name,
@@ -3340,6 +3440,9 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 crate::expression_tree::ImageReference::EmbeddedTexture{resource_id} => {
                     format!("slint::private_api::image_from_embedded_textures(&slint_embedded_resource_{resource_id})")
                 },
+                crate::expression_tree::ImageReference::EmbeddedAnimatedTexture{resource_id} => {
+                    format!("slint::private_api::image_from_embedded_texture_frames({{ slint_embedded_resource_{resource_id}, std::size(slint_embedded_resource_{resource_id}) }}, {{ slint_embedded_resource_{resource_id}_durations, std::size(slint_embedded_resource_{resource_id}_durations) }})")
+                },
             };
             match &nine_slice {
                 Some([a, b, c, d]) => {
@@ -3437,6 +3540,20 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 stops_it.join(", "), stops.len()
             )
         }
+        Expression::ConicGradient{ from_angle, center_x, center_y, stops } => {
+            let from_angle = compile_expression(from_angle, ctx);
+            let center_x = compile_expression(center_x, ctx);
+            let center_y = compile_expression(center_y, ctx);
+            let mut stops_it = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                format!("slint::private_api::GradientStop{{ {color}, float({position}), }}")
+            });
+            format!(
+                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::ConicGradientBrush({}, {}, {}, stops, {})); }}()",
+                stops_it.join(", "), from_angle, center_x, center_y, stops.len()
+            )
+        }
         Expression::EnumerationValue(value) => {
             let prefix = if value.enumeration.node.is_some() { "" } else {"slint::cbindgen_private::"};
             format!(
@@ -3528,6 +3645,9 @@ fn compile_builtin_function_call(
         BuiltinFunction::GetWindowDefaultFontSize => {
             format!("{}.default_font_size()", access_window_field(ctx))
         }
+        BuiltinFunction::GetWindowLayoutDirection => {
+            format!("{}.layout_direction()", access_window_field(ctx))
+        }
         BuiltinFunction::AnimationTick => "slint::cbindgen_private::slint_animation_tick()".into(),
         BuiltinFunction::Debug => {
             ctx.generator_state.conditional_includes.iostream.set(true);
@@ -3659,6 +3779,42 @@ fn compile_builtin_function_call(
         BuiltinFunction::StringToUppercase => {
             format!("{}.to_uppercase()", a.next().unwrap())
         }
+        BuiltinFunction::StringContains => {
+            format!("[](std::string_view s, std::string_view needle){{ return s.find(needle) != std::string_view::npos; }}({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::StringStartsWith => {
+            format!("{}.starts_with({})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::StringEndsWith => {
+            format!("{}.ends_with({})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::StringFind => {
+            format!("[](const auto &s, const auto &needle){{ return slint::cbindgen_private::slint_shared_string_find(&s, &needle); }}({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::StringSubstring => {
+            format!("[](const auto &s, int start, int length){{ slint::SharedString out; slint::cbindgen_private::slint_shared_string_substring(&out, &s, start, length); return out; }}({}, {}, {})",
+                a.next().unwrap(), a.next().unwrap(), a.next().unwrap(),
+            )
+        }
+        BuiltinFunction::StringReplace => {
+            format!("[](const auto &s, const auto &from, const auto &to){{ slint::SharedString out; slint::cbindgen_private::slint_shared_string_replace(&out, &s, &from, &to); return out; }}({}, {}, {})",
+                a.next().unwrap(), a.next().unwrap(), a.next().unwrap(),
+            )
+        }
+        BuiltinFunction::StringTrim => {
+            format!("[](const auto &s){{ slint::SharedString out; slint::cbindgen_private::slint_shared_string_trim(&out, &s); return out; }}({})", a.next().unwrap())
+        }
+        BuiltinFunction::StringSplit => {
+            format!("[](const auto &s, const auto &separator){{ slint::SharedVector<slint::SharedString> out; slint::cbindgen_private::slint_shared_string_split(&out, &s, &separator); return std::make_shared<slint::VectorModel<slint::SharedString>>(std::vector<slint::SharedString>(out.begin(), out.end())); }}({}, {})",
+                a.next().unwrap(), a.next().unwrap(),
+            )
+        }
+        BuiltinFunction::StringPadStart | BuiltinFunction::StringPadEnd => {
+            format!("[](const auto &s, int length, const auto &fill){{ slint::SharedString out; slint::cbindgen_private::slint_shared_string_pad(&out, &s, length, &fill, {}); return out; }}({}, {}, {})",
+                matches!(function, BuiltinFunction::StringPadStart),
+                a.next().unwrap(), a.next().unwrap(), a.next().unwrap(),
+            )
+        }
         BuiltinFunction::ColorRgbaStruct => {
             format!("{}.to_argb_uint()", a.next().unwrap())
         }
@@ -0,0 +1,366 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*! module for the Python wrapper module generator
+
+The generated `.py` module loads the `.slint` file next to it with `slint.load_file()`, and
+re-exports the classes it creates. Type checkers and IDEs see typed stubs of these classes instead,
+following the conventions of the Python API:
+ - `-` in names are replaced by `_`
+ - properties and callbacks are attributes of the component instance, functions are methods
+ - exported globals are accessed as a property of the component instance
+ - enums are `str` based `enum.Enum`s, which are also defined at run-time. Their
+   `__slint_enum__` attribute tells the Python API the name of the Slint enum to convert the
+   members to
+*/
+
+use super::{interpreter_api_arg_name, interpreter_api_name as ident, Access};
+use crate::langtype::{Enumeration, Function, Struct, Type};
+use crate::llr::PublicProperty;
+use crate::object_tree::Document;
+use crate::CompilerConfiguration;
+use std::fmt::Write;
+
+/// Names that can't be used as Python attributes
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+pub fn generate(
+    doc: &Document,
+    compiler_config: &CompilerConfiguration,
+) -> std::io::Result<String> {
+    let file_name = doc
+        .node
+        .as_ref()
+        .and_then(|n| n.source_file.path().file_name().map(|f| f.to_string_lossy().to_string()))
+        .unwrap_or_default();
+
+    let mut output = String::new();
+    writeln!(
+        output,
+        "# This file is auto-generated by the Slint compiler from {file_name}. Do not edit.\n\n\
+        from __future__ import annotations\n\n\
+        import enum\n\
+        import pathlib\n\
+        import typing\n\n\
+        import slint\n\n\
+        _module = slint.load_file(pathlib.Path(__file__).parent / {})",
+        string_literal(&file_name)
+    )
+    .unwrap();
+
+    let structs_and_enums = doc.used_types.borrow().structs_and_enums.clone();
+    for ty in &structs_and_enums {
+        if let Type::Enumeration(en) = ty {
+            generate_enum(&mut output, en);
+        }
+    }
+
+    let llr = crate::llr::lower_to_item_tree::lower_to_item_tree(doc, compiler_config)?;
+    let exported_globals = llr.globals.iter().filter(|glob| glob.exported).collect::<Vec<_>>();
+
+    let mut stubs = String::new();
+    let mut runtime_names = vec![];
+    for ty in &structs_and_enums {
+        if let Type::Struct(s) = ty {
+            if let Struct { name: Some(name), node: Some(_), fields, .. } = s.as_ref() {
+                let name = ident(name);
+                writeln!(stubs, "\n    class {name}:").unwrap();
+                let fields = fields
+                    .iter()
+                    .filter(|(field, _)| !is_keyword(field))
+                    .map(|(field, ty)| (ident(field), python_type(ty, Access::Read)))
+                    .collect::<Vec<_>>();
+                for (field, ty) in &fields {
+                    writeln!(stubs, "        {field}: {ty}").unwrap();
+                }
+                let args = fields
+                    .iter()
+                    .map(|(field, ty)| format!(", {field}: {ty} = ..."))
+                    .collect::<String>();
+                let separator = if fields.is_empty() { "" } else { ", *" };
+                writeln!(stubs, "\n        def __init__(self{separator}{args}) -> None: ...")
+                    .unwrap();
+                runtime_names.push((name.clone(), name));
+            }
+        }
+    }
+
+    for glob in &exported_globals {
+        let name = ident(&glob.name);
+        writeln!(stubs, "\n    class {name}:").unwrap();
+        generate_properties(&mut stubs, &glob.public_properties, "pass");
+        // The component's property with the name of the global shadows the class
+        writeln!(stubs, "\n    _{name}_global = {name}").unwrap();
+    }
+
+    for component in &llr.public_components {
+        let name = ident(&component.name);
+        writeln!(stubs, "\n    class {name}(slint.Component):").unwrap();
+        writeln!(stubs, "        def __init__(self, **kwargs: typing.Any) -> None: ...").unwrap();
+        generate_properties(&mut stubs, &component.public_properties, "");
+        for glob in &exported_globals {
+            let glob_name = ident(&glob.name);
+            writeln!(stubs, "        @property").unwrap();
+            writeln!(stubs, "        def {glob_name}(self) -> _{glob_name}_global: ...").unwrap();
+        }
+        runtime_names.push((name, component.name.to_string()));
+    }
+
+    if !stubs.is_empty() {
+        writeln!(output, "\n\nif typing.TYPE_CHECKING:{stubs}\nelse:").unwrap();
+        for (name, runtime_name) in &runtime_names {
+            writeln!(output, "    {name} = getattr(_module, {})", string_literal(runtime_name))
+                .unwrap();
+        }
+        if runtime_names.is_empty() {
+            writeln!(output, "    pass").unwrap();
+        }
+    }
+
+    Ok(output)
+}
+
+fn generate_enum(output: &mut String, en: &Enumeration) {
+    writeln!(output, "\n\nclass {}(str, enum.Enum):", ident(&en.name)).unwrap();
+    // Tells the Python API to pass the members as values of this Slint enum
+    writeln!(output, "    __slint_enum__ = {}", string_literal(&en.name)).unwrap();
+    for value in &en.values {
+        let member = ident(value);
+        let member = if is_keyword(&member) { format!("{member}_") } else { member };
+        writeln!(output, "    {member} = {}", string_literal(value)).unwrap();
+    }
+}
+
+/// The properties, callbacks and functions of a component or a global.
+/// `empty_body` is emitted if there is nothing else in the class
+fn generate_properties(output: &mut String, properties: &[PublicProperty], empty_body: &str) {
+    let mut empty = true;
+    for p in properties.iter().filter(|p| !is_keyword(&p.name)) {
+        empty = false;
+        let name = ident(&p.name);
+        match &p.ty {
+            Type::Function(function) => {
+                let args = function
+                    .args
+                    .iter()
+                    .enumerate()
+                    .map(|(i, arg)| {
+                        format!(", {}: {}", arg_name(function, i), python_type(arg, Access::Write))
+                    })
+                    .collect::<String>();
+                writeln!(
+                    output,
+                    "        def {name}(self{args}) -> {}: ...",
+                    python_type(&function.return_type, Access::Read)
+                )
+                .unwrap();
+            }
+            Type::Callback(callback) => {
+                let args = callback
+                    .args
+                    .iter()
+                    .map(|arg| python_type(arg, Access::Read))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let ty = format!(
+                    "typing.Callable[[{args}], {}]",
+                    python_type(&callback.return_type, Access::Write)
+                );
+                generate_property(output, &name, &ty, &ty, false);
+            }
+            ty => {
+                generate_property(
+                    output,
+                    &name,
+                    &python_type(ty, Access::Read),
+                    &python_type(ty, Access::Write),
+                    p.read_only,
+                );
+            }
+        }
+    }
+    if empty && !empty_body.is_empty() {
+        writeln!(output, "        {empty_body}").unwrap();
+    }
+}
+
+fn generate_property(output: &mut String, name: &str, read: &str, write: &str, read_only: bool) {
+    writeln!(output, "        @property").unwrap();
+    writeln!(output, "        def {name}(self) -> {read}: ...").unwrap();
+    if !read_only {
+        writeln!(output, "        @{name}.setter").unwrap();
+        writeln!(output, "        def {name}(self, value: {write}) -> None: ...").unwrap();
+    }
+}
+
+fn arg_name(function: &Function, index: usize) -> String {
+    interpreter_api_arg_name(function, index, is_keyword)
+}
+
+/// The Python type annotation for a Slint type
+fn python_type(ty: &Type, access: Access) -> String {
+    match ty {
+        Type::Float32
+        | Type::Int32
+        | Type::Duration
+        | Type::Angle
+        | Type::PhysicalLength
+        | Type::LogicalLength
+        | Type::Rem
+        | Type::Percent
+        | Type::UnitProduct(_) => "float".into(),
+        Type::String => "str".into(),
+        Type::Bool => "bool".into(),
+        Type::Void => "None".into(),
+        Type::Color | Type::Brush => match access {
+            Access::Read => "slint.Brush".into(),
+            Access::Write => "slint.Brush | slint.Color".into(),
+        },
+        Type::Image => "slint.Image".into(),
+        Type::Array(inner) => format!("slint.Model[{}]", python_type(inner, Access::Read)),
+        Type::Struct(s) => match s.as_ref() {
+            Struct { name: Some(name), node: Some(_), .. } => ident(name),
+            _ => match access {
+                Access::Read => "slint.Struct".into(),
+                Access::Write => "slint.Struct | dict[str, typing.Any]".into(),
+            },
+        },
+        Type::Enumeration(en) => ident(&en.name),
+        _ => "typing.Any".into(),
+    }
+}
+
+fn is_keyword(name: &str) -> bool {
+    PYTHON_KEYWORDS.contains(&ident(name).as_str())
+}
+
+/// A Python string literal for `value`
+fn string_literal(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => write!(result, "\\U{:08x}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+#[test]
+fn test_python_module() {
+    let source = r#"
+export struct Person { name: string, birth-year: int }
+export enum Direction { left, top-right }
+export global Logic {
+    in-out property <[Person]> people;
+    callback greet(person: Person) -> string;
+}
+export component App inherits Window {
+    in property <color> background-color;
+    out property <int> count;
+    in-out property <Direction> direction;
+    callback clicked(int, string);
+    public function reset(value: int) -> bool { return true; }
+}
+"#;
+    let mut compiler_config = CompilerConfiguration::new(crate::generator::OutputFormat::Python);
+    compiler_config.style = Some("fluent".into());
+    let mut test_diags = crate::diagnostics::BuildDiagnostics::default();
+    let doc_node = crate::parser::parse(
+        source.into(),
+        Some(std::path::Path::new("app.slint")),
+        &mut test_diags,
+    );
+    let (doc, diag, _) =
+        spin_on::spin_on(crate::compile_syntax_node(doc_node, test_diags, compiler_config.clone()));
+    assert!(!diag.has_errors(), "slint compile error {:#?}", diag.to_string_vec());
+
+    let output = generate(&doc, &compiler_config).unwrap();
+    assert_eq!(
+        output,
+        r#"# This file is auto-generated by the Slint compiler from app.slint. Do not edit.
+
+from __future__ import annotations
+
+import enum
+import pathlib
+import typing
+
+import slint
+
+_module = slint.load_file(pathlib.Path(__file__).parent / "app.slint")
+
+
+class Direction(str, enum.Enum):
+    __slint_enum__ = "Direction"
+    left = "left"
+    top_right = "top-right"
+
+
+if typing.TYPE_CHECKING:
+    class Person:
+        birth_year: float
+        name: str
+
+        def __init__(self, *, birth_year: float = ..., name: str = ...) -> None: ...
+
+    class Logic:
+        @property
+        def greet(self) -> typing.Callable[[Person], str]: ...
+        @greet.setter
+        def greet(self, value: typing.Callable[[Person], str]) -> None: ...
+        @property
+        def people(self) -> slint.Model[Person]: ...
+        @people.setter
+        def people(self, value: slint.Model[Person]) -> None: ...
+
+    _Logic_global = Logic
+
+    class App(slint.Component):
+        def __init__(self, **kwargs: typing.Any) -> None: ...
+        @property
+        def background_color(self) -> slint.Brush: ...
+        @background_color.setter
+        def background_color(self, value: slint.Brush | slint.Color) -> None: ...
+        @property
+        def clicked(self) -> typing.Callable[[float, str], None]: ...
+        @clicked.setter
+        def clicked(self, value: typing.Callable[[float, str], None]) -> None: ...
+        @property
+        def count(self) -> float: ...
+        @property
+        def direction(self) -> Direction: ...
+        @direction.setter
+        def direction(self, value: Direction) -> None: ...
+        def reset(self, value: float) -> bool: ...
+        @property
+        def Logic(self) -> _Logic_global: ...
+
+else:
+    Person = getattr(_module, "Person")
+    App = getattr(_module, "App")
+"#
+    );
+}
+
+#[test]
+fn test_string_literal() {
+    assert_eq!(string_literal("app.slint"), r#""app.slint""#);
+    assert_eq!(string_literal("a \"b\" \\c"), r#""a \"b\" \\c""#);
+    assert_eq!(string_literal("line\nbreak\ttab"), r#""line\nbreak\ttab""#);
+    assert_eq!(string_literal("\u{7f}\u{85}"), r#""\U0000007f\U00000085""#);
+    // Unlike with `{:?}`, combining characters are not escaped: Python source files are UTF-8
+    assert_eq!(string_literal("e\u{301}t\u{e9}"), "\"e\u{301}t\u{e9}\"");
+}
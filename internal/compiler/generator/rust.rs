@@ -527,7 +527,15 @@ fn handle_property_init(
                 } }
             } else {
                 match &binding_expression.animation {
-                    Some(llr::Animation::Static(anim)) => {
+                    Some(llr::Animation::Static(anim, keyframes)) if !keyframes.is_empty() => {
+                        let anim = compile_expression(anim, ctx);
+                        let keyframes = compile_keyframes(keyframes, prop_type, ctx);
+                        quote! { {
+                            #init_self_pin_ref
+                            slint::private_unstable_api::set_animated_property_binding_with_keyframes(#rust_property, &self_rc, #binding_tokens, #anim, #keyframes);
+                        } }
+                    }
+                    Some(llr::Animation::Static(anim, _)) => {
                         let anim = compile_expression(anim, ctx);
                         quote! { {
                             #init_self_pin_ref
@@ -1877,16 +1885,39 @@ fn property_set_value_tokens(
     let prop = access_member(property, ctx);
     let prop_type = ctx.property_ty(property);
     let value_tokens = set_primitive_property_value(prop_type, value_tokens);
-    if let Some((animation, map)) = &ctx.property_info(property).animation {
-        let mut animation = (*animation).clone();
+    if let Some(((animation, keyframes), map)) = &ctx.property_info(property).animation {
+        let mut animation = animation.clone();
         map.map_expression(&mut animation);
         let animation_tokens = compile_expression(&animation, ctx);
+        if !keyframes.is_empty() {
+            let mut keyframes = keyframes.clone();
+            for (_, value) in &mut keyframes {
+                map.map_expression(value);
+            }
+            let keyframes = compile_keyframes(&keyframes, prop_type, ctx);
+            return prop.then(|prop| {
+                quote!(#prop.set_animated_value_with_keyframes(#value_tokens as _, #animation_tokens, #keyframes))
+            });
+        }
         return prop
             .then(|prop| quote!(#prop.set_animated_value(#value_tokens as _, #animation_tokens)));
     }
     prop.then(|prop| quote!(#prop.set(#value_tokens as _)))
 }
 
+/// Returns the code for an array of `Keyframe` for a property of the given type
+fn compile_keyframes(
+    keyframes: &llr::Keyframes,
+    prop_type: &Type,
+    ctx: &EvaluationContext,
+) -> TokenStream {
+    let keyframes = keyframes.iter().map(|(offset, value)| {
+        let value = set_primitive_property_value(prop_type, compile_expression(value, ctx));
+        quote!(sp::Keyframe { offset: #offset, value: (#value) as _ })
+    });
+    quote!([#(#keyframes),*])
+}
+
 /// Returns the code that can access the given property or callback
 fn access_member(reference: &llr::PropertyReference, ctx: &EvaluationContext) -> MemberAccess {
     fn in_native_item(
@@ -2458,6 +2489,13 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                         sp::Image::from(sp::ImageInner::StaticTextures(&#symbol))
                     )
                 }
+                crate::expression_tree::ImageReference::EmbeddedAnimatedTexture { resource_id } => {
+                    let symbol = format_ident!("SLINT_EMBEDDED_RESOURCE_{}", resource_id);
+                    let symbol_durations = format_ident!("SLINT_EMBEDDED_RESOURCE_{}_DURATIONS", resource_id);
+                    quote!(
+                        sp::image_from_embedded_texture_frames(&#symbol, &#symbol_durations)
+                    )
+                }
             };
             match &nine_slice {
                 Some([a, b, c, d]) => {
@@ -2572,6 +2610,19 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 sp::RadialGradientBrush::new_circle([#(#stops),*])
             ))
         }
+        Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+            let from_angle = compile_expression(from_angle, ctx);
+            let center_x = compile_expression(center_x, ctx);
+            let center_y = compile_expression(center_y, ctx);
+            let stops = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                quote!(sp::GradientStop{ color: #color, position: #position as _ })
+            });
+            quote!(slint::Brush::ConicGradient(
+                sp::ConicGradientBrush::new(#from_angle as _, #center_x as _, #center_y as _, [#(#stops),*])
+            ))
+        }
         Expression::EnumerationValue(value) => {
             let base_ident = ident(&value.enumeration.name);
             let value_ident = ident(&value.to_pascal_case());
@@ -2968,6 +3019,10 @@ fn compile_builtin_function_call(
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).window_item().unwrap().as_pin_ref().default_font_size().get())
         }
+        BuiltinFunction::GetWindowLayoutDirection => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).layout_direction())
+        }
         BuiltinFunction::AnimationTick => {
             quote!(sp::animation_tick())
         }
@@ -3019,6 +3074,40 @@ fn compile_builtin_function_call(
         }
         BuiltinFunction::StringToLowercase => quote!(sp::SharedString::from(#(#a)*.to_lowercase())),
         BuiltinFunction::StringToUppercase => quote!(sp::SharedString::from(#(#a)*.to_uppercase())),
+        BuiltinFunction::StringContains => {
+            let (s, needle) = (a.next().unwrap(), a.next().unwrap());
+            quote!(#s.contains(#needle.as_str()))
+        }
+        BuiltinFunction::StringStartsWith => {
+            let (s, prefix) = (a.next().unwrap(), a.next().unwrap());
+            quote!(#s.starts_with(#prefix.as_str()))
+        }
+        BuiltinFunction::StringEndsWith => {
+            let (s, suffix) = (a.next().unwrap(), a.next().unwrap());
+            quote!(#s.ends_with(#suffix.as_str()))
+        }
+        BuiltinFunction::StringFind => {
+            let (s, needle) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::shared_string_find(#s.as_str(), #needle.as_str()))
+        }
+        BuiltinFunction::StringSubstring => {
+            let (s, start, length) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(sp::shared_string_substring(#s.as_str(), #start as i32, #length as i32))
+        }
+        BuiltinFunction::StringReplace => {
+            let (s, from, to) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(sp::shared_string_replace(#s.as_str(), #from.as_str(), #to.as_str()))
+        }
+        BuiltinFunction::StringTrim => quote!(sp::SharedString::from(#(#a)*.trim())),
+        BuiltinFunction::StringSplit => {
+            let (s, separator) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::ModelRc::new(sp::SharedVectorModel::from(sp::shared_string_split(#s.as_str(), #separator.as_str()))))
+        }
+        BuiltinFunction::StringPadStart | BuiltinFunction::StringPadEnd => {
+            let (s, length, fill) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            let at_start = matches!(function, BuiltinFunction::StringPadStart);
+            quote!(sp::shared_string_pad(#s.as_str(), #length as i32, #fill.as_str(), #at_start))
+        }
         BuiltinFunction::ColorRgbaStruct => quote!( #(#a)*.to_argb_u8()),
         BuiltinFunction::ColorHsvaStruct => quote!( #(#a)*.to_hsva()),
         BuiltinFunction::ColorBrighter => {
@@ -3373,6 +3462,47 @@ fn generate_resources(doc: &Document) -> Vec<TokenStream> {
                     )
                 },
                 #[cfg(feature = "software-renderer")]
+                crate::embedded_resources::EmbeddedResourcesKind::AnimatedTextureData(crate::embedded_resources::AnimatedTexture {
+                    texture: crate::embedded_resources::Texture {
+                        data, format, rect,
+                        total_size: crate::embedded_resources::Size{width, height},
+                        original_size: crate::embedded_resources::Size{width: unscaled_width, height: unscaled_height},
+                    },
+                    frame_durations,
+                }) => {
+                    let (r_x, r_y, r_w, r_h) = (rect.x(), rect.y(), rect.width(), rect.height());
+                    let symbol_data = format_ident!("SLINT_EMBEDDED_RESOURCE_{}_DATA", er.id);
+                    let symbol_durations = format_ident!("SLINT_EMBEDDED_RESOURCE_{}_DURATIONS", er.id);
+                    let data_size = data.len();
+                    let frame_count = frame_durations.len();
+                    let frame_size = data_size / frame_count;
+                    let frames = (0..frame_count).map(|i| {
+                        let index = i * frame_size;
+                        quote!(
+                            sp::StaticTextures {
+                                size: sp::IntSize::new(#width as _, #height as _),
+                                original_size: sp::IntSize::new(#unscaled_width as _, #unscaled_height as _),
+                                data: sp::Slice::from_slice(&#symbol_data),
+                                textures: sp::Slice::from_slice(&[
+                                    sp::StaticTexture {
+                                        rect: sp::euclid::rect(#r_x as _, #r_y as _, #r_w as _, #r_h as _),
+                                        format: #format,
+                                        color: sp::Color::from_argb_encoded(0),
+                                        index: #index,
+                                    }
+                                ])
+                            }
+                        )
+                    });
+                    quote!(
+                        #link_section
+                        static #symbol_data : [u8; #data_size]= [#(#data),*];
+                        #link_section
+                        static #symbol: [sp::StaticTextures; #frame_count] = [#(#frames),*];
+                        static #symbol_durations: [u32; #frame_count] = [#(#frame_durations),*];
+                    )
+                },
+                #[cfg(feature = "software-renderer")]
                 crate::embedded_resources::EmbeddedResourcesKind::BitmapFontData(crate::embedded_resources::BitmapFont { family_name, character_map, units_per_em, ascent, descent, x_height, cap_height, glyphs, weight, italic, sdf }) => {
 
                     let character_map_size = character_map.len();
@@ -0,0 +1,298 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*! module for the TypeScript declaration generator
+
+The generated `.d.ts` file describes the module returned by `loadFile()` in the Node.js API
+(and the wasm interpreter), following the same conventions:
+ - `-` in names are replaced by `_`
+ - exported components are constructed with `new` and an optional object with the initial
+   values of their properties and callbacks
+ - exported globals are accessed as a property of the component instance
+ - structs are plain objects, enums are strings
+
+It can be used like so:
+```ts
+import * as slint from "slint-ui";
+import type * as ui_types from "./app.slint";
+const ui = slint.loadFile("app.slint") as typeof ui_types;
+```
+*/
+
+use super::{interpreter_api_arg_name, interpreter_api_name as ident, Access};
+use crate::langtype::{Enumeration, Struct, Type};
+use crate::llr::PublicProperty;
+use crate::object_tree::Document;
+use crate::CompilerConfiguration;
+use std::fmt::Write;
+
+pub fn generate(
+    doc: &Document,
+    compiler_config: &CompilerConfiguration,
+) -> std::io::Result<String> {
+    let mut output = String::new();
+    output.push_str(
+        "// This file is auto-generated by the Slint compiler. Do not edit.\n\n\
+        import type { Brush, ComponentHandle, ImageData, Model, RgbaColor } from \"slint-ui\";\n",
+    );
+
+    for ty in doc.used_types.borrow().structs_and_enums.iter() {
+        match ty {
+            Type::Struct(s) => {
+                if let Struct { name: Some(name), node: Some(_), fields, .. } = s.as_ref() {
+                    let name = ident(name);
+                    writeln!(output, "\nexport interface {name} {{").unwrap();
+                    for (field_name, field_type) in fields {
+                        writeln!(
+                            output,
+                            "    {}: {};",
+                            ident(field_name),
+                            ts_type(field_type, Access::Read)
+                        )
+                        .unwrap();
+                    }
+                    writeln!(output, "}}").unwrap();
+                    writeln!(
+                        output,
+                        "export declare const {name}: new (properties?: Partial<{name}>) => {name};"
+                    )
+                    .unwrap();
+                }
+            }
+            Type::Enumeration(en) => generate_enum(&mut output, en),
+            _ => {}
+        }
+    }
+
+    let llr = crate::llr::lower_to_item_tree::lower_to_item_tree(doc, compiler_config)?;
+
+    let exported_globals = llr.globals.iter().filter(|glob| glob.exported).collect::<Vec<_>>();
+    for glob in &exported_globals {
+        writeln!(output, "\nexport interface {} {{", ident(&glob.name)).unwrap();
+        generate_properties(&mut output, &glob.public_properties);
+        writeln!(output, "}}").unwrap();
+    }
+
+    for component in &llr.public_components {
+        let name = ident(&component.name);
+
+        writeln!(output, "\nexport interface {name}Properties {{").unwrap();
+        for p in component.public_properties.iter().filter(|p| !p.read_only) {
+            match &p.ty {
+                Type::Function(_) => {}
+                Type::Callback(callback) => {
+                    writeln!(output, "    {}?: {};", ident(&p.name), callback_type(callback))
+                        .unwrap();
+                }
+                ty => writeln!(output, "    {}?: {};", ident(&p.name), ts_type(ty, Access::Write))
+                    .unwrap(),
+            }
+        }
+        writeln!(output, "}}").unwrap();
+
+        writeln!(output, "\nexport interface {name} extends ComponentHandle {{").unwrap();
+        generate_properties(&mut output, &component.public_properties);
+        for glob in &exported_globals {
+            let glob_name = ident(&glob.name);
+            for alias in std::iter::once(&glob.name).chain(glob.aliases.iter()) {
+                writeln!(output, "    readonly {}: {glob_name};", ident(alias)).unwrap();
+            }
+        }
+        writeln!(output, "}}").unwrap();
+        writeln!(
+            output,
+            "export declare const {name}: new (properties?: {name}Properties) => {name};"
+        )
+        .unwrap();
+    }
+
+    Ok(output)
+}
+
+fn generate_enum(output: &mut String, en: &Enumeration) {
+    let name = ident(&en.name);
+    let values = en.values.iter().map(|v| format!("{v:?}")).collect::<Vec<_>>();
+    writeln!(output, "\nexport type {name} = {};", values.join(" | ")).unwrap();
+    writeln!(output, "export declare const {name}: {{").unwrap();
+    for value in &en.values {
+        let value = ident(value);
+        writeln!(output, "    readonly {value}: {value:?};").unwrap();
+    }
+    writeln!(output, "}};").unwrap();
+}
+
+/// The properties, callbacks and functions of a component or a global
+fn generate_properties(output: &mut String, properties: &[PublicProperty]) {
+    for p in properties {
+        let name = ident(&p.name);
+        match &p.ty {
+            Type::Function(function) => {
+                let args = function
+                    .args
+                    .iter()
+                    .enumerate()
+                    .map(|(i, arg)| {
+                        format!("{}: {}", arg_name(function, i), ts_type(arg, Access::Write))
+                    })
+                    .collect::<Vec<_>>();
+                writeln!(
+                    output,
+                    "    {name}({}): {};",
+                    args.join(", "),
+                    ts_type(&function.return_type, Access::Read)
+                )
+                .unwrap();
+            }
+            Type::Callback(callback) => {
+                writeln!(output, "    {name}: {};", callback_type(callback)).unwrap();
+            }
+            ty => {
+                let read = ts_type(ty, Access::Read);
+                let write = ts_type(ty, Access::Write);
+                if p.read_only {
+                    writeln!(output, "    readonly {name}: {read};").unwrap();
+                } else if read == write {
+                    writeln!(output, "    {name}: {read};").unwrap();
+                } else {
+                    writeln!(output, "    get {name}(): {read};").unwrap();
+                    writeln!(output, "    set {name}(value: {write});").unwrap();
+                }
+            }
+        }
+    }
+}
+
+/// The type of a callback handler: the arguments come from Slint, and the return value goes to Slint
+fn callback_type(callback: &crate::langtype::Function) -> String {
+    let args = callback
+        .args
+        .iter()
+        .enumerate()
+        .map(|(i, arg)| format!("{}: {}", arg_name(callback, i), ts_type(arg, Access::Read)))
+        .collect::<Vec<_>>();
+    format!("({}) => {}", args.join(", "), ts_type(&callback.return_type, Access::Write))
+}
+
+fn arg_name(function: &crate::langtype::Function, index: usize) -> String {
+    interpreter_api_arg_name(function, index, |_| false)
+}
+
+fn ts_type(ty: &Type, access: Access) -> String {
+    match ty {
+        Type::Float32
+        | Type::Int32
+        | Type::Duration
+        | Type::Angle
+        | Type::PhysicalLength
+        | Type::LogicalLength
+        | Type::Rem
+        | Type::Percent
+        | Type::UnitProduct(_) => "number".into(),
+        Type::String => "string".into(),
+        Type::Bool => "boolean".into(),
+        Type::Void => "void".into(),
+        Type::Color | Type::Brush => match access {
+            Access::Read => "Brush".into(),
+            Access::Write => "Brush | RgbaColor | string".into(),
+        },
+        Type::Image => "ImageData".into(),
+        Type::Array(inner) => match access {
+            Access::Read => format!("Model<{}>", ts_type(inner, Access::Read)),
+            Access::Write => {
+                let inner = ts_type(inner, Access::Read);
+                format!("Model<{inner}> | {inner}[]")
+            }
+        },
+        Type::Model => "Model<any>".into(),
+        Type::Struct(s) => match s.as_ref() {
+            Struct { name: Some(name), node: Some(_), .. } => match access {
+                Access::Read => ident(name),
+                Access::Write => format!("Partial<{}>", ident(name)),
+            },
+            Struct { fields, .. } => {
+                let fields = fields
+                    .iter()
+                    .map(|(name, ty)| format!("{}: {}", ident(name), ts_type(ty, access)))
+                    .collect::<Vec<_>>();
+                format!("{{ {} }}", fields.join("; "))
+            }
+        },
+        Type::Enumeration(en) => ident(&en.name),
+        _ => "unknown".into(),
+    }
+}
+
+#[test]
+fn test_typescript_declarations() {
+    let source = r#"
+export struct Person { name: string, birth-year: int }
+export enum Direction { left, top-right }
+export global Logic {
+    in-out property <[Person]> people;
+    callback greet(person: Person) -> string;
+}
+export component App inherits Window {
+    in property <color> background-color;
+    out property <int> count;
+    in-out property <Direction> direction;
+    callback clicked(int, string);
+    public function reset(value: int) -> bool { return true; }
+}
+"#;
+    let mut compiler_config =
+        CompilerConfiguration::new(crate::generator::OutputFormat::TypeScript);
+    compiler_config.style = Some("fluent".into());
+    let mut test_diags = crate::diagnostics::BuildDiagnostics::default();
+    let doc_node = crate::parser::parse(
+        source.into(),
+        Some(std::path::Path::new("app.slint")),
+        &mut test_diags,
+    );
+    let (doc, diag, _) =
+        spin_on::spin_on(crate::compile_syntax_node(doc_node, test_diags, compiler_config.clone()));
+    assert!(!diag.has_errors(), "slint compile error {:#?}", diag.to_string_vec());
+
+    let output = generate(&doc, &compiler_config).unwrap();
+    assert_eq!(
+        output,
+        r#"// This file is auto-generated by the Slint compiler. Do not edit.
+
+import type { Brush, ComponentHandle, ImageData, Model, RgbaColor } from "slint-ui";
+
+export type Direction = "left" | "top-right";
+export declare const Direction: {
+    readonly left: "left";
+    readonly top_right: "top_right";
+};
+
+export interface Person {
+    birth_year: number;
+    name: string;
+}
+export declare const Person: new (properties?: Partial<Person>) => Person;
+
+export interface Logic {
+    greet: (person: Person) => string;
+    get people(): Model<Person>;
+    set people(value: Model<Person> | Person[]);
+}
+
+export interface AppProperties {
+    background_color?: Brush | RgbaColor | string;
+    clicked?: (arg_0: number, arg_1: string) => void;
+    direction?: Direction;
+}
+
+export interface App extends ComponentHandle {
+    get background_color(): Brush;
+    set background_color(value: Brush | RgbaColor | string);
+    clicked: (arg_0: number, arg_1: string) => void;
+    readonly count: number;
+    direction: Direction;
+    reset(value: number): boolean;
+    readonly Logic: Logic;
+}
+export declare const App: new (properties?: AppProperties) => App;
+"#
+    );
+}
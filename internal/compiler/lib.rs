@@ -34,6 +34,7 @@
 pub mod object_tree;
 pub mod parser;
 pub mod pathutils;
+pub mod statistics;
 #[cfg(feature = "bundle-translations")]
 pub mod translations;
 pub mod typeloader;
@@ -155,6 +156,12 @@ pub struct CompilerConfiguration {
     /// Generate debug information for elements (ids, type names)
     pub debug_info: bool,
 
+    /// Warn about unused private properties, functions, callbacks, struct fields and imports
+    pub warn_unused: bool,
+
+    /// Measure the time spent in each compiler pass, for [`statistics::Statistics`]
+    pub collect_statistics: bool,
+
     /// Generate debug hooks to inspect/override properties.
     pub debug_hooks: Option<std::hash::RandomState>,
 
@@ -210,6 +217,8 @@ pub fn new(output_format: OutputFormat) -> Self {
 
         let debug_info = std::env::var_os("SLINT_EMIT_DEBUG_INFO").is_some();
 
+        let warn_unused = std::env::var_os("SLINT_WARN_UNUSED").is_some();
+
         let cpp_namespace = match output_format {
             #[cfg(feature = "cpp")]
             OutputFormat::Cpp(config) => match config.namespace {
@@ -238,6 +247,8 @@ pub fn new(output_format: OutputFormat) -> Self {
             cpp_namespace,
             error_on_binding_loop_with_window_layout: false,
             debug_info,
+            warn_unused,
+            collect_statistics: false,
             debug_hooks: None,
             components_to_generate: ComponentSelection::ExportedWindows,
             #[cfg(feature = "software-renderer")]
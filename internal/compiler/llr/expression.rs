@@ -158,6 +158,15 @@ pub enum Expression {
         stops: Vec<(Expression, Expression)>,
     },
 
+    ConicGradient {
+        from_angle: Box<Expression>,
+        /// The center, as a fraction of the width and height of the item
+        center_x: Box<Expression>,
+        center_y: Box<Expression>,
+        /// First expression in the tuple is a color, second expression is the stop position
+        stops: Vec<(Expression, Expression)>,
+    },
+
     EnumerationValue(crate::langtype::EnumerationValue),
 
     LayoutCacheAccess {
@@ -311,6 +320,7 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             Self::EasingCurve(_) => Type::Easing,
             Self::LinearGradient { .. } => Type::Brush,
             Self::RadialGradient { .. } => Type::Brush,
+            Self::ConicGradient { .. } => Type::Brush,
             Self::EnumerationValue(e) => Type::Enumeration(e.enumeration.clone()),
             Self::LayoutCacheAccess { .. } => Type::LogicalLength,
             Self::BoxLayoutFunction { sub_expression, .. } => sub_expression.ty(ctx),
@@ -384,6 +394,15 @@ macro_rules! visit_impl {
                     $visitor(b);
                 }
             }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+                $visitor(from_angle);
+                $visitor(center_x);
+                $visitor(center_y);
+                for (a, b) in stops {
+                    $visitor(a);
+                    $visitor(b);
+                }
+            }
             Expression::EnumerationValue(_) => {}
             Expression::LayoutCacheAccess { repeater_index, .. } => {
                 if let Some(repeater_index) = repeater_index {
@@ -440,9 +459,12 @@ pub fn visit_property_references(
                 Expression::PropertyReference(p) => p,
                 Expression::CallBackCall { callback, .. } => callback,
                 Expression::PropertyAssignment { property, .. } => {
-                    if let Some((a, map)) = &ctx.property_info(property).animation {
+                    if let Some(((a, keyframes), map)) = &ctx.property_info(property).animation {
                         let ctx2 = map.map_context(ctx);
                         a.visit_property_references(&ctx2, visitor);
+                        for (_, value) in keyframes {
+                            value.visit_property_references(&ctx2, visitor);
+                        }
                     }
                     property
                 }
@@ -752,7 +774,7 @@ fn arg_type(&self, index: usize) -> &Type {
 pub(crate) struct PropertyInfoResult<'a> {
     pub analysis: Option<&'a crate::object_tree::PropertyAnalysis>,
     pub binding: Option<(&'a super::BindingExpression, ContextMap)>,
-    pub animation: Option<(&'a Expression, ContextMap)>,
+    pub animation: Option<(&'a (Expression, super::Keyframes), ContextMap)>,
     pub property_decl: Option<&'a super::Property>,
 }
 
@@ -42,10 +42,13 @@ pub fn ty(&self, ctx: &dyn super::TypeResolutionContext) -> Type {
     }
 }
 
+/// The keyframes of an animation: their offset between 0 and 1, and the constant value of the property at that offset
+pub type Keyframes = Vec<(f32, Expression)>;
+
 #[derive(Debug, Clone)]
 pub enum Animation {
     /// The expression is a Struct with the animation fields
-    Static(Expression),
+    Static(Expression, Keyframes),
     Transition(Expression),
 }
 
@@ -265,7 +268,7 @@ pub struct SubComponent {
     pub property_init: Vec<(PropertyReference, BindingExpression)>,
     pub change_callbacks: Vec<(PropertyReference, MutExpression)>,
     /// The animation for properties which are animated
-    pub animations: HashMap<PropertyReference, Expression>,
+    pub animations: HashMap<PropertyReference, (Expression, Keyframes)>,
     pub two_way_bindings: Vec<(PropertyReference, PropertyReference)>,
     pub const_properties: Vec<PropertyReference>,
     /// Code that is run in the sub component constructor, after property initializations
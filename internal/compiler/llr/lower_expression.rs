@@ -10,7 +10,7 @@
 use smol_str::{format_smolstr, SmolStr};
 
 use super::lower_to_item_tree::{LoweredElement, LoweredSubComponentMapping, LoweringState};
-use super::{Animation, PropertyIdx, PropertyReference, RepeatedElementIdx};
+use super::{Animation, Keyframes, PropertyIdx, PropertyReference, RepeatedElementIdx};
 use crate::expression_tree::{BuiltinFunction, Callable, Expression as tree_Expression};
 use crate::langtype::{EnumerationValue, Struct, Type};
 use crate::layout::Orientation;
@@ -232,6 +232,17 @@ pub fn lower_expression(
                 .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
                 .collect::<_>(),
         },
+        tree_Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+            llr_Expression::ConicGradient {
+                from_angle: Box::new(lower_expression(from_angle, ctx)),
+                center_x: Box::new(lower_expression(center_x, ctx)),
+                center_y: Box::new(lower_expression(center_y, ctx)),
+                stops: stops
+                    .iter()
+                    .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
+                    .collect::<_>(),
+            }
+        }
         tree_Expression::EnumerationValue(e) => llr_Expression::EnumerationValue(e.clone()),
         tree_Expression::ReturnStatement(..) => {
             panic!("The remove return pass should have removed all return")
@@ -492,9 +503,33 @@ fn animation_fields() -> impl Iterator<Item = (SmolStr, Type)> {
             ),
             (SmolStr::new_static("easing"), Type::Easing),
             (SmolStr::new_static("delay"), Type::Int32),
+            (SmolStr::new_static("stiffness"), Type::Float32),
+            (SmolStr::new_static("damping"), Type::Float32),
+            (SmolStr::new_static("mass"), Type::Float32),
         ])
     }
 
+    fn lower_keyframes(a: &ElementRc, ctx: &mut ExpressionLoweringCtx<'_>) -> Keyframes {
+        let a = a.borrow();
+        let Some(binding) = a.bindings.get("keyframes") else { return Keyframes::new() };
+        let binding = binding.borrow();
+        let tree_Expression::Array { values, .. } = binding.expression.ignore_debug_hooks() else {
+            return Keyframes::new();
+        };
+        values
+            .iter()
+            .filter_map(|keyframe| match keyframe {
+                tree_Expression::Struct { values, .. } => match values.get("offset")? {
+                    tree_Expression::NumberLiteral(offset, _) => {
+                        Some((*offset as f32, lower_expression(values.get("value")?, ctx)))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
     fn animation_ty() -> Rc<Struct> {
         Rc::new(Struct {
             fields: animation_fields().collect(),
@@ -505,7 +540,9 @@ fn animation_ty() -> Rc<Struct> {
     }
 
     match a {
-        PropertyAnimation::Static(a) => Animation::Static(lower_animation_element(a, ctx)),
+        PropertyAnimation::Static(a) => {
+            Animation::Static(lower_animation_element(a, ctx), lower_keyframes(a, ctx))
+        }
         PropertyAnimation::Transition { state_ref, animations } => {
             let set_state = llr_Expression::StoreLocalVariable {
                 name: "state".into(),
@@ -681,6 +718,18 @@ fn solve_layout(
             let (padding, spacing) = generate_layout_padding_and_spacing(&layout.geometry, o, ctx);
             let bld = box_layout_data(layout, o, ctx);
             let size = layout_geometry_size(&layout.geometry.rect, o, ctx);
+            let direction_enum =
+                crate::typeregister::BUILTIN.with(|e| e.enums.LayoutDirection.clone());
+            let direction = match o {
+                Orientation::Horizontal => llr_Expression::BuiltinFunctionCall {
+                    function: BuiltinFunction::GetWindowLayoutDirection,
+                    arguments: vec![],
+                },
+                Orientation::Vertical => llr_Expression::EnumerationValue(EnumerationValue {
+                    value: direction_enum.default_value,
+                    enumeration: direction_enum.clone(),
+                }),
+            };
             let data = make_struct(
                 "BoxLayoutData",
                 [
@@ -693,6 +742,7 @@ fn solve_layout(
                             .with(|e| Type::Enumeration(e.enums.LayoutAlignment.clone())),
                         bld.alignment,
                     ),
+                    ("direction", Type::Enumeration(direction_enum), direction),
                     ("cells", bld.cells.ty(ctx), bld.cells),
                 ],
             );
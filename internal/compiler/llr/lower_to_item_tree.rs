@@ -465,8 +465,8 @@ fn lower_sub_component(
         {
             if let Some(anim) = binding.animation.as_ref() {
                 match super::lower_expression::lower_animation(anim, &mut ctx) {
-                    Animation::Static(anim) => {
-                        sub_component.animations.insert(prop, anim);
+                    Animation::Static(anim, keyframes) => {
+                        sub_component.animations.insert(prop, (anim, keyframes));
                     }
                     Animation::Transition(_) => {
                         // Cannot set a property with a transition anyway
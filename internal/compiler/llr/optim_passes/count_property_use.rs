@@ -166,9 +166,13 @@ fn visit_property(pr: &PropertyReference, ctx: &EvaluationContext) {
 fn visit_binding_expression(binding: &BindingExpression, ctx: &EvaluationContext) {
     binding.expression.borrow().visit_property_references(ctx, &mut visit_property);
     match &binding.animation {
-        Some(Animation::Static(e) | Animation::Transition(e)) => {
-            e.visit_property_references(ctx, &mut visit_property)
+        Some(Animation::Static(e, keyframes)) => {
+            e.visit_property_references(ctx, &mut visit_property);
+            for (_, value) in keyframes {
+                value.visit_property_references(ctx, &mut visit_property);
+            }
         }
+        Some(Animation::Transition(e)) => e.visit_property_references(ctx, &mut visit_property),
         None => (),
     }
 }
@@ -63,6 +63,7 @@ fn expression_cost(exp: &Expression, ctx: &EvaluationContext) -> isize {
         Expression::EasingCurve(_) => 1,
         Expression::LinearGradient { .. } => ALLOC_COST,
         Expression::RadialGradient { .. } => ALLOC_COST,
+        Expression::ConicGradient { .. } => ALLOC_COST,
         Expression::EnumerationValue(_) => 0,
         Expression::LayoutCacheAccess { .. } => PROPERTY_ACCESS_COST,
         Expression::BoxLayoutFunction { .. } => return isize::MAX,
@@ -86,6 +87,7 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
     match function {
         BuiltinFunction::GetWindowScaleFactor => PROPERTY_ACCESS_COST,
         BuiltinFunction::GetWindowDefaultFontSize => PROPERTY_ACCESS_COST,
+        BuiltinFunction::GetWindowLayoutDirection => PROPERTY_ACCESS_COST,
         BuiltinFunction::AnimationTick => PROPERTY_ACCESS_COST,
         BuiltinFunction::Debug => isize::MAX,
         BuiltinFunction::Mod => 10,
@@ -119,6 +121,16 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::StringCharacterCount => 50,
         BuiltinFunction::StringToLowercase => ALLOC_COST,
         BuiltinFunction::StringToUppercase => ALLOC_COST,
+        BuiltinFunction::StringContains => 50,
+        BuiltinFunction::StringStartsWith => 50,
+        BuiltinFunction::StringEndsWith => 50,
+        BuiltinFunction::StringFind => 50,
+        BuiltinFunction::StringSubstring => ALLOC_COST,
+        BuiltinFunction::StringReplace => ALLOC_COST,
+        BuiltinFunction::StringTrim => ALLOC_COST,
+        BuiltinFunction::StringSplit => ALLOC_COST,
+        BuiltinFunction::StringPadStart => ALLOC_COST,
+        BuiltinFunction::StringPadEnd => ALLOC_COST,
         BuiltinFunction::ColorRgbaStruct => 50,
         BuiltinFunction::ColorHsvaStruct => 50,
         BuiltinFunction::ColorBrighter => 50,
@@ -331,6 +331,14 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
                 "@radial-gradient(circle, {})",
                 stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", ")
             ),
+            Expression::ConicGradient { from_angle, center_x, center_y, stops } => write!(
+                f,
+                "@conic-gradient(from {} at {} {}, {})",
+                e(from_angle),
+                e(center_x),
+                e(center_y),
+                stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", ")
+            ),
             Expression::EnumerationValue(x) => write!(f, "{x}"),
             Expression::LayoutCacheAccess { layout_cache_prop, index, repeater_index: None } => {
                 write!(f, "{}[{}]", DisplayPropertyRef(layout_cache_prop, ctx), index)
@@ -14,7 +14,7 @@
 use crate::object_tree::{ElementRc, PropertyVisibility};
 use crate::parser::NodeOrToken;
 use crate::typeregister::TypeRegister;
-use smol_str::{SmolStr, ToSmolStr};
+use smol_str::{format_smolstr, SmolStr, ToSmolStr};
 use std::cell::RefCell;
 
 mod named_colors;
@@ -39,6 +39,10 @@ pub struct LookupCtx<'a> {
     /// The name of the arguments of the callback or function
     pub arguments: Vec<SmolStr>,
 
+    /// The local variables declared with `let`. There is one entry per nested code block,
+    /// the innermost block being last.
+    pub local_variables: Vec<Vec<(SmolStr, Type)>>,
+
     /// The type register in which to look for Globals
     pub type_register: &'a TypeRegister,
 
@@ -59,6 +63,7 @@ pub fn empty_context(type_register: &'a TypeRegister, diag: &'a mut BuildDiagnos
             component_scope: Default::default(),
             diag,
             arguments: Default::default(),
+            local_variables: Default::default(),
             type_register,
             type_loader: None,
             current_token: None,
@@ -218,6 +223,31 @@ fn lookup(&self, ctx: &LookupCtx, name: &SmolStr) -> Option<LookupResult> {
     }
 }
 
+struct LocalVariableLookup;
+impl LookupObject for LocalVariableLookup {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&SmolStr, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        for (name, ty) in ctx.local_variables.iter().rev().flatten() {
+            let expression =
+                Expression::ReadLocalVariable { name: local_variable_name(name), ty: ty.clone() };
+            if let Some(r) = f(name, expression.into()) {
+                return Some(r);
+            }
+        }
+        None
+    }
+}
+
+/// The name of the variable in the expression tree for a local variable declared with `let`.
+///
+/// The prefix avoids clashes with the local variables introduced by the compiler passes.
+pub fn local_variable_name(name: &str) -> SmolStr {
+    format_smolstr!("local_{name}")
+}
+
 struct ArgumentsLookup;
 impl LookupObject for ArgumentsLookup {
     fn for_each_entry<R>(
@@ -830,16 +860,22 @@ fn for_each_entry<R>(
 
 pub fn global_lookup() -> impl LookupObject {
     (
-        ArgumentsLookup,
+        LocalVariableLookup,
         (
-            SpecialIdLookup,
+            ArgumentsLookup,
             (
-                IdLookup,
+                SpecialIdLookup,
                 (
-                    InScopeLookup,
+                    IdLookup,
                     (
-                        LookupType,
-                        (BuiltinNamespaceLookup, (ReturnTypeSpecificLookup, BuiltinFunctionLookup)),
+                        InScopeLookup,
+                        (
+                            LookupType,
+                            (
+                                BuiltinNamespaceLookup,
+                                (ReturnTypeSpecificLookup, BuiltinFunctionLookup),
+                            ),
+                        ),
                     ),
                 ),
             ),
@@ -941,6 +977,16 @@ fn for_each_entry<R>(
             .or_else(|| f("character-count", function_call(BuiltinFunction::StringCharacterCount)))
             .or_else(|| f("to-lowercase", member_function(BuiltinFunction::StringToLowercase)))
             .or_else(|| f("to-uppercase", member_function(BuiltinFunction::StringToUppercase)))
+            .or_else(|| f("contains", member_function(BuiltinFunction::StringContains)))
+            .or_else(|| f("starts-with", member_function(BuiltinFunction::StringStartsWith)))
+            .or_else(|| f("ends-with", member_function(BuiltinFunction::StringEndsWith)))
+            .or_else(|| f("find", member_function(BuiltinFunction::StringFind)))
+            .or_else(|| f("substring", member_function(BuiltinFunction::StringSubstring)))
+            .or_else(|| f("replace", member_function(BuiltinFunction::StringReplace)))
+            .or_else(|| f("trim", member_function(BuiltinFunction::StringTrim)))
+            .or_else(|| f("split", member_function(BuiltinFunction::StringSplit)))
+            .or_else(|| f("pad-start", member_function(BuiltinFunction::StringPadStart)))
+            .or_else(|| f("pad-end", member_function(BuiltinFunction::StringPadEnd)))
     }
 }
 struct ColorExpression<'a>(&'a Expression);
@@ -67,6 +67,9 @@ pub struct Document {
 
     /// The popup_menu_impl
     pub popup_menu_impl: Option<Rc<Component>>,
+
+    /// The time spent in each compiler pass, when [`crate::CompilerConfiguration::collect_statistics`] is set
+    pub pass_timings: Vec<(&'static str, std::time::Duration)>,
 }
 
 impl Document {
@@ -266,6 +269,7 @@ pub fn from_node(
             translation_builder: None,
             used_types: Default::default(),
             popup_menu_impl: None,
+            pass_timings: Vec::new(),
         }
     }
 
@@ -620,11 +624,12 @@ fn clone(&self) -> Self {
         fn deep_clone(e: &ElementRc) -> ElementRc {
             let e = e.borrow();
             debug_assert!(e.children.is_empty());
-            debug_assert!(e.property_declarations.is_empty());
             debug_assert!(e.states.is_empty() && e.transitions.is_empty());
             Rc::new(RefCell::new(Element {
                 id: e.id.clone(),
                 base_type: e.base_type.clone(),
+                // Only the `keyframes` property can be declared in an animation
+                property_declarations: e.property_declarations.clone(),
                 bindings: e.bindings.clone(),
                 property_analysis: e.property_analysis.clone(),
                 enclosing_component: e.enclosing_component.clone(),
@@ -1405,6 +1410,7 @@ pub fn from_node(
                             &anim,
                             &prop_name_token,
                             lookup_result.property_type,
+                            false,
                             diag,
                             tr,
                         ) {
@@ -2020,10 +2026,11 @@ fn animation_element_from_node(
     anim: &syntax_nodes::PropertyAnimation,
     prop_name: &syntax_nodes::QualifiedName,
     prop_type: Type,
+    in_transition: bool,
     diag: &mut BuildDiagnostics,
     tr: &TypeRegister,
 ) -> Option<ElementRc> {
-    let anim_type = tr.property_animation_type_for_property(prop_type);
+    let anim_type = tr.property_animation_type_for_property(prop_type.clone());
     if !matches!(anim_type, ElementType::Builtin(..)) {
         diag.push_error(
             format!(
@@ -2044,6 +2051,48 @@ fn animation_element_from_node(
             diag,
         );
 
+        let mut keyframes = anim.Keyframes();
+        if let Some(keyframes_node) = keyframes.next() {
+            if in_transition {
+                diag.push_error(
+                    "Keyframes are not supported in transitions".into(),
+                    &keyframes_node,
+                );
+            }
+            if anim_element.bindings.contains_key("stiffness") {
+                diag.push_error(
+                    "Keyframes cannot be used in a spring animation".into(),
+                    &keyframes_node,
+                );
+            }
+            for extra in keyframes {
+                diag.push_error("Duplicated keyframes".into(), &extra);
+            }
+            // The keyframes are an array of `{ offset: float, value: <property type> }`,
+            // stored as a binding of the animation element
+            let keyframe_type = Type::Struct(Rc::new(Struct {
+                fields: BTreeMap::from([
+                    (SmolStr::new_static("offset"), Type::Float32),
+                    (SmolStr::new_static("value"), prop_type),
+                ]),
+                name: None,
+                node: None,
+                rust_attributes: None,
+            }));
+            anim_element.property_declarations.insert(
+                SmolStr::new_static("keyframes"),
+                PropertyDeclaration {
+                    property_type: Type::Array(keyframe_type.into()),
+                    node: Some(keyframes_node.clone().into()),
+                    ..Default::default()
+                },
+            );
+            anim_element.bindings.insert(
+                SmolStr::new_static("keyframes"),
+                BindingExpression::new_uncompiled(keyframes_node.into()).into(),
+            );
+        }
+
         apply_default_type_properties(&mut anim_element);
 
         Some(Rc::new(RefCell::new(anim_element)))
@@ -2546,7 +2595,7 @@ fn from_node(
                 .filter_map(|(pa, qn)| {
                     lookup_property_from_qualified_name_for_state(qn.clone(), r, diag).and_then(
                         |(ne, prop_type)| {
-                            animation_element_from_node(&pa, &qn, prop_type, diag, tr)
+                            animation_element_from_node(&pa, &qn, prop_type, true, diag, tr)
                                 .map(|anim_element| (ne, qn.to_source_location(), anim_element))
                         },
                     )
@@ -353,7 +353,11 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// Declaration of a property.
         PropertyDeclaration-> [ ?Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding ],
         /// QualifiedName are the properties name
-        PropertyAnimation-> [ *QualifiedName, *Binding ],
+        PropertyAnimation-> [ *QualifiedName, *Binding, *Keyframes ],
+        /// `keyframes: [0%: value, ...]` in a PropertyAnimation
+        Keyframes -> [ *Keyframe ],
+        /// `50%: value` inside Keyframes, the offset is a NumberLiteral token
+        Keyframe -> [ Expression ],
         /// `changed xxx => {...}`  where `xxx` is the DeclaredIdentifier
         PropertyChangedCallback-> [ DeclaredIdentifier, CodeBlock ],
         /// wraps Identifiers, like `Rectangle` or `SomeModule.SomeType`
@@ -367,8 +371,10 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// the right-hand-side of a binding
         // Fixme: the test should be a or
         BindingExpression-> [ ?CodeBlock, ?Expression ],
-        CodeBlock-> [ *Expression, *ReturnStatement ],
+        CodeBlock-> [ *Expression, *ReturnStatement, *LetStatement ],
         ReturnStatement -> [ ?Expression ],
+        /// `let foo: type = expression;` (the type is optional)
+        LetStatement -> [ DeclaredIdentifier, ?Type, Expression ],
         // FIXME: the test should test that as alternative rather than several of them (but it can also be a literal)
         Expression-> [ ?Expression, ?FunctionCallExpression, ?IndexExpression, ?SelfAssignment,
                        ?ConditionalExpression, ?QualifiedName, ?BinaryExpression, ?Array, ?ObjectLiteral,
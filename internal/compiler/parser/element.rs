@@ -433,6 +433,8 @@ fn parse_property_declaration(p: &mut impl Parser) {
 /// animate x { duration: 1000; }
 /// animate x, foo.y {  }
 /// animate * {  }
+/// animate x { duration: 1s; keyframes: [0%: 0px, 50%: 20px, 100%: 0px]; }
+/// animate x { keyframes: [50%: 20px,] }
 /// ```
 fn parse_property_animation(p: &mut impl Parser) {
     debug_assert_eq!(p.peek().as_str(), "animate");
@@ -457,6 +459,12 @@ fn parse_property_animation(p: &mut impl Parser) {
             }
             SyntaxKind::Eof => return,
             SyntaxKind::Identifier => match p.nth(1).kind() {
+                SyntaxKind::Colon
+                    if p.peek().as_str() == "keyframes"
+                        && p.nth(2).kind() == SyntaxKind::LBracket =>
+                {
+                    parse_keyframes(&mut *p)
+                }
                 SyntaxKind::Colon => parse_property_binding(&mut *p),
                 _ => {
                     p.consume();
@@ -471,6 +479,31 @@ fn parse_property_animation(p: &mut impl Parser) {
     }
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,Keyframes
+/// keyframes: [0%: 0px, 50%: 20px, 100%: 0px];
+/// keyframes: [25%: red, 75%: blue,]
+/// keyframes: []
+/// ```
+fn parse_keyframes(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().as_str(), "keyframes");
+    let mut p = p.start_node(SyntaxKind::Keyframes);
+    p.expect(SyntaxKind::Identifier); // "keyframes"
+    p.expect(SyntaxKind::Colon);
+    p.expect(SyntaxKind::LBracket);
+    while !matches!(p.nth(0).kind(), SyntaxKind::RBracket | SyntaxKind::Eof) {
+        let mut p = p.start_node(SyntaxKind::Keyframe);
+        p.expect(SyntaxKind::NumberLiteral);
+        p.expect(SyntaxKind::Colon);
+        parse_expression(&mut *p);
+        if !p.test(SyntaxKind::Comma) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RBracket);
+    p.test(SyntaxKind::Semicolon);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,PropertyChangedCallback
 /// changed the-property => { x = y; }
@@ -245,13 +245,16 @@ fn parse_at_keyword(p: &mut impl Parser) {
         "radial-gradient" | "radial_gradient" => {
             parse_gradient(p);
         }
+        "conic-gradient" | "conic_gradient" => {
+            parse_gradient(p);
+        }
         "tr" => {
             parse_tr(p);
         }
         _ => {
             p.consume();
             p.test(SyntaxKind::Identifier); // consume the identifier, so that autocomplete works
-            p.error("Expected 'image-url', 'tr', 'linear-gradient' or 'radial-gradient' after '@'");
+            p.error("Expected 'image-url', 'tr', 'linear-gradient', 'radial-gradient' or 'conic-gradient' after '@'");
         }
     }
 }
@@ -353,15 +356,38 @@ fn parse_template_string(p: &mut impl Parser) {
 /// @linear-gradient(217deg, rgba(255,0,0,0.8), rgba(255,0,0,0) 70.71%)
 /// @linear_gradient(217deg, rgba(255,0,0,0.8), rgba(255,0,0,0) 70.71%)
 /// @radial-gradient(circle, #e66465, blue 50%, #9198e5)
+/// @conic-gradient(red, blue)
+/// @conic-gradient(from -90deg at 25% 75%, red, blue 50%, red)
 /// ```
 fn parse_gradient(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::AtGradient);
     p.expect(SyntaxKind::At);
     debug_assert!(p.peek().as_str().ends_with("gradient"));
+    let is_conic = p.peek().as_str().starts_with("conic");
     p.expect(SyntaxKind::Identifier); //eg "linear-gradient"
 
     p.expect(SyntaxKind::LParent);
 
+    if is_conic {
+        // The `from` and `at` keywords are kept as identifier tokens so that `from -90deg`
+        // isn't parsed as a subtraction
+        let mut has_position = false;
+        if p.peek().as_str() == "from" {
+            p.consume();
+            parse_expression(&mut *p);
+            has_position = true;
+        }
+        if p.peek().as_str() == "at" {
+            p.consume();
+            parse_expression(&mut *p);
+            parse_expression(&mut *p);
+            has_position = true;
+        }
+        if has_position {
+            p.test(SyntaxKind::Comma);
+        }
+    }
+
     while !p.test(SyntaxKind::RParent) {
         if !parse_expression(&mut *p) {
             return;
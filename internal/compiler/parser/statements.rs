@@ -4,6 +4,7 @@
 use super::element::parse_code_block;
 use super::expressions::parse_expression;
 use super::prelude::*;
+use super::r#type::parse_type;
 
 #[cfg_attr(test, parser_test)]
 /// ```test
@@ -14,6 +15,8 @@
 /// if (true) { foo = bar; } else { bar = foo;  }
 /// return;
 /// if (true) { return 42; }
+/// let foo = 42;
+/// let foo: int = bar + 1;
 /// ```
 pub fn parse_statement(p: &mut impl Parser) -> bool {
     if p.nth(0).kind() == SyntaxKind::RBrace {
@@ -50,6 +53,11 @@ pub fn parse_statement(p: &mut impl Parser) -> bool {
         return true;
     }
 
+    if p.peek().as_str() == "let" && p.nth(1).kind() == SyntaxKind::Identifier {
+        parse_let_statement(p);
+        return true;
+    }
+
     parse_expression(p);
     if matches!(
         p.nth(0).kind(),
@@ -67,6 +75,28 @@ pub fn parse_statement(p: &mut impl Parser) -> bool {
     p.test(SyntaxKind::Semicolon)
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,LetStatement
+/// let foo = 42;
+/// let foo: string = "hello";
+/// let foo : [int] = [1, 2, 3];
+/// ```
+fn parse_let_statement(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::LetStatement);
+    debug_assert_eq!(p.peek().as_str(), "let");
+    p.expect(SyntaxKind::Identifier); // "let"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    if p.test(SyntaxKind::Colon) {
+        parse_type(&mut *p);
+    }
+    p.expect(SyntaxKind::Equal);
+    parse_expression(&mut *p);
+    p.expect(SyntaxKind::Semicolon);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,ConditionalExpression
 /// if (true) { foo = bar; } else { bar = foo;  }
@@ -7,6 +7,7 @@
 mod check_expressions;
 mod check_public_api;
 mod check_rotation;
+pub mod check_unused;
 mod clip;
 mod collect_custom_fonts;
 mod collect_globals;
@@ -58,6 +59,28 @@
 use crate::namedreference::NamedReference;
 use smol_str::SmolStr;
 
+/// Run a pass and record the time it took in the `PassTimer`, under the name of the pass function
+macro_rules! timed {
+    ($timer:ident, $module:ident :: $pass:ident ( $($arg:expr),* $(,)? ) .await) => {{
+        let start = $timer.start();
+        let result = $module::$pass($($arg),*).await;
+        $timer.record(stringify!($pass), start);
+        result
+    }};
+    ($timer:ident, $module:ident :: $pass:ident ( $($arg:expr),* $(,)? )) => {{
+        let start = $timer.start();
+        let result = $module::$pass($($arg),*);
+        $timer.record(stringify!($pass), start);
+        result
+    }};
+    ($timer:ident, $pass:ident ( $($arg:expr),* $(,)? )) => {{
+        let start = $timer.start();
+        let result = $pass($($arg),*);
+        $timer.record(stringify!($pass), start);
+        result
+    }};
+}
+
 pub fn ignore_debug_hooks(expr: &Expression) -> &Expression {
     let mut expr = expr;
     loop {
@@ -94,122 +117,165 @@ pub async fn run_passes(
 
     let global_type_registry = type_loader.global_type_registry.clone();
 
-    run_import_passes(doc, type_loader, diag);
-    check_public_api::check_public_api(doc, &type_loader.compiler_config, diag);
+    let mut timer =
+        crate::statistics::PassTimer::new(type_loader.compiler_config.collect_statistics);
+
+    timed!(timer, run_import_passes(doc, type_loader, diag));
+    if type_loader.compiler_config.warn_unused {
+        timed!(timer, check_unused::check_unused_library_files(doc, type_loader, diag));
+    }
+    timed!(timer, check_public_api::check_public_api(doc, &type_loader.compiler_config, diag));
 
-    let raw_type_loader =
-        keep_raw.then(|| crate::typeloader::snapshot_with_extra_doc(type_loader, doc).unwrap());
+    let raw_type_loader = keep_raw.then(|| {
+        let start = timer.start();
+        let raw_type_loader = crate::typeloader::snapshot_with_extra_doc(type_loader, doc).unwrap();
+        timer.record("snapshot_with_extra_doc", start);
+        raw_type_loader
+    });
 
-    collect_subcomponents::collect_subcomponents(doc);
+    timed!(timer, collect_subcomponents::collect_subcomponents(doc));
     doc.visit_all_used_components(|component| {
-        compile_paths::compile_paths(
-            component,
-            &doc.local_registry,
-            type_loader.compiler_config.embed_resources,
-            diag,
+        timed!(
+            timer,
+            compile_paths::compile_paths(
+                component,
+                &doc.local_registry,
+                type_loader.compiler_config.embed_resources,
+                diag,
+            )
         );
     });
-    lower_tabwidget::lower_tabwidget(doc, type_loader, diag).await;
-    lower_menus::lower_menus(doc, type_loader, diag).await;
-    collect_subcomponents::collect_subcomponents(doc);
+    timed!(timer, lower_tabwidget::lower_tabwidget(doc, type_loader, diag).await);
+    timed!(timer, lower_menus::lower_menus(doc, type_loader, diag).await);
+    timed!(timer, collect_subcomponents::collect_subcomponents(doc));
 
     doc.visit_all_used_components(|component| {
-        apply_default_properties_from_style::apply_default_properties_from_style(
-            component,
-            &style_metrics,
-            &palette,
-            diag,
+        timed!(
+            timer,
+            apply_default_properties_from_style::apply_default_properties_from_style(
+                component,
+                &style_metrics,
+                &palette,
+                diag,
+            )
         );
-        lower_states::lower_states(component, &doc.local_registry, diag);
-        lower_text_input_interface::lower_text_input_interface(component);
-        lower_platform::lower_platform(component, type_loader);
-        repeater_component::process_repeater_components(component);
-        lower_popups::lower_popups(component, &doc.local_registry, diag);
-        collect_init_code::collect_init_code(component);
-        lower_timers::lower_timers(component, diag);
+        timed!(timer, lower_states::lower_states(component, &doc.local_registry, diag));
+        timed!(timer, lower_text_input_interface::lower_text_input_interface(component));
+        timed!(timer, lower_platform::lower_platform(component, type_loader));
+        timed!(timer, repeater_component::process_repeater_components(component));
+        timed!(timer, lower_popups::lower_popups(component, &doc.local_registry, diag));
+        timed!(timer, collect_init_code::collect_init_code(component));
+        timed!(timer, lower_timers::lower_timers(component, diag));
     });
 
-    inlining::inline(doc, inlining::InlineSelection::InlineOnlyRequiredComponents, diag);
-    collect_subcomponents::collect_subcomponents(doc);
+    timed!(
+        timer,
+        inlining::inline(doc, inlining::InlineSelection::InlineOnlyRequiredComponents, diag)
+    );
+    timed!(timer, collect_subcomponents::collect_subcomponents(doc));
 
     for root_component in doc.exported_roots() {
-        focus_handling::call_focus_on_init(&root_component);
-        ensure_window::ensure_window(&root_component, &doc.local_registry, &style_metrics, diag);
+        timed!(timer, focus_handling::call_focus_on_init(&root_component));
+        timed!(
+            timer,
+            ensure_window::ensure_window(
+                &root_component,
+                &doc.local_registry,
+                &style_metrics,
+                diag
+            )
+        );
     }
     if let Some(popup_menu_impl) = &doc.popup_menu_impl {
-        focus_handling::call_focus_on_init(popup_menu_impl);
+        timed!(timer, focus_handling::call_focus_on_init(popup_menu_impl));
     }
 
     doc.visit_all_used_components(|component| {
-        border_radius::handle_border_radius(component, diag);
-        flickable::handle_flickable(component, &global_type_registry.borrow());
-        lower_component_container::lower_component_container(component, &doc.local_registry, diag);
-        lower_layout::lower_layouts(component, type_loader, &style_metrics, diag);
-        default_geometry::default_geometry(component, diag);
-        lower_absolute_coordinates::lower_absolute_coordinates(component);
-        z_order::reorder_by_z_order(component, diag);
-        lower_property_to_element::lower_property_to_element(
-            component,
-            "opacity",
-            core::iter::empty(),
-            None,
-            &SmolStr::new_static("Opacity"),
-            &global_type_registry.borrow(),
-            diag,
+        timed!(timer, border_radius::handle_border_radius(component, diag));
+        timed!(timer, flickable::handle_flickable(component, &global_type_registry.borrow()));
+        timed!(
+            timer,
+            lower_component_container::lower_component_container(
+                component,
+                &doc.local_registry,
+                diag
+            )
         );
-        lower_property_to_element::lower_property_to_element(
-            component,
-            "cache-rendering-hint",
-            core::iter::empty(),
-            None,
-            &SmolStr::new_static("Layer"),
-            &global_type_registry.borrow(),
-            diag,
+        timed!(timer, lower_layout::lower_layouts(component, type_loader, &style_metrics, diag));
+        timed!(timer, default_geometry::default_geometry(component, diag));
+        timed!(timer, lower_absolute_coordinates::lower_absolute_coordinates(component));
+        timed!(timer, z_order::reorder_by_z_order(component, diag));
+        timed!(
+            timer,
+            lower_property_to_element::lower_property_to_element(
+                component,
+                "opacity",
+                core::iter::empty(),
+                None,
+                &SmolStr::new_static("Opacity"),
+                &global_type_registry.borrow(),
+                diag,
+            )
         );
-        visible::handle_visible(component, &global_type_registry.borrow(), diag);
-        lower_shadows::lower_shadow_properties(component, &doc.local_registry, diag);
-        lower_property_to_element::lower_property_to_element(
-            component,
-            crate::typeregister::RESERVED_ROTATION_PROPERTIES[0].0,
-            crate::typeregister::RESERVED_ROTATION_PROPERTIES[1..]
-                .iter()
-                .map(|(prop_name, _)| *prop_name),
-            Some(&|e, prop| Expression::BinaryExpression {
-                lhs: Expression::PropertyReference(NamedReference::new(
-                    e,
-                    match prop {
-                        "rotation-origin-x" => SmolStr::new_static("width"),
-                        "rotation-origin-y" => SmolStr::new_static("height"),
-                        "rotation-angle" => return Expression::Invalid,
-                        _ => unreachable!(),
-                    },
-                ))
-                .into(),
-                op: '/',
-                rhs: Expression::NumberLiteral(2., Default::default()).into(),
-            }),
-            &SmolStr::new_static("Rotate"),
-            &global_type_registry.borrow(),
-            diag,
+        timed!(
+            timer,
+            lower_property_to_element::lower_property_to_element(
+                component,
+                "cache-rendering-hint",
+                core::iter::empty(),
+                None,
+                &SmolStr::new_static("Layer"),
+                &global_type_registry.borrow(),
+                diag,
+            )
+        );
+        timed!(timer, visible::handle_visible(component, &global_type_registry.borrow(), diag));
+        timed!(timer, lower_shadows::lower_shadow_properties(component, &doc.local_registry, diag));
+        timed!(
+            timer,
+            lower_property_to_element::lower_property_to_element(
+                component,
+                crate::typeregister::RESERVED_ROTATION_PROPERTIES[0].0,
+                crate::typeregister::RESERVED_ROTATION_PROPERTIES[1..]
+                    .iter()
+                    .map(|(prop_name, _)| *prop_name),
+                Some(&|e, prop| Expression::BinaryExpression {
+                    lhs: Expression::PropertyReference(NamedReference::new(
+                        e,
+                        match prop {
+                            "rotation-origin-x" => SmolStr::new_static("width"),
+                            "rotation-origin-y" => SmolStr::new_static("height"),
+                            "rotation-angle" => return Expression::Invalid,
+                            _ => unreachable!(),
+                        },
+                    ))
+                    .into(),
+                    op: '/',
+                    rhs: Expression::NumberLiteral(2., Default::default()).into(),
+                }),
+                &SmolStr::new_static("Rotate"),
+                &global_type_registry.borrow(),
+                diag,
+            )
         );
-        clip::handle_clip(component, &global_type_registry.borrow(), diag);
+        timed!(timer, clip::handle_clip(component, &global_type_registry.borrow(), diag));
         if type_loader.compiler_config.accessibility {
-            lower_accessibility::lower_accessibility_properties(component, diag);
+            timed!(timer, lower_accessibility::lower_accessibility_properties(component, diag));
         }
-        materialize_fake_properties::materialize_fake_properties(component);
+        timed!(timer, materialize_fake_properties::materialize_fake_properties(component));
     });
     for root_component in doc.exported_roots() {
-        lower_layout::check_window_layout(&root_component);
+        timed!(timer, lower_layout::check_window_layout(&root_component));
     }
-    collect_globals::collect_globals(doc, diag);
+    timed!(timer, collect_globals::collect_globals(doc, diag));
 
     if type_loader.compiler_config.inline_all_elements {
-        inlining::inline(doc, inlining::InlineSelection::InlineAllComponents, diag);
+        timed!(timer, inlining::inline(doc, inlining::InlineSelection::InlineAllComponents, diag));
         doc.used_types.borrow_mut().sub_components.clear();
     }
 
-    binding_analysis::binding_analysis(doc, &type_loader.compiler_config, diag);
-    unique_id::assign_unique_id(doc);
+    timed!(timer, binding_analysis::binding_analysis(doc, &type_loader.compiler_config, diag));
+    timed!(timer, unique_id::assign_unique_id(doc));
 
     doc.visit_all_used_components(|component| {
         // Don't perform the empty rectangle removal when debug info is requested, because the resulting
@@ -217,45 +283,48 @@ pub async fn run_passes(
         // but siblings or sibling children. We need a new data structure to perform a correct element tree
         // traversal.
         if !type_loader.compiler_config.debug_info {
-            optimize_useless_rectangles::optimize_useless_rectangles(component);
+            timed!(timer, optimize_useless_rectangles::optimize_useless_rectangles(component));
         }
-        move_declarations::move_declarations(component);
+        timed!(timer, move_declarations::move_declarations(component));
     });
 
-    remove_aliases::remove_aliases(doc, diag);
-    remove_return::remove_return(doc);
+    timed!(timer, remove_aliases::remove_aliases(doc, diag));
+    timed!(timer, remove_return::remove_return(doc));
 
     doc.visit_all_used_components(|component| {
         if !diag.has_errors() {
             // binding loop causes panics in const_propagation
-            const_propagation::const_propagation(component);
+            timed!(timer, const_propagation::const_propagation(component));
         }
-        deduplicate_property_read::deduplicate_property_read(component);
+        timed!(timer, deduplicate_property_read::deduplicate_property_read(component));
         if !component.is_global() {
-            resolve_native_classes::resolve_native_classes(component);
+            timed!(timer, resolve_native_classes::resolve_native_classes(component));
         }
     });
 
-    remove_unused_properties::remove_unused_properties(doc);
-    collect_structs_and_enums::collect_structs_and_enums(doc);
+    timed!(timer, remove_unused_properties::remove_unused_properties(doc));
+    timed!(timer, collect_structs_and_enums::collect_structs_and_enums(doc));
 
     doc.visit_all_used_components(|component| {
         if !component.is_global() {
-            generate_item_indices::generate_item_indices(component);
+            timed!(timer, generate_item_indices::generate_item_indices(component));
         }
     });
 
     // collect globals once more: After optimizations we might have less globals
-    collect_globals::collect_globals(doc, diag);
+    timed!(timer, collect_globals::collect_globals(doc, diag));
 
-    embed_images::embed_images(
-        doc,
-        type_loader.compiler_config.embed_resources,
-        type_loader.compiler_config.const_scale_factor,
-        &type_loader.compiler_config.resource_url_mapper,
-        diag,
-    )
-    .await;
+    timed!(
+        timer,
+        embed_images::embed_images(
+            doc,
+            type_loader.compiler_config.embed_resources,
+            type_loader.compiler_config.const_scale_factor,
+            &type_loader.compiler_config.resource_url_mapper,
+            diag,
+        )
+        .await
+    );
 
     #[cfg(feature = "bundle-translations")]
     if let Some(path) = &type_loader.compiler_config.translation_path_bundle {
@@ -298,26 +367,34 @@ pub async fn run_passes(
                 translation_builder.collect_characters_seen(&mut characters_seen);
             }
 
-            embed_glyphs::embed_glyphs(
-                doc,
-                &type_loader.compiler_config,
-                font_pixel_sizes,
-                characters_seen,
-                std::iter::once(&*doc).chain(type_loader.all_documents()),
-                diag,
+            timed!(
+                timer,
+                embed_glyphs::embed_glyphs(
+                    doc,
+                    &type_loader.compiler_config,
+                    font_pixel_sizes,
+                    characters_seen,
+                    std::iter::once(&*doc).chain(type_loader.all_documents()),
+                    diag,
+                )
             );
         }
         _ => {
             // Create font registration calls for custom fonts, unless we're embedding pre-rendered glyphs
-            collect_custom_fonts::collect_custom_fonts(
-                doc,
-                std::iter::once(&*doc).chain(type_loader.all_documents()),
-                type_loader.compiler_config.embed_resources
-                    == crate::EmbedResourcesKind::EmbedAllResources,
+            timed!(
+                timer,
+                collect_custom_fonts::collect_custom_fonts(
+                    doc,
+                    std::iter::once(&*doc).chain(type_loader.all_documents()),
+                    type_loader.compiler_config.embed_resources
+                        == crate::EmbedResourcesKind::EmbedAllResources,
+                )
             );
         }
     };
 
+    doc.pass_timings = timer.finish();
+
     raw_type_loader
 }
 
@@ -335,4 +412,7 @@ pub fn run_import_passes(
     check_expressions::check_expressions(doc, diag);
     check_rotation::check_rotation(doc, diag);
     unique_id::check_unique_id(doc, diag);
+    if type_loader.compiler_config.warn_unused {
+        check_unused::check_unused(doc, diag);
+    }
 }
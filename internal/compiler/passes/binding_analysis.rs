@@ -573,7 +573,7 @@ fn visit_implicit_layout_info_dependencies(
                 vis(&NamedReference::new(item, SmolStr::new_static("width")).into(), N);
             }
         }
-        "Text" | "TextInput" => {
+        "Text" | "StyledText" | "TextInput" => {
             vis(&NamedReference::new(item, SmolStr::new_static("text")).into(), N);
             vis(&NamedReference::new(item, SmolStr::new_static("font-family")).into(), N);
             vis(&NamedReference::new(item, SmolStr::new_static("font-size")).into(), N);
@@ -0,0 +1,398 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Warn about the symbols of a document, and the files of a library, that are never used.
+//!
+//! This is opt-in with [`crate::CompilerConfiguration::warn_unused`].
+//! A `// slint: allow(unused)` comment placed before a declaration, or before one of the
+//! elements, components or structs that contain it, silences the warnings.
+
+use crate::diagnostics::{BuildDiagnostics, SourceFileInner, SourceLocation, Span};
+use crate::expression_tree::Expression;
+use crate::langtype::{ElementType, Struct, Type};
+use crate::object_tree::{Document, ElementRc, PropertyVisibility};
+use crate::parser::{identifier_text, normalize_identifier, syntax_nodes, SyntaxKind, SyntaxNode};
+use itertools::Either;
+use smol_str::SmolStr;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+pub fn check_unused(doc: &Document, diag: &mut BuildDiagnostics) {
+    let Some(doc_node) = &doc.node else { return };
+    if doc_node.source_file.path().starts_with("builtin:") {
+        return;
+    }
+    check_unused_declarations(doc, diag);
+    check_unused_struct_fields(doc, diag);
+    check_unused_imports(doc_node, diag);
+}
+
+/// Returns true if a `// slint: allow(unused)` comment precedes the node or one of its ancestors
+fn is_allowed(node: &SyntaxNode) -> bool {
+    std::iter::successors(Some(node.clone()), |n| n.parent()).any(|n| {
+        std::iter::successors(n.first_token().and_then(|t| t.prev_token()), |t| t.prev_token())
+            .take_while(|t| matches!(t.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment))
+            .any(|t| {
+                t.kind() == SyntaxKind::Comment
+                    && t.text()
+                        .chars()
+                        .filter(|c| !c.is_whitespace())
+                        .eq("//slint:allow(unused)".chars())
+            })
+    })
+}
+
+/// The node to report the diagnostic on: the declared identifier if there is one
+fn identifier_node(node: &SyntaxNode) -> SyntaxNode {
+    node.child_node(SyntaxKind::DeclaredIdentifier).unwrap_or_else(|| node.clone())
+}
+
+/// The element (possibly the root of a base component) that declares the property `name`
+fn declaring_element(element: ElementRc, name: &str) -> ElementRc {
+    let mut element = element;
+    loop {
+        if element.borrow().property_declarations.contains_key(name) {
+            return element;
+        }
+        let base_type = element.borrow().base_type.clone();
+        match base_type {
+            ElementType::Component(base) => element = base.root_element.clone(),
+            _ => return element,
+        }
+    }
+}
+
+/// Private properties and functions, and callbacks, that are declared but never used
+fn check_unused_declarations(doc: &Document, diag: &mut BuildDiagnostics) {
+    let mut used = HashSet::new();
+    for component in &doc.inner_components {
+        crate::object_tree::visit_all_named_references(component, &mut |nr| {
+            let element = declaring_element(nr.element(), nr.name());
+            used.insert((Rc::as_ptr(&element) as *const (), nr.name().clone()));
+        });
+    }
+
+    // The callbacks of exported components and globals can be invoked from other files
+    // or from the native code
+    let exported_roots = doc
+        .exports
+        .iter()
+        .filter_map(|(_, e)| e.as_ref().left())
+        .map(|c| Rc::as_ptr(&c.root_element))
+        .collect::<HashSet<_>>();
+
+    for component in &doc.inner_components {
+        crate::object_tree::recurse_elem_including_sub_components(
+            component,
+            &(),
+            &mut |elem, _| {
+                let e = elem.borrow();
+                let is_exported_root = exported_roots.contains(&Rc::as_ptr(elem));
+                for (name, decl) in &e.property_declarations {
+                    let Some(node) = &decl.node else { continue };
+                    if used.contains(&(Rc::as_ptr(elem) as *const (), name.clone())) {
+                        continue;
+                    }
+                    let message = match &decl.property_type {
+                        Type::Function(_) => {
+                            if decl.visibility != PropertyVisibility::Private {
+                                continue;
+                            }
+                            format!("Function '{name}' is never called")
+                        }
+                        Type::Callback(_) | Type::InferredCallback => {
+                            if is_exported_root
+                                || e.bindings
+                                    .get(name)
+                                    .is_some_and(|b| !b.borrow().two_way_bindings.is_empty())
+                            {
+                                continue;
+                            }
+                            format!("Callback '{name}' is never invoked")
+                        }
+                        _ => {
+                            if decl.visibility != PropertyVisibility::Private
+                                || e.change_callbacks.contains_key(name)
+                            {
+                                continue;
+                            }
+                            format!("Private property '{name}' is never used")
+                        }
+                    };
+                    if !is_allowed(node) {
+                        diag.push_warning(message, &identifier_node(node));
+                    }
+                }
+            },
+        );
+    }
+}
+
+/// Fields of the structs private to this document that are never read
+fn check_unused_struct_fields(doc: &Document, diag: &mut BuildDiagnostics) {
+    let structs = doc
+        .inner_types
+        .iter()
+        .filter_map(|ty| match ty {
+            Type::Struct(s) => s.node.as_ref().map(|node| (s.clone(), node.clone())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if structs.is_empty() {
+        return;
+    }
+
+    // Structs reachable from the public API are generated for the native code, which may read
+    // any of their fields
+    let mut public_structs = HashSet::new();
+    for (_, export) in doc.exports.iter() {
+        match export {
+            Either::Left(component) => {
+                let root = component.root_element.borrow();
+                for decl in root.property_declarations.values() {
+                    if !matches!(
+                        decl.visibility,
+                        PropertyVisibility::Private | PropertyVisibility::Protected
+                    ) {
+                        collect_structs(&decl.property_type, &mut public_structs);
+                    }
+                }
+            }
+            Either::Right(ty) => collect_structs(ty, &mut public_structs),
+        }
+    }
+
+    let mut read_fields: HashSet<(SmolStr, SmolStr)> = HashSet::new();
+    let read_all = |ty: &Type, read_fields: &mut HashSet<(SmolStr, SmolStr)>| {
+        if let Type::Struct(s) = ty {
+            if let Some(struct_name) = &s.name {
+                for field in s.fields.keys() {
+                    read_fields.insert((struct_name.clone(), field.clone()));
+                }
+            }
+        }
+    };
+    for component in &doc.inner_components {
+        crate::object_tree::visit_all_expressions(component, |expr, _| {
+            expr.visit_recursive(&mut |expr| match expr {
+                Expression::StructFieldAccess { base, name } => {
+                    if let Type::Struct(s) = base.ty() {
+                        if let Some(struct_name) = &s.name {
+                            read_fields.insert((struct_name.clone(), name.clone()));
+                        }
+                    }
+                }
+                // Comparisons and conversions read all the fields
+                Expression::BinaryExpression { lhs, op: '=' | '!', .. } => {
+                    read_all(&lhs.ty(), &mut read_fields)
+                }
+                Expression::Cast { from, .. } => read_all(&from.ty(), &mut read_fields),
+                _ => {}
+            })
+        });
+    }
+
+    for (s, node) in structs {
+        let Some(struct_name) = &s.name else { continue };
+        if public_structs.contains(struct_name) {
+            continue;
+        }
+        for member in node.ObjectTypeMember() {
+            let Some(field) = identifier_text(&member) else { continue };
+            if !read_fields.contains(&(struct_name.clone(), field.clone())) && !is_allowed(&member)
+            {
+                diag.push_warning(
+                    format!("Field '{field}' of struct '{struct_name}' is never read"),
+                    &member,
+                );
+            }
+        }
+    }
+}
+
+/// Collect the name of the structs used by `ty`
+fn collect_structs(ty: &Type, result: &mut HashSet<SmolStr>) {
+    match ty {
+        Type::Struct(s) => {
+            let Struct { name, fields, .. } = &**s;
+            if let Some(name) = name {
+                if !result.insert(name.clone()) {
+                    return;
+                }
+            }
+            for field in fields.values() {
+                collect_structs(field, result);
+            }
+        }
+        Type::Array(inner) => collect_structs(inner, result),
+        Type::Callback(f) | Type::Function(f) => {
+            f.args.iter().for_each(|arg| collect_structs(arg, result));
+            collect_structs(&f.return_type, result);
+        }
+        _ => {}
+    }
+}
+
+/// The name under which an imported identifier is known in the document
+pub fn imported_name(identifier: &syntax_nodes::ImportIdentifier) -> SmolStr {
+    identifier
+        .InternalName()
+        .and_then(|n| identifier_text(&n))
+        .or_else(|| identifier_text(&identifier.ExternalName()))
+        .unwrap_or_default()
+}
+
+/// The imported identifiers that are never referenced in the document
+pub fn unused_imports(doc_node: &syntax_nodes::Document) -> Vec<syntax_nodes::ImportIdentifier> {
+    let mut used = HashSet::new();
+    let mut token = doc_node.first_token();
+    while let Some(t) = token {
+        if t.kind() == SyntaxKind::Identifier
+            && !t.parent_ancestors().any(|n| n.kind() == SyntaxKind::ImportSpecifier)
+        {
+            used.insert(normalize_identifier(t.text()));
+        }
+        token = t.next_token();
+    }
+
+    doc_node
+        .ImportSpecifier()
+        .filter_map(|i| i.ImportIdentifierList())
+        .flat_map(|l| l.ImportIdentifier())
+        .filter(|i| !used.contains(&imported_name(i)))
+        .collect()
+}
+
+/// Imported components and types that are never referenced in the document
+fn check_unused_imports(doc_node: &syntax_nodes::Document, diag: &mut BuildDiagnostics) {
+    let unused_in_document = unused_imports(doc_node);
+
+    for import in doc_node.ImportSpecifier() {
+        let Some(list) = import.ImportIdentifierList() else { continue };
+        if is_allowed(&import) {
+            continue;
+        }
+        let identifiers = list.ImportIdentifier().collect::<Vec<_>>();
+        let unused = identifiers
+            .iter()
+            .filter(|identifier| {
+                unused_in_document.iter().any(|u| u.text_range() == identifier.text_range())
+            })
+            .collect::<Vec<_>>();
+        if !identifiers.is_empty() && unused.len() == identifiers.len() {
+            if let Some(file) = import.child_token(SyntaxKind::StringLiteral) {
+                let name = crate::literals::unescape_string(file.text()).unwrap_or_default();
+                diag.push_warning(format!("Nothing imported from '{name}' is used"), &file);
+            }
+        } else {
+            for identifier in unused {
+                diag.push_warning(
+                    format!("Imported '{}' is never used", identifier.text().to_string().trim()),
+                    identifier,
+                );
+            }
+        }
+    }
+}
+
+/// The `.slint` files of the libraries in use that nothing imports.
+///
+/// Only libraries whose path is an entry file are checked: the other files in the directory of
+/// the entry file are private to the library, while all the files of a library directory
+/// can be imported by the applications.
+pub fn check_unused_library_files(
+    doc: &Document,
+    type_loader: &crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    let mut loaded = type_loader.all_files().cloned().collect::<HashSet<_>>();
+    loaded.extend(doc.node.as_ref().map(|node| node.source_file.path().to_owned()));
+
+    let mut library_entries = type_loader
+        .compiler_config
+        .library_paths
+        .values()
+        .filter_map(|path| crate::fileaccess::load_file(path))
+        .filter(|file| !file.is_builtin() && loaded.contains(&file.canon_path))
+        .map(|file| file.canon_path)
+        .filter(|path| path.extension().is_some_and(|ext| ext == "slint"))
+        .collect::<Vec<_>>();
+    library_entries.sort();
+    library_entries.dedup();
+
+    for entry in library_entries {
+        let Some(directory) = entry.parent() else { continue };
+        let mut files = Vec::new();
+        collect_slint_files(directory, &mut files);
+        files.sort();
+        for file in files {
+            if !loaded.contains(&file) {
+                diag.push_warning_with_span(
+                    format!(
+                        "This file is never imported by the library entry file '{}'",
+                        entry.display()
+                    ),
+                    SourceLocation {
+                        source_file: Some(SourceFileInner::from_path_only(file)),
+                        span: Span::new(0),
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Collect the `.slint` files in `directory` and its sub-directories
+fn collect_slint_files(directory: &Path, result: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(directory) else { return };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_slint_files(&path, result);
+        } else if path.extension().is_some_and(|ext| ext == "slint") {
+            result.push(path);
+        }
+    }
+}
+
+#[test]
+fn test_unused_library_files() {
+    let library_path: PathBuf =
+        [env!("CARGO_MANIFEST_DIR"), "tests", "typeloader", "library"].iter().collect();
+
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+    compiler_config.warn_unused = true;
+    compiler_config.library_paths =
+        std::collections::HashMap::from([("lib".into(), library_path.join("lib.slint"))]);
+    let mut test_diags = crate::diagnostics::BuildDiagnostics::default();
+    let doc_node = crate::parser::parse(
+        r#"
+import { LibraryType } from "@lib";
+export component Foo inherits Window {
+    LibraryType {}
+}
+"#
+        .into(),
+        Some(std::path::Path::new("HELLO")),
+        &mut test_diags,
+    );
+    let (_, diag, _) =
+        spin_on::spin_on(crate::compile_syntax_node(doc_node, test_diags, compiler_config));
+    assert!(!diag.has_errors(), "slint compile error {:#?}", diag.to_string_vec());
+    let warnings =
+        diag.iter().map(|d| (d.source_file().map(Path::to_owned), d.message())).collect::<Vec<_>>();
+    assert_eq!(
+        warnings,
+        vec![(
+            Some(library_path.join("library_helper_type.slint")),
+            format!(
+                "This file is never imported by the library entry file '{}'",
+                library_path.join("lib.slint").display()
+            )
+            .as_str()
+        )]
+    );
+}
@@ -148,6 +148,25 @@ fn embed_image(
                 let mut kind = EmbeddedResourcesKind::RawData;
                 #[cfg(feature = "software-renderer")]
                 if embed_files == EmbedResourcesKind::EmbedTextures {
+                    match load_animated_image(&_file, _scale_factor) {
+                        Ok(Some((frames, original_size))) => {
+                            kind = EmbeddedResourcesKind::AnimatedTextureData(
+                                generate_animated_texture(frames, original_size),
+                            );
+                            return register_embedded_image(
+                                e.insert(EmbeddedResources { id: maybe_id, kind }),
+                                path,
+                            );
+                        }
+                        Ok(None) => {}
+                        Err(err) => {
+                            diag.push_error(
+                                format!("Cannot load image file {path}: {err}"),
+                                source_location,
+                            );
+                            return ImageReference::None;
+                        }
+                    }
                     match load_image(_file, _scale_factor) {
                         Ok((img, source_format, original_size)) => {
                             kind = EmbeddedResourcesKind::TextureData(generate_texture(
@@ -173,11 +192,19 @@ fn embed_image(
         }
     };
 
+    register_embedded_image(e, path)
+}
+
+fn register_embedded_image(e: &EmbeddedResources, path: &str) -> ImageReference {
     match e.kind {
         #[cfg(feature = "software-renderer")]
         EmbeddedResourcesKind::TextureData { .. } => {
             ImageReference::EmbeddedTexture { resource_id: e.id }
         }
+        #[cfg(feature = "software-renderer")]
+        EmbeddedResourcesKind::AnimatedTextureData { .. } => {
+            ImageReference::EmbeddedAnimatedTexture { resource_id: e.id }
+        }
         _ => ImageReference::EmbeddedData {
             resource_id: e.id,
             extension: std::path::Path::new(path)
@@ -316,6 +343,31 @@ enum ColorState {
     }
 }
 
+/// Generate a texture strip with all the frames of an animated image stacked vertically.
+///
+/// Unlike for still images, the frames are not cropped to their non-transparent area, so that
+/// every frame has the same size and can be found at a fixed offset in the data.
+#[cfg(feature = "software-renderer")]
+fn generate_animated_texture(
+    frames: Vec<(image::RgbaImage, u32)>,
+    original_size: Size,
+) -> AnimatedTexture {
+    let (width, height) = frames[0].0.dimensions();
+    let is_opaque = frames.iter().all(|(frame, _)| frame.pixels().all(|p| p[3] == 255));
+    let format = if is_opaque { PixelFormat::Rgb } else { PixelFormat::RgbaPremultiplied };
+    let rect = Rect::from_xywh(0, 0, width, height).unwrap();
+    let mut data = Vec::new();
+    let mut frame_durations = Vec::with_capacity(frames.len());
+    for (frame, duration) in frames {
+        data.extend(convert_image(frame, SourceFormat::Rgba, format, rect));
+        frame_durations.push(duration);
+    }
+    AnimatedTexture {
+        texture: Texture { total_size: Size { width, height }, original_size, rect, data, format },
+        frame_durations,
+    }
+}
+
 #[cfg(feature = "software-renderer")]
 fn convert_image(
     image: image::RgbaImage,
@@ -441,3 +493,70 @@ fn load_image(
         )
     })
 }
+
+/// Decode the frames of an animated GIF, APNG, or WebP, and how long each of them is displayed in milliseconds.
+///
+/// Returns `Ok(None)` if the file isn't an animated image.
+#[cfg(feature = "software-renderer")]
+fn load_animated_image(
+    file: &crate::fileaccess::VirtualFile,
+    scale_factor: f64,
+) -> image::ImageResult<Option<(Vec<(image::RgbaImage, u32)>, Size)>> {
+    use image::AnimationDecoder;
+    let Ok(format) = image::ImageFormat::from_path(&file.canon_path) else {
+        return Ok(None);
+    };
+    if !matches!(
+        format,
+        image::ImageFormat::Png | image::ImageFormat::Gif | image::ImageFormat::WebP
+    ) {
+        return Ok(None);
+    }
+    let data = match file.builtin_contents {
+        Some(data) => std::borrow::Cow::Borrowed(data),
+        None => std::fs::read(&file.canon_path).map_err(image::ImageError::IoError)?.into(),
+    };
+    let reader = std::io::Cursor::new(data.as_ref());
+    let frames = match format {
+        image::ImageFormat::Png => {
+            let decoder = image::codecs::png::PngDecoder::new(reader)?;
+            if !decoder.is_apng()? {
+                return Ok(None);
+            }
+            decoder.apng()?.into_frames()
+        }
+        image::ImageFormat::Gif => image::codecs::gif::GifDecoder::new(reader)?.into_frames(),
+        _ => {
+            let decoder = image::codecs::webp::WebPDecoder::new(reader)?;
+            if !decoder.has_animation() {
+                return Ok(None);
+            }
+            decoder.into_frames()
+        }
+    };
+    let frames = frames.collect_frames()?;
+    if frames.len() < 2 {
+        return Ok(None);
+    }
+    let (original_width, original_height) = frames[0].buffer().dimensions();
+    let frames = frames
+        .into_iter()
+        .map(|frame| {
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let duration = numer / denom.max(1);
+            // Like web browsers, use a default duration for frames that have (almost) none
+            let duration = if duration < 10 { 100 } else { duration };
+            let mut buffer = frame.into_buffer();
+            if scale_factor < 1. {
+                buffer = image::imageops::resize(
+                    &buffer,
+                    (original_width as f64 * scale_factor) as u32,
+                    (original_height as f64 * scale_factor) as u32,
+                    image::imageops::FilterType::Gaussian,
+                );
+            }
+            (buffer, duration)
+        })
+        .collect();
+    Ok(Some((frames, Size { width: original_width, height: original_height })))
+}
@@ -42,6 +42,7 @@ fn resolve_expression(
             component_scope: scope,
             diag,
             arguments: vec![],
+            local_variables: vec![],
             type_register,
             type_loader: Some(type_loader),
             current_token: None,
@@ -64,6 +65,9 @@ fn resolve_expression(
             SyntaxKind::BindingExpression => {
                 Expression::from_binding_expression_node(node.clone(), &mut lookup_ctx)
             }
+            SyntaxKind::Keyframes => {
+                Expression::from_keyframes_node(node.clone().into(), &mut lookup_ctx)
+            }
             SyntaxKind::PropertyChangedCallback => Expression::from_codeblock_node(
                 syntax_nodes::PropertyChangedCallback::from(node.clone()).CodeBlock(),
                 &mut lookup_ctx,
@@ -197,14 +201,18 @@ pub fn from_binding_expression_node(node: SyntaxNode, ctx: &mut LookupCtx) -> Se
     fn from_codeblock_node(node: syntax_nodes::CodeBlock, ctx: &mut LookupCtx) -> Expression {
         debug_assert_eq!(node.kind(), SyntaxKind::CodeBlock);
 
+        // The local variables declared in this block are only visible until its end
+        ctx.local_variables.push(Vec::new());
         let mut statements_or_exprs = node
             .children()
             .filter_map(|n| match n.kind() {
                 SyntaxKind::Expression => Some(Self::from_expression_node(n.into(), ctx)),
                 SyntaxKind::ReturnStatement => Some(Self::from_return_statement(n.into(), ctx)),
+                SyntaxKind::LetStatement => Some(Self::from_let_statement(n.into(), ctx)),
                 _ => None,
             })
             .collect::<Vec<_>>();
+        ctx.local_variables.pop();
 
         let exit_points_and_return_types = statements_or_exprs
             .iter()
@@ -251,6 +259,41 @@ fn from_return_statement(
         }))
     }
 
+    fn from_let_statement(node: syntax_nodes::LetStatement, ctx: &mut LookupCtx) -> Expression {
+        let name = identifier_text(&node.DeclaredIdentifier()).unwrap_or_default();
+        if ctx.local_variables.iter().flatten().any(|(n, _)| *n == name) {
+            ctx.diag.push_error(
+                format!("Redeclaration of local variable '{name}'"),
+                &node.DeclaredIdentifier(),
+            );
+        } else if ctx.arguments.contains(&name) {
+            ctx.diag.push_error(
+                format!("Local variable '{name}' shadows an argument with the same name"),
+                &node.DeclaredIdentifier(),
+            );
+        }
+
+        let mut value = Self::from_expression_node(node.Expression(), ctx);
+        if let Some(ty) = node.Type() {
+            let ty = type_from_node(ty, ctx.diag, ctx.type_register);
+            value = value.maybe_convert_to(ty, &node.Expression(), ctx.diag);
+        }
+        let ty = value.ty();
+        if matches!(ty, Type::Void) || matches!(&ty, Type::Array(e) if **e == Type::Void) {
+            ctx.diag.push_error(
+                format!("Cannot infer the type of local variable '{name}'"),
+                &node.Expression(),
+            );
+        }
+
+        let local_name = crate::lookup::local_variable_name(&name);
+        ctx.local_variables
+            .last_mut()
+            .expect("let statement outside of a code block")
+            .push((name, ty));
+        Expression::StoreLocalVariable { name: local_name, value: Box::new(value) }
+    }
+
     fn from_callback_connection(
         node: syntax_nodes::CallbackConnection,
         ctx: &mut LookupCtx,
@@ -437,13 +480,29 @@ fn from_at_image_url_node(node: syntax_nodes::AtImageUrl, ctx: &mut LookupCtx) -
 
     pub fn from_at_gradient(node: syntax_nodes::AtGradient, ctx: &mut LookupCtx) -> Self {
         enum GradKind {
-            Linear { angle: Box<Expression> },
+            Linear {
+                angle: Box<Expression>,
+            },
             Radial,
+            Conic {
+                from_angle: Box<Expression>,
+                center_x: Box<Expression>,
+                center_y: Box<Expression>,
+            },
         }
 
+        // The first identifier is the name of the gradient, the others are the `from` and `at`
+        // keywords of the conic gradient
         let mut subs = node
             .children_with_tokens()
-            .filter(|n| matches!(n.kind(), SyntaxKind::Comma | SyntaxKind::Expression));
+            .filter(|n| {
+                matches!(
+                    n.kind(),
+                    SyntaxKind::Comma | SyntaxKind::Expression | SyntaxKind::Identifier
+                )
+            })
+            .skip(1)
+            .peekable();
 
         let grad_token = node.child_token(SyntaxKind::Identifier).unwrap();
         let grad_text = grad_token.text();
@@ -492,8 +551,60 @@ enum GradKind {
                 return Expression::Invalid;
             }
             GradKind::Radial
+        } else if grad_text.starts_with("conic") {
+            let mut has_position = false;
+            let to_expression = |n: Option<NodeOrToken>, ty: Type, ctx: &mut LookupCtx| match n {
+                Some(NodeOrToken::Node(n)) if n.kind() == SyntaxKind::Expression => {
+                    let e = syntax_nodes::Expression::from(n);
+                    Some(Box::new(
+                        Expression::from_expression_node(e.clone(), ctx)
+                            .maybe_convert_to(ty, &e, ctx.diag),
+                    ))
+                }
+                _ => None,
+            };
+            let is_keyword =
+                |n: &NodeOrToken, keyword: &str| n.as_token().is_some_and(|t| t.text() == keyword);
+
+            let mut from_angle = Box::new(Expression::NumberLiteral(0., Unit::Deg));
+            if subs.next_if(|n| is_keyword(n, "from")).is_some() {
+                has_position = true;
+                let Some(angle) = to_expression(subs.next(), Type::Angle, ctx) else {
+                    ctx.diag.push_error("Expected angle expression after 'from'".into(), &node);
+                    return Expression::Invalid;
+                };
+                from_angle = angle;
+            }
+            let mut center_x = Box::new(Expression::NumberLiteral(0.5, Unit::None));
+            let mut center_y = Box::new(Expression::NumberLiteral(0.5, Unit::None));
+            if subs.next_if(|n| is_keyword(n, "at")).is_some() {
+                has_position = true;
+                let (Some(x), Some(y)) = (
+                    to_expression(subs.next(), Type::Float32, ctx),
+                    to_expression(subs.next(), Type::Float32, ctx),
+                ) else {
+                    ctx.diag.push_error(
+                        "Expected two expressions for the position of the center after 'at'".into(),
+                        &node,
+                    );
+                    return Expression::Invalid;
+                };
+                center_x = x;
+                center_y = y;
+            }
+            if has_position {
+                let comma = subs.next();
+                if comma.as_ref().is_some_and(|s| s.kind() != SyntaxKind::Comma) {
+                    ctx.diag.push_error(
+                        "The position of the conic gradient must be followed by a comma".into(),
+                        comma.as_ref().map_or(&node, |x| x as &dyn Spanned),
+                    );
+                    return Expression::Invalid;
+                }
+            }
+            GradKind::Conic { from_angle, center_x, center_y }
         } else {
-            // Parser should have ensured we have one of the linear or radial gradient
+            // Parser should have ensured we have one of the linear, radial or conic gradient
             panic!("Not a gradient {grad_text:?}");
         };
 
@@ -599,6 +710,9 @@ enum Stop {
         match grad_kind {
             GradKind::Linear { angle } => Expression::LinearGradient { angle, stops },
             GradKind::Radial => Expression::RadialGradient { stops },
+            GradKind::Conic { from_angle, center_x, center_y } => {
+                Expression::ConicGradient { from_angle, center_x, center_y, stops }
+            }
         }
     }
 
@@ -1203,6 +1317,85 @@ fn from_array_node(node: syntax_nodes::Array, ctx: &mut LookupCtx) -> Expression
         Expression::Array { element_ty, values }
     }
 
+    /// The keyframes of an animation, resolved to an array of `{ offset: float, value: <property type> }`
+    fn from_keyframes_node(node: syntax_nodes::Keyframes, ctx: &mut LookupCtx) -> Expression {
+        let keyframe_ty = match &ctx.property_type {
+            Type::Array(ty) => match &**ty {
+                Type::Struct(s) => s.clone(),
+                _ => return Expression::Invalid,
+            },
+            _ => return Expression::Invalid,
+        };
+        let value_ty = keyframe_ty.fields.get("value").cloned().unwrap_or_default();
+        // Resolve the values as if they were bindings of the animated property, so that
+        // things like color names work
+        let array_ty = std::mem::replace(&mut ctx.property_type, value_ty.clone());
+
+        let mut last_offset = -1.;
+        let values = node
+            .Keyframe()
+            .map(|keyframe| {
+                let offset = match keyframe
+                    .child_token(SyntaxKind::NumberLiteral)
+                    .map(|t| crate::literals::parse_number_literal(t.text().into()))
+                {
+                    Some(Ok(Expression::NumberLiteral(value, Unit::Percent)))
+                        if (0. ..=100.).contains(&value) =>
+                    {
+                        if value <= last_offset {
+                            ctx.diag.push_error(
+                                "Keyframes must be sorted by increasing offset".into(),
+                                &keyframe,
+                            );
+                        }
+                        last_offset = value;
+                        value / 100.
+                    }
+                    _ => {
+                        ctx.diag.push_error(
+                            "The offset of a keyframe must be a percentage between 0% and 100%"
+                                .into(),
+                            &keyframe,
+                        );
+                        0.
+                    }
+                };
+
+                let value = Expression::from_expression_node(keyframe.Expression(), ctx)
+                    .maybe_convert_to(value_ty.clone(), &keyframe.Expression(), ctx.diag);
+                let mut depends_on_state = !value.is_constant();
+                value.visit_recursive(&mut |e| {
+                    depends_on_state |= matches!(
+                        e,
+                        Expression::PropertyReference(..)
+                            | Expression::RepeaterIndexReference { .. }
+                            | Expression::RepeaterModelReference { .. }
+                    )
+                });
+                if depends_on_state {
+                    ctx.diag.push_error(
+                        "Keyframe values must be constant".into(),
+                        &keyframe.Expression(),
+                    );
+                }
+
+                Expression::Struct {
+                    ty: keyframe_ty.clone(),
+                    values: HashMap::from([
+                        (
+                            SmolStr::new_static("offset"),
+                            Expression::NumberLiteral(offset, Unit::None),
+                        ),
+                        (SmolStr::new_static("value"), value),
+                    ]),
+                }
+            })
+            .collect();
+        ctx.property_type = array_ty;
+
+        Expression::Array { element_ty: Type::Struct(keyframe_ty), values }
+    }
+
     fn from_string_template_node(
         node: syntax_nodes::StringTemplate,
         ctx: &mut LookupCtx,
@@ -1636,6 +1829,7 @@ fn resolve_two_way_bindings(
                                 component_scope: &scope.0,
                                 diag,
                                 arguments: vec![],
+                                local_variables: vec![],
                                 type_register,
                                 type_loader: None,
                                 current_token: Some(node.clone().into()),
@@ -0,0 +1,275 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*! Statistics about a compiled document
+
+This helps finding out which components make the generated code big, or which pass makes the
+compilation slow. The time spent in each pass is only measured when
+[`CompilerConfiguration::collect_statistics`](crate::CompilerConfiguration::collect_statistics)
+is set.
+*/
+
+use crate::embedded_resources::EmbeddedResourcesKind;
+use crate::langtype::ElementType;
+use crate::object_tree::{recurse_elem_including_sub_components, Document};
+use smol_str::SmolStr;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+/// Measures the wall time spent in each pass.
+/// Does nothing when not enabled, as `Instant::now()` is not available on all platforms.
+pub(crate) struct PassTimer {
+    timings: Option<Vec<(&'static str, Duration)>>,
+}
+
+impl PassTimer {
+    pub fn new(enabled: bool) -> Self {
+        Self { timings: enabled.then(Vec::new) }
+    }
+
+    /// To be called before running a pass, the result is passed to [`Self::record`] after the pass.
+    pub fn start(&self) -> Option<Instant> {
+        self.timings.is_some().then(Instant::now)
+    }
+
+    /// Attribute the time elapsed since `start` to the given pass.
+    /// Passes that run more than once (or once per component) accumulate their time.
+    pub fn record(&mut self, pass: &'static str, start: Option<Instant>) {
+        let (Some(timings), Some(start)) = (&mut self.timings, start) else { return };
+        let elapsed = start.elapsed();
+        match timings.iter_mut().find(|(name, _)| *name == pass) {
+            Some((_, duration)) => *duration += elapsed,
+            None => timings.push((pass, elapsed)),
+        }
+    }
+
+    pub fn finish(self) -> Vec<(&'static str, Duration)> {
+        self.timings.unwrap_or_default()
+    }
+}
+
+/// The amount of generated things for one component, including its repeated components and popups
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ComponentStatistics {
+    pub name: SmolStr,
+    pub is_global: bool,
+    /// Number of native items (Rectangle, Text, ...)
+    pub items: usize,
+    /// Number of declared properties, callbacks and functions
+    pub properties: usize,
+    /// Number of bindings, including the bindings set on the items
+    pub bindings: usize,
+    /// Number of `for` and `if` elements
+    pub repeaters: usize,
+    /// Number of instances of other (non-inlined) components
+    pub sub_component_instances: usize,
+    /// Number of component instances that were inlined into this component
+    pub inlined_components: usize,
+}
+
+/// An image or font embedded in the generated code
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceStatistics {
+    pub path: SmolStr,
+    /// One of `"file"`, `"texture"`, `"animated-texture"`, or `"bitmap-font"`
+    pub kind: &'static str,
+    /// The size in bytes of the embedded data
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Statistics {
+    pub components: Vec<ComponentStatistics>,
+    pub resources: Vec<ResourceStatistics>,
+    /// The time spent in each pass, in the order they were first run
+    pub passes: Vec<(&'static str, Duration)>,
+}
+
+impl Statistics {
+    /// Collect the statistics of a document on which all the passes have run
+    pub fn collect(doc: &Document) -> Self {
+        let mut components = Vec::new();
+        doc.visit_all_used_components(|component| {
+            let mut stats = ComponentStatistics {
+                name: component.id.clone(),
+                is_global: component.is_global(),
+                ..Default::default()
+            };
+            recurse_elem_including_sub_components(component, &(), &mut |elem, _| {
+                let elem = elem.borrow();
+                stats.properties += elem.property_declarations.len();
+                stats.bindings += elem.bindings.len();
+                stats.inlined_components += elem.debug.len().saturating_sub(1);
+                if elem.repeated.is_some() {
+                    stats.repeaters += 1;
+                    return;
+                }
+                match &elem.base_type {
+                    ElementType::Native(_) | ElementType::Builtin(_) => stats.items += 1,
+                    ElementType::Component(c) if c.parent_element.upgrade().is_none() => {
+                        stats.sub_component_instances += 1
+                    }
+                    _ => {}
+                }
+            });
+            components.push(stats);
+        });
+
+        let resources = doc
+            .embedded_file_resources
+            .borrow()
+            .iter()
+            .filter_map(|(path, resource)| {
+                let (kind, size) = match &resource.kind {
+                    EmbeddedResourcesKind::ListOnly => return None,
+                    EmbeddedResourcesKind::RawData => {
+                        let file =
+                            crate::fileaccess::load_file(std::path::Path::new(path.as_str()));
+                        let size = match file.as_ref().and_then(|f| f.builtin_contents) {
+                            Some(data) => data.len() as u64,
+                            None => std::fs::metadata(path.as_str()).map_or(0, |m| m.len()),
+                        };
+                        ("file", size)
+                    }
+                    #[cfg(feature = "software-renderer")]
+                    EmbeddedResourcesKind::TextureData(texture) => {
+                        ("texture", texture.data.len() as u64)
+                    }
+                    #[cfg(feature = "software-renderer")]
+                    EmbeddedResourcesKind::AnimatedTextureData(animated) => {
+                        ("animated-texture", animated.texture.data.len() as u64)
+                    }
+                    #[cfg(feature = "software-renderer")]
+                    EmbeddedResourcesKind::BitmapFontData(font) => (
+                        "bitmap-font",
+                        font.glyphs
+                            .iter()
+                            .flat_map(|glyphs| glyphs.glyph_data.iter())
+                            .map(|glyph| glyph.data.len() as u64)
+                            .sum(),
+                    ),
+                };
+                Some(ResourceStatistics { path: path.clone(), kind, size })
+            })
+            .collect();
+
+        Self { components, resources, passes: doc.pass_timings.clone() }
+    }
+
+    /// Serialize the statistics to JSON
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\n  \"components\": [");
+        for (i, c) in self.components.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            write!(
+                json,
+                "{separator}\n    {{ \"name\": {}, \"is_global\": {}, \"items\": {}, \"properties\": {}, \
+                \"bindings\": {}, \"repeaters\": {}, \"sub_component_instances\": {}, \
+                \"inlined_components\": {} }}",
+                json_string(&c.name),
+                c.is_global,
+                c.items,
+                c.properties,
+                c.bindings,
+                c.repeaters,
+                c.sub_component_instances,
+                c.inlined_components
+            )
+            .unwrap();
+        }
+        json.push_str("\n  ],\n  \"resources\": [");
+        for (i, r) in self.resources.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            write!(
+                json,
+                "{separator}\n    {{ \"path\": {}, \"kind\": {}, \"size\": {} }}",
+                json_string(&r.path),
+                json_string(r.kind),
+                r.size
+            )
+            .unwrap();
+        }
+        write!(
+            json,
+            "\n  ],\n  \"total_resource_size\": {},\n  \"passes\": [",
+            self.resources.iter().map(|r| r.size).sum::<u64>()
+        )
+        .unwrap();
+        for (i, (name, duration)) in self.passes.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            write!(
+                json,
+                "{separator}\n    {{ \"name\": {}, \"ms\": {:.3} }}",
+                json_string(name),
+                duration.as_secs_f64() * 1000.
+            )
+            .unwrap();
+        }
+        json.push_str("\n  ]\n}\n");
+        json
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(result, "\\u{:04x}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+#[test]
+fn test_statistics() {
+    let source = r#"
+component Button {
+    in property <string> text;
+    callback clicked;
+    Rectangle { Text { text: root.text; } }
+    TouchArea { clicked => { root.clicked(); } }
+}
+export global Logic {
+    in-out property <int> counter;
+}
+export component App inherits Window {
+    property <bool> flag;
+    for i in 3: Button { text: "Button " + i; clicked => { Logic.counter += 1; } }
+    if flag: Rectangle {}
+    Button { text: "Ok"; }
+}
+"#;
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Llr);
+    compiler_config.collect_statistics = true;
+    compiler_config.inline_all_elements = false;
+    let mut test_diags = crate::diagnostics::BuildDiagnostics::default();
+    let doc_node = crate::parser::parse(
+        source.into(),
+        Some(std::path::Path::new("app.slint")),
+        &mut test_diags,
+    );
+    let (doc, diag, _) =
+        spin_on::spin_on(crate::compile_syntax_node(doc_node, test_diags, compiler_config));
+    assert!(!diag.has_errors(), "slint compile error {:#?}", diag.to_string_vec());
+
+    let stats = Statistics::collect(&doc);
+    let app = stats.components.iter().find(|c| c.name == "App").unwrap();
+    assert_eq!(app.repeaters, 2);
+    assert!(!app.is_global);
+    let logic = stats.components.iter().find(|c| c.name == "Logic").unwrap();
+    assert!(logic.is_global);
+    assert_eq!(logic.properties, 1);
+    assert!(stats.passes.iter().any(|(name, _)| *name == "inline"));
+    assert!(stats.passes.iter().any(|(name, _)| *name == "binding_analysis"));
+
+    let json = stats.to_json();
+    assert!(json.contains("\"name\": \"App\""), "{json}");
+    assert!(json.contains("\"passes\": ["), "{json}");
+}
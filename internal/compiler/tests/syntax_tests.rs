@@ -284,6 +284,7 @@ fn process_file_source(
     compiler_config.embed_resources = i_slint_compiler::EmbedResourcesKind::OnlyBuiltinResources;
     compiler_config.enable_experimental = true;
     compiler_config.style = Some("fluent".into());
+    compiler_config.warn_unused = source.contains("config:warn_unused");
     compiler_config.components_to_generate =
         if source.contains("config:generate_all_exported_windows") {
             ComponentSelection::ExportedWindows
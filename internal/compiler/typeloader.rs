@@ -298,6 +298,7 @@ fn snapshot_document(&mut self, document: &object_tree::Document) -> object_tree
                 Weak::upgrade(&self.use_component(p))
                     .expect("Components can get upgraded at this point")
             }),
+            pass_timings: document.pass_timings.clone(),
         }
     }
 
@@ -809,6 +810,19 @@ fn snapshot_expression(
                     .map(|(e1, e2)| (self.snapshot_expression(e1), self.snapshot_expression(e2)))
                     .collect(),
             },
+            Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+                Expression::ConicGradient {
+                    from_angle: Box::new(self.snapshot_expression(from_angle)),
+                    center_x: Box::new(self.snapshot_expression(center_x)),
+                    center_y: Box::new(self.snapshot_expression(center_y)),
+                    stops: stops
+                        .iter()
+                        .map(|(e1, e2)| {
+                            (self.snapshot_expression(e1), self.snapshot_expression(e2))
+                        })
+                        .collect(),
+                }
+            }
             Expression::ReturnStatement(expr) => Expression::ReturnStatement(
                 expr.as_ref().map(|e| Box::new(self.snapshot_expression(e))),
             ),
@@ -10,7 +10,7 @@
 #[cfg(target_has_atomic = "ptr")]
 pub use crate::future::*;
 use crate::graphics::{Rgba8Pixel, SharedPixelBuffer};
-use crate::input::{KeyEventType, MouseEvent};
+use crate::input::{KeyEventType, MouseEvent, TouchEventType};
 use crate::item_tree::ItemTreeVTable;
 use crate::window::{WindowAdapter, WindowInner};
 use alloc::boxed::Box;
@@ -622,6 +622,27 @@ pub fn try_dispatch_event(
             crate::platform::WindowEvent::PointerExited => {
                 self.0.process_mouse_input(MouseEvent::Exit)
             }
+            crate::platform::WindowEvent::TouchPressed { touch_id, position } => {
+                self.0.process_touch_input(
+                    touch_id,
+                    position.to_euclid().cast(),
+                    TouchEventType::Pressed,
+                );
+            }
+            crate::platform::WindowEvent::TouchMoved { touch_id, position } => {
+                self.0.process_touch_input(
+                    touch_id,
+                    position.to_euclid().cast(),
+                    TouchEventType::Moved,
+                );
+            }
+            crate::platform::WindowEvent::TouchReleased { touch_id, position } => {
+                self.0.process_touch_input(
+                    touch_id,
+                    position.to_euclid().cast(),
+                    TouchEventType::Released,
+                );
+            }
 
             crate::platform::WindowEvent::KeyPressed { text } => {
                 self.0.process_key_input(crate::input::KeyEvent {
@@ -50,7 +50,7 @@
 
 pub mod rendering_metrics_collector;
 
-#[cfg(feature = "box-shadow-cache")]
+#[cfg(any(feature = "box-shadow-cache", all(feature = "software-renderer", feature = "std")))]
 pub mod boxshadowcache;
 
 pub mod border_radius;
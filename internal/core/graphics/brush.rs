@@ -29,6 +29,9 @@ pub enum Brush {
     /// The radial gradient variant of a brush describes a circle variant centered
     /// in the middle
     RadialGradient(RadialGradientBrush),
+    /// The conic gradient variant of a brush describes the gradient stops for a fill
+    /// where the colors are swept around a center point.
+    ConicGradient(ConicGradientBrush),
 }
 
 /// Construct a brush with transparent color
@@ -50,6 +53,9 @@ pub fn color(&self) -> Color {
             Brush::RadialGradient(gradient) => {
                 gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
             }
+            Brush::ConicGradient(gradient) => {
+                gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
+            }
         }
     }
 
@@ -66,6 +72,7 @@ pub fn is_transparent(&self) -> bool {
             Brush::SolidColor(c) => c.alpha() == 0,
             Brush::LinearGradient(_) => false,
             Brush::RadialGradient(_) => false,
+            Brush::ConicGradient(_) => false,
         }
     }
 
@@ -82,6 +89,7 @@ pub fn is_opaque(&self) -> bool {
             Brush::SolidColor(c) => c.alpha() == 255,
             Brush::LinearGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
             Brush::RadialGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
+            Brush::ConicGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
         }
     }
 
@@ -104,6 +112,7 @@ pub fn brighter(&self, factor: f32) -> Self {
                     GradientStop { color: s.color.brighter(factor), position: s.position }
                 })))
             }
+            Brush::ConicGradient(g) => Brush::ConicGradient(g.map_colors(|c| c.brighter(factor))),
         }
     }
 
@@ -123,6 +132,7 @@ pub fn darker(&self, factor: f32) -> Self {
                 g.stops()
                     .map(|s| GradientStop { color: s.color.darker(factor), position: s.position }),
             )),
+            Brush::ConicGradient(g) => Brush::ConicGradient(g.map_colors(|c| c.darker(factor))),
         }
     }
 
@@ -147,6 +157,9 @@ pub fn transparentize(&self, amount: f32) -> Self {
                     GradientStop { color: s.color.transparentize(amount), position: s.position }
                 })))
             }
+            Brush::ConicGradient(g) => {
+                Brush::ConicGradient(g.map_colors(|c| c.transparentize(amount)))
+            }
         }
     }
 
@@ -168,6 +181,7 @@ pub fn with_alpha(&self, alpha: f32) -> Self {
                     GradientStop { color: s.color.with_alpha(alpha), position: s.position }
                 })))
             }
+            Brush::ConicGradient(g) => Brush::ConicGradient(g.map_colors(|c| c.with_alpha(alpha))),
         }
     }
 }
@@ -221,6 +235,84 @@ pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
     }
 }
 
+/// The ConicGradientBrush describes a way of filling a shape with colors that are swept
+/// around a center point, starting at the specified angle.
+#[derive(Clone, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct ConicGradientBrush(SharedVector<GradientStop>);
+
+impl ConicGradientBrush {
+    /// Creates a new conic gradient, starting at the `from_angle` (in degrees, clockwise from the top)
+    /// and centered at the given point, expressed as a fraction of the width and height of the shape.
+    pub fn new(
+        from_angle: f32,
+        center_x: f32,
+        center_y: f32,
+        stops: impl IntoIterator<Item = GradientStop>,
+    ) -> Self {
+        let stop_iter = stops.into_iter();
+        let mut encoded = SharedVector::with_capacity(stop_iter.size_hint().0 + 3);
+        // The gradient's first stops are fake stops to store the angle and the center
+        for position in [from_angle, center_x, center_y] {
+            encoded.push(GradientStop { color: Default::default(), position });
+        }
+        encoded.extend(stop_iter);
+        Self(encoded)
+    }
+    /// Returns the angle, in degrees, at which the gradient starts.
+    pub fn from_angle(&self) -> f32 {
+        self.0[0].position
+    }
+    /// Returns the center of the gradient, as a fraction of the width and height of the shape.
+    pub fn center(&self) -> Point2D<f32> {
+        Point2D::new(self.0[1].position, self.0[2].position)
+    }
+    /// Returns the color stops of the conic gradient.
+    pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
+        // skip the fake stops that just contain the angle and the center
+        self.0.iter().skip(3)
+    }
+
+    /// Returns the color of the gradient at the given position of a shape of the given size.
+    pub fn color_at(&self, position: Point2D<f32>, size: Size2D<f32>) -> Color {
+        let center = self.center();
+        let dx = position.x - center.x * size.width;
+        let dy = position.y - center.y * size.height;
+        // Angle clockwise from the top
+        let turns = (dx.atan2(-dy).to_degrees() - self.from_angle()) / 360.;
+        gradient_color_at(self.stops(), turns - turns.floor())
+    }
+
+    fn map_colors(&self, f: impl Fn(Color) -> Color) -> Self {
+        let mut result = self.clone();
+        for s in result.0.make_mut_slice().iter_mut().skip(3) {
+            s.color = f(s.color);
+        }
+        result
+    }
+}
+
+/// Returns the interpolated color of the stops at the position `t` (between 0 and 1)
+pub(crate) fn gradient_color_at<'a>(
+    stops: impl Iterator<Item = &'a GradientStop>,
+    t: f32,
+) -> Color {
+    let mut prev: Option<&GradientStop> = None;
+    for stop in stops {
+        if t <= stop.position {
+            return match prev {
+                Some(prev) if stop.position > prev.position => prev.color.interpolate(
+                    &stop.color,
+                    (t - prev.position) / (stop.position - prev.position),
+                ),
+                _ => stop.color,
+            };
+        }
+        prev = Some(stop);
+    }
+    prev.map(|s| s.color).unwrap_or_default()
+}
+
 /// GradientStop describes a single color stop in a gradient. The colors between multiple
 /// stops are interpolated.
 #[repr(C)]
@@ -330,8 +422,35 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
                     Brush::RadialGradient(new_grad)
                 }
             }
-            (a @ Brush::LinearGradient(_), b @ Brush::RadialGradient(_))
-            | (a @ Brush::RadialGradient(_), b @ Brush::LinearGradient(_)) => {
+            (Brush::SolidColor(col), Brush::ConicGradient(grad)) => {
+                Brush::ConicGradient(grad.map_colors(|c| col.interpolate(&c, t)))
+            }
+            (a @ Brush::ConicGradient(_), b @ Brush::SolidColor(_)) => {
+                Self::interpolate(b, a, 1. - t)
+            }
+            (Brush::ConicGradient(lhs), Brush::ConicGradient(rhs)) => {
+                if lhs.0.len() < rhs.0.len() {
+                    Self::interpolate(target_value, self, 1. - t)
+                } else {
+                    let mut new_grad = lhs.clone();
+                    let mut iter = new_grad.0.make_mut_slice().iter_mut();
+                    // The angle and the center
+                    for s2 in rhs.0.iter().take(3) {
+                        let s1 = iter.next().unwrap();
+                        s1.position = s1.position.interpolate(&s2.position, t);
+                    }
+                    for s2 in rhs.stops() {
+                        let s1 = iter.next().unwrap();
+                        s1.color = s1.color.interpolate(&s2.color, t);
+                        s1.position = s1.position.interpolate(&s2.position, t);
+                    }
+                    for x in iter {
+                        x.position = x.position.interpolate(&1.0, t);
+                    }
+                    Brush::ConicGradient(new_grad)
+                }
+            }
+            (a, b) => {
                 // Just go to an intermediate color.
                 let color = Color::interpolate(&b.color(), &a.color(), t);
                 if t < 0.5 {
@@ -344,6 +463,26 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
     }
 }
 
+#[test]
+#[allow(clippy::float_cmp)] // We want bit-wise equality here
+fn test_conic_gradient_encoding() {
+    let stops: SharedVector<GradientStop> = [
+        GradientStop { position: 0.0, color: Color::from_rgb_u8(255, 0, 0) },
+        GradientStop { position: 1.0, color: Color::from_rgb_u8(0, 0, 255) },
+    ]
+    .into();
+    let grad = ConicGradientBrush::new(90., 0.25, 0.5, stops.clone());
+    assert_eq!(grad.from_angle(), 90.);
+    assert_eq!(grad.center(), Point2D::new(0.25, 0.5));
+    assert!(grad.stops().eq(stops.iter()));
+
+    let size = Size2D::new(40., 20.);
+    // Right of the center is where the gradient starts
+    assert_eq!(grad.color_at(Point2D::new(20., 10.), size), Color::from_rgb_u8(255, 0, 0));
+    // Half-way, on the left of the center
+    assert_eq!(grad.color_at(Point2D::new(0., 10.), size), Color::from_rgb_u8(128, 0, 128));
+}
+
 #[test]
 #[allow(clippy::float_cmp)] // We want bit-wise equality here
 fn test_linear_gradient_encoding() {
@@ -48,6 +48,11 @@ pub struct OpaqueImageVTable {
     pub static NINE_SLICE_VT for NineSliceImage
 }
 
+OpaqueImageVTable_static! {
+    /// VTable for RC wrapped animated image helper struct.
+    pub static ANIMATED_IMAGE_VT for AnimatedImage
+}
+
 /// SharedPixelBuffer is a container for storing image data as pixels. It is
 /// internally reference counted and cheap to clone.
 ///
@@ -344,6 +349,7 @@ pub fn new(resource: &ImageInner) -> Option<Self> {
             ImageInner::NineSlice(nine) => vtable::VRc::borrow(nine).cache_key(),
             #[cfg(feature = "unstable-wgpu-24")]
             ImageInner::WGPUTexture(..) => return None,
+            ImageInner::AnimatedImage(animated) => vtable::VRc::borrow(animated).cache_key(),
         };
         if matches!(key, ImageCacheKey::Invalid) {
             None
@@ -377,6 +383,99 @@ fn cache_key(&self) -> ImageCacheKey {
     }
 }
 
+/// An image made of several frames that are displayed one after the other,
+/// such as an animated GIF, APNG, or WebP.
+pub struct AnimatedImage {
+    /// The frames, and how long each of them is displayed in milliseconds
+    frames: alloc::vec::Vec<(ImageInner, u32)>,
+    total_duration: u64,
+    cache_key: ImageCacheKey,
+    /// Changes when the next frame of the playing animation is due
+    frame_change: core::pin::Pin<alloc::rc::Rc<crate::Property<u32>>>,
+    /// Fires when the frame shown by [`Self::playing_frame`] is over
+    next_frame_timer: crate::timers::Timer,
+}
+
+impl AnimatedImage {
+    /// Create an animated image from its frames, and how long each of them is displayed in milliseconds.
+    pub fn new(frames: alloc::vec::Vec<(ImageInner, u32)>, cache_key: ImageCacheKey) -> Self {
+        let total_duration = frames.iter().map(|(_, duration)| *duration as u64).sum();
+        Self {
+            frames,
+            total_duration,
+            cache_key,
+            frame_change: alloc::rc::Rc::pin(Default::default()),
+            next_frame_timer: Default::default(),
+        }
+    }
+
+    /// Returns the frame to show for the animation tick, as the animation loops forever.
+    ///
+    /// The calling binding is marked dirty when the next frame is due, so that the image is
+    /// only redrawn when the frame changes.
+    pub fn playing_frame(&self) -> Image {
+        self.frame_change.as_ref().get();
+        let elapsed = crate::properties::evaluate_no_tracking(crate::animations::current_tick);
+        let (index, remaining) = self.frame_at(elapsed.as_millis());
+        if remaining > 0 && !self.next_frame_timer.running() {
+            let frame_change = self.frame_change.clone();
+            self.next_frame_timer.start(
+                crate::timers::TimerMode::SingleShot,
+                core::time::Duration::from_millis(remaining),
+                move || {
+                    let frame_change = frame_change.as_ref();
+                    frame_change.set(frame_change.get_untracked().wrapping_add(1));
+                },
+            );
+        }
+        self.frame(index as i32)
+    }
+
+    /// Returns the number of frames
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns the frame at the given index. The index wraps around the number of frames.
+    pub fn frame(&self, index: i32) -> Image {
+        if self.frames.is_empty() {
+            return Image::default();
+        }
+        Image(self.frames[index.rem_euclid(self.frames.len() as i32) as usize].0.clone())
+    }
+
+    /// Returns the index of the frame to show when the animation has been running for
+    /// `elapsed` milliseconds. The animation loops forever.
+    pub fn frame_index_at(&self, elapsed: u64) -> usize {
+        self.frame_at(elapsed).0
+    }
+
+    /// Returns the index of the frame to show after `elapsed` milliseconds, and for how many
+    /// more milliseconds it is shown.
+    fn frame_at(&self, elapsed: u64) -> (usize, u64) {
+        if self.total_duration == 0 {
+            return (0, 0);
+        }
+        let mut offset = elapsed % self.total_duration;
+        for (index, (_, duration)) in self.frames.iter().enumerate() {
+            if offset < *duration as u64 {
+                return (index, *duration as u64 - offset);
+            }
+            offset -= *duration as u64;
+        }
+        (0, 0)
+    }
+}
+
+impl OpaqueImage for AnimatedImage {
+    fn size(&self) -> IntSize {
+        self.frames.first().map(|(frame, _)| frame.size()).unwrap_or_default()
+    }
+    fn cache_key(&self) -> ImageCacheKey {
+        self.cache_key.clone()
+    }
+}
+
 /// Represents a `wgpu::Texture` for each version of WGPU we support.
 #[cfg(feature = "unstable-wgpu-24")]
 #[derive(Clone, Debug)]
@@ -427,6 +526,7 @@ pub enum ImageInner {
     NineSlice(vtable::VRc<OpaqueImageVTable, NineSliceImage>) = 7,
     #[cfg(feature = "unstable-wgpu-24")]
     WGPUTexture(WGPUTexture) = 8,
+    AnimatedImage(vtable::VRc<OpaqueImageVTable, AnimatedImage>) = 9,
 }
 
 impl ImageInner {
@@ -515,6 +615,7 @@ pub fn render_to_buffer(
                 Some(SharedImageBuffer::RGBA8Premultiplied(buffer))
             }
             ImageInner::NineSlice(nine) => nine.0.render_to_buffer(None),
+            ImageInner::AnimatedImage(animated) => animated.frame(0).0.render_to_buffer(None),
             _ => None,
         }
     }
@@ -546,6 +647,7 @@ pub fn size(&self) -> IntSize {
             ImageInner::NineSlice(nine) => nine.0.size(),
             #[cfg(feature = "unstable-wgpu-24")]
             ImageInner::WGPUTexture(texture) => texture.size(),
+            ImageInner::AnimatedImage(animated) => animated.size(),
         }
     }
 }
@@ -566,6 +668,7 @@ fn eq(&self, other: &Self) -> bool {
             #[cfg(not(target_arch = "wasm32"))]
             (Self::BorrowedOpenGLTexture(l0), Self::BorrowedOpenGLTexture(r0)) => l0 == r0,
             (Self::NineSlice(l), Self::NineSlice(r)) => l.0 == r.0 && l.1 == r.1,
+            (Self::AnimatedImage(l), Self::AnimatedImage(r)) => vtable::VRc::ptr_eq(l, r),
             _ => false,
         }
     }
@@ -915,6 +1018,12 @@ pub fn path(&self) -> Option<&std::path::Path> {
                 } => Some(std::path::Path::new(path.as_str())),
                 _ => None,
             },
+            ImageInner::AnimatedImage(animated) => match &animated.cache_key {
+                ImageCacheKey::Path(CachedPath { path, .. }) => {
+                    Some(std::path::Path::new(path.as_str()))
+                }
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -1045,6 +1154,82 @@ pub fn load_image_from_embedded_data(data: Slice<'static, u8>, format: Slice<'_,
     })
 }
 
+/// Create an animated image from textures embedded in the binary.
+/// `frames` contains the textures of each frame, and `durations` how long each of them is displayed in milliseconds.
+/// This is called by the generated code.
+pub fn image_from_embedded_texture_frames(
+    frames: &'static [StaticTextures],
+    durations: &'static [u32],
+) -> Image {
+    let cache_key = frames.first().map_or(ImageCacheKey::Invalid, |frame| {
+        ImageCacheKey::from_embedded_image_data(frame.data.as_slice())
+    });
+    let frames = frames
+        .iter()
+        .zip(durations)
+        .map(|(frame, duration)| (ImageInner::StaticTextures(frame), *duration))
+        .collect();
+    Image(ImageInner::AnimatedImage(vtable::VRc::new(AnimatedImage::new(frames, cache_key))))
+}
+
+#[test]
+fn test_animated_image_frames() {
+    let frame = |width| ImageInner::EmbeddedImage {
+        cache_key: ImageCacheKey::Invalid,
+        buffer: SharedImageBuffer::RGB8(SharedPixelBuffer::new(width, 10)),
+    };
+    let animated = AnimatedImage::new(
+        alloc::vec![(frame(1), 100), (frame(2), 50), (frame(3), 200)],
+        ImageCacheKey::Invalid,
+    );
+    assert_eq!(animated.frame_count(), 3);
+    assert_eq!(animated.frame_index_at(0), 0);
+    assert_eq!(animated.frame_index_at(99), 0);
+    assert_eq!(animated.frame_index_at(100), 1);
+    assert_eq!(animated.frame_index_at(149), 1);
+    assert_eq!(animated.frame_index_at(150), 2);
+    assert_eq!(animated.frame_index_at(350), 0);
+    assert_eq!(animated.frame_index_at(500), 2);
+    assert_eq!(animated.frame(1).size(), IntSize::new(2, 10));
+    assert_eq!(animated.frame(4).size(), IntSize::new(2, 10));
+    assert_eq!(animated.frame(-1).size(), IntSize::new(3, 10));
+
+    let image = Image(ImageInner::AnimatedImage(vtable::VRc::new(animated)));
+    assert_eq!(image.size(), IntSize::new(1, 10));
+}
+
+#[test]
+fn test_animated_image_playing_frame() {
+    let frame = |width| ImageInner::EmbeddedImage {
+        cache_key: ImageCacheKey::Invalid,
+        buffer: SharedImageBuffer::RGB8(SharedPixelBuffer::new(width, 10)),
+    };
+    let animated =
+        AnimatedImage::new(alloc::vec![(frame(1), 100), (frame(2), 1000)], ImageCacheKey::Invalid);
+    let tracker = alloc::boxed::Box::pin(crate::properties::PropertyTracker::default());
+    let playing_frame_width =
+        || tracker.as_ref().evaluate(|| animated.playing_frame().size().width);
+    let start = crate::tests::slint_get_mocked_time();
+    let mock_elapsed_time = |time| {
+        crate::tests::slint_mock_elapsed_time(start + time - crate::tests::slint_get_mocked_time())
+    };
+
+    assert_eq!(playing_frame_width(), 1);
+    // Showing a frame doesn't keep the animations running, a timer is started instead
+    assert!(
+        !crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| driver.has_active_animations())
+    );
+    assert!(animated.next_frame_timer.running());
+
+    mock_elapsed_time(99);
+    assert!(!tracker.is_dirty());
+    mock_elapsed_time(100);
+    assert!(tracker.is_dirty());
+    assert!(!animated.next_frame_timer.running());
+    assert_eq!(playing_frame_width(), 2);
+    assert!(animated.next_frame_timer.running());
+}
+
 #[test]
 fn test_image_size_from_buffer_without_backend() {
     {
@@ -1396,6 +1581,10 @@ pub extern "C" fn slint_image_path(image: &Image) -> Option<&SharedString> {
                 },
                 _ => None,
             },
+            ImageInner::AnimatedImage(animated) => match &animated.cache_key {
+                ImageCacheKey::Path(CachedPath { path, .. }) => Some(path),
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -1408,6 +1597,18 @@ pub extern "C" fn slint_image_path(image: &Image) -> Option<&SharedString> {
         core::ptr::write(image, Image::from(ImageInner::StaticTextures(textures)));
     }
 
+    #[unsafe(no_mangle)]
+    pub unsafe extern "C" fn slint_image_from_embedded_texture_frames(
+        frames: Slice<'static, StaticTextures>,
+        durations: Slice<'static, u32>,
+        image: *mut Image,
+    ) {
+        core::ptr::write(
+            image,
+            image_from_embedded_texture_frames(frames.as_slice(), durations.as_slice()),
+        );
+    }
+
     #[unsafe(no_mangle)]
     pub unsafe extern "C" fn slint_image_compare_equal(image1: &Image, image2: &Image) -> bool {
         image1.eq(image2)
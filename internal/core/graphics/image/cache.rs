@@ -5,7 +5,10 @@
 This module contains image and caching related types for the run-time library.
 */
 
-use super::{CachedPath, Image, ImageCacheKey, ImageInner, SharedImageBuffer, SharedPixelBuffer};
+use super::{
+    AnimatedImage, CachedPath, Image, ImageCacheKey, ImageInner, SharedImageBuffer,
+    SharedPixelBuffer,
+};
 use crate::{slice::Slice, SharedString};
 
 struct ImageWeightInBytes;
@@ -30,6 +33,9 @@ fn weight(&self, _key: &ImageCacheKey, value: &ImageInner) -> usize {
             ImageInner::NineSlice(nine) => self.weight(_key, &nine.0),
             #[cfg(feature = "unstable-wgpu-24")]
             ImageInner::WGPUTexture(..) => 0, // The texture is imported from the application and will never reside in our cache.
+            ImageInner::AnimatedImage(animated) => (0..animated.frame_count() as i32)
+                .map(|i| self.weight(_key, &animated.frame(i).0))
+                .sum(),
         }
     }
 }
@@ -97,17 +103,25 @@ pub(crate) fn load_image_from_path(&mut self, path: &SharedString) -> Option<Ima
                 )));
             }
 
+            if let Some(format) = image::ImageFormat::from_path(path.as_str())
+                .ok()
+                .filter(|format| is_animation_format(*format))
+            {
+                return std::fs::read(path.as_str())
+                    .map_err(image::ImageError::IoError)
+                    .and_then(|data| decode_image(&data, Some(format), cache_key))
+                    .map_err(|decode_err| {
+                        crate::debug_log!("Error loading image from {}: {}", &path, decode_err);
+                    })
+                    .ok();
+            }
+
             image::open(std::path::Path::new(&path.as_str())).map_or_else(
                 |decode_err| {
                     crate::debug_log!("Error loading image from {}: {}", &path, decode_err);
                     None
                 },
-                |image| {
-                    Some(ImageInner::EmbeddedImage {
-                        cache_key,
-                        buffer: dynamic_image_to_shared_image_buffer(image),
-                    })
-                },
+                |image| Some(still_image(image, cache_key)),
             )
         });
     }
@@ -135,26 +149,96 @@ pub(crate) fn load_image_from_embedded_data(
             let format = std::str::from_utf8(format.as_slice())
                 .ok()
                 .and_then(image::ImageFormat::from_extension);
-            let maybe_image = if let Some(format) = format {
-                image::load_from_memory_with_format(data.as_slice(), format)
-            } else {
-                image::load_from_memory(data.as_slice())
-            };
-
-            match maybe_image {
-                Ok(image) => Some(ImageInner::EmbeddedImage {
-                    cache_key,
-                    buffer: dynamic_image_to_shared_image_buffer(image),
-                }),
-                Err(decode_err) => {
+
+            decode_image(data.as_slice(), format, cache_key)
+                .map_err(|decode_err| {
                     crate::debug_log!("Error decoding embedded image: {}", decode_err);
-                    None
-                }
+                })
+                .ok()
+        })
+    }
+}
+
+/// Returns true if images of this format may contain several frames
+fn is_animation_format(format: image::ImageFormat) -> bool {
+    matches!(format, image::ImageFormat::Png | image::ImageFormat::Gif | image::ImageFormat::WebP)
+}
+
+/// Decode an image from memory, guessing the format if none is given.
+/// Formats that may contain several frames are decoded only once: as an animated image if
+/// there is more than one frame, and as a still image otherwise.
+fn decode_image(
+    data: &[u8],
+    format: Option<image::ImageFormat>,
+    cache_key: ImageCacheKey,
+) -> image::ImageResult<ImageInner> {
+    use image::AnimationDecoder;
+    let format = match format {
+        Some(format) => format,
+        None => image::guess_format(data)?,
+    };
+    let reader = std::io::Cursor::new(data);
+    let frames = match format {
+        image::ImageFormat::Png => {
+            let decoder = image::codecs::png::PngDecoder::new(reader)?;
+            if !decoder.is_apng()? {
+                return Ok(still_image(image::DynamicImage::from_decoder(decoder)?, cache_key));
+            }
+            decoder.apng()?.into_frames()
+        }
+        #[cfg(feature = "image-default-formats")]
+        image::ImageFormat::Gif => image::codecs::gif::GifDecoder::new(reader)?.into_frames(),
+        #[cfg(feature = "image-default-formats")]
+        image::ImageFormat::WebP => {
+            let decoder = image::codecs::webp::WebPDecoder::new(reader)?;
+            if !decoder.has_animation() {
+                return Ok(still_image(image::DynamicImage::from_decoder(decoder)?, cache_key));
             }
+            decoder.into_frames()
+        }
+        _ => return Ok(still_image(image::load_from_memory_with_format(data, format)?, cache_key)),
+    };
+    let mut frames = frames
+        .map(|frame| {
+            let frame = frame?;
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let duration = numer / denom.max(1);
+            // Like web browsers, use a default duration for frames that have (almost) none
+            let duration = if duration < 10 { 100 } else { duration };
+            let buffer = frame.into_buffer();
+            let buffer = SharedImageBuffer::RGBA8(SharedPixelBuffer::clone_from_slice(
+                buffer.as_raw(),
+                buffer.width(),
+                buffer.height(),
+            ));
+            Ok((buffer, duration))
         })
+        .collect::<image::ImageResult<alloc::vec::Vec<_>>>()?;
+    match frames.len() {
+        0 => Err(image::ImageError::Decoding(image::error::DecodingError::new(
+            format.into(),
+            "image has no frames",
+        ))),
+        1 => Ok(ImageInner::EmbeddedImage { cache_key, buffer: frames.pop().unwrap().0 }),
+        _ => {
+            let frames = frames
+                .into_iter()
+                .map(|(buffer, duration)| {
+                    (
+                        ImageInner::EmbeddedImage { cache_key: ImageCacheKey::Invalid, buffer },
+                        duration,
+                    )
+                })
+                .collect();
+            Ok(ImageInner::AnimatedImage(vtable::VRc::new(AnimatedImage::new(frames, cache_key))))
+        }
     }
 }
 
+fn still_image(image: image::DynamicImage, cache_key: ImageCacheKey) -> ImageInner {
+    ImageInner::EmbeddedImage { cache_key, buffer: dynamic_image_to_shared_image_buffer(image) }
+}
+
 fn dynamic_image_to_shared_image_buffer(dynamic_image: image::DynamicImage) -> SharedImageBuffer {
     if dynamic_image.color().has_alpha() {
         let rgba8image = dynamic_image.to_rgba8();
@@ -226,4 +310,45 @@ fn test_path_cache_invalidation() {
             .iter()
             .all(|pixel| *pixel == Rgba8Pixel { r: 0, g: 255, b: 0, a: 255 }));
     }
+
+    #[cfg(feature = "image-default-formats")]
+    #[test]
+    fn test_animated_gif() {
+        use crate::graphics::ImageInner;
+        // A 1x1 GIF with a red and a green frame, shown for 100ms and 200ms
+        static GIF: &[u8] = b"GIF89a\x01\x00\x01\x00\x80\x00\x00\xff\x00\x00\x00\xff\x00\
+            \x21\xf9\x04\x00\x0a\x00\x00\x00\x2c\x00\x00\x00\x00\x01\x00\x01\x00\x00\x02\x02\x44\x01\x00\
+            \x21\xf9\x04\x00\x14\x00\x00\x00\x2c\x00\x00\x00\x00\x01\x00\x01\x00\x00\x02\x02\x4c\x01\x00\x3b";
+        let image = crate::graphics::load_image_from_embedded_data(
+            crate::slice::Slice::from_slice(GIF),
+            crate::slice::Slice::from_slice(b"gif"),
+        );
+        let ImageInner::AnimatedImage(animated) = &image.0 else {
+            panic!("GIF was not loaded as an animated image");
+        };
+        assert_eq!(animated.frame_count(), 2);
+        assert_eq!(animated.frame_index_at(150), 1);
+        assert_eq!(animated.frame_index_at(300), 0);
+        let pixel = |frame| animated.frame(frame).to_rgba8().unwrap().as_slice()[0];
+        assert_eq!(pixel(0), Rgba8Pixel { r: 255, g: 0, b: 0, a: 255 });
+        assert_eq!(pixel(1), Rgba8Pixel { r: 0, g: 255, b: 0, a: 255 });
+    }
+
+    #[cfg(feature = "image-default-formats")]
+    #[test]
+    fn test_single_frame_gif() {
+        use crate::graphics::ImageInner;
+        // A 1x1 GIF with a single red frame
+        static GIF: &[u8] = b"GIF89a\x01\x00\x01\x00\x80\x00\x00\xff\x00\x00\x00\xff\x00\
+            \x21\xf9\x04\x00\x0a\x00\x00\x00\x2c\x00\x00\x00\x00\x01\x00\x01\x00\x00\x02\x02\x44\x01\x00\x3b";
+        let image = crate::graphics::load_image_from_embedded_data(
+            crate::slice::Slice::from_slice(GIF),
+            crate::slice::Slice::from_slice(b"gif"),
+        );
+        assert!(matches!(image.0, ImageInner::EmbeddedImage { .. }));
+        assert_eq!(
+            image.to_rgba8().unwrap().as_slice()[0],
+            Rgba8Pixel { r: 255, g: 0, b: 0, a: 255 }
+        );
+    }
 }
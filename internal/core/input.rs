@@ -17,7 +17,7 @@
 use alloc::rc::Rc;
 use alloc::vec::Vec;
 use const_field_offset::FieldOffsets;
-use core::cell::Cell;
+use core::cell::{Cell, RefCell};
 use core::pin::Pin;
 use core::time::Duration;
 
@@ -46,6 +46,21 @@ pub enum MouseEvent {
     /// `delta_x` is the amount of pixels to scroll in horizontal direction,
     /// `delta_y` is the amount of pixels to scroll in vertical direction.
     Wheel { position: LogicalPoint, delta_x: Coord, delta_y: Coord },
+    /// Two touch points are moved relative to each other to scale or rotate.
+    /// `position` is the center between the touch points.
+    /// `scale_delta` is the factor by which the distance between the points changed since the last event.
+    /// `rotation_delta` is the angle in degrees by which the points rotated clockwise since the last event.
+    PinchGesture {
+        position: LogicalPoint,
+        scale_delta: f32,
+        rotation_delta: f32,
+        phase: GesturePhase,
+    },
+    /// The pointer moved while a drag and drop operation is in progress.
+    /// The dragged data can be queried with [`crate::window::WindowInner::drag_data`].
+    DragMove { position: LogicalPoint },
+    /// The dragged data was dropped at `position`.
+    Drop { position: LogicalPoint },
     /// The mouse exited the item or component
     Exit,
 }
@@ -58,6 +73,9 @@ pub fn position(&self) -> Option<LogicalPoint> {
             MouseEvent::Released { position, .. } => Some(*position),
             MouseEvent::Moved { position } => Some(*position),
             MouseEvent::Wheel { position, .. } => Some(*position),
+            MouseEvent::PinchGesture { position, .. } => Some(*position),
+            MouseEvent::DragMove { position } => Some(*position),
+            MouseEvent::Drop { position } => Some(*position),
             MouseEvent::Exit => None,
         }
     }
@@ -69,6 +87,9 @@ pub fn translate(&mut self, vec: LogicalVector) {
             MouseEvent::Released { position, .. } => Some(position),
             MouseEvent::Moved { position } => Some(position),
             MouseEvent::Wheel { position, .. } => Some(position),
+            MouseEvent::PinchGesture { position, .. } => Some(position),
+            MouseEvent::DragMove { position } => Some(position),
+            MouseEvent::Drop { position } => Some(position),
             MouseEvent::Exit => None,
         };
         if let Some(pos) = pos {
@@ -87,6 +108,140 @@ fn set_click_count(&mut self, count: u8) {
     }
 }
 
+/// The phase of a [`MouseEvent::PinchGesture`]
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GesturePhase {
+    /// A second touch point was pressed and the gesture starts.
+    #[default]
+    Started,
+    /// The touch points moved.
+    Updated,
+    /// One of the touch points was released and the gesture is over.
+    Ended,
+}
+
+/// This enum defines the different kinds of touch events that can happen.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TouchEventType {
+    /// A touch point was pressed.
+    Pressed,
+    /// A touch point was moved.
+    Moved,
+    /// A touch point was released.
+    Released,
+}
+
+/// This state keeps track of the touch points pressed in a window, and translates touch events into
+/// mouse events: the first touch point acts as the left mouse button, and the relative movement of the
+/// first two touch points produces [`MouseEvent::PinchGesture`] events.
+///
+/// When the first touch point is released while other touch points are still pressed, no mouse
+/// events are emulated until all the touch points are released.
+#[derive(Default)]
+pub struct TouchState {
+    /// The pressed touch points in the order they were pressed
+    points: RefCell<Vec<(u64, LogicalPoint)>>,
+    /// The touch point that is forwarded as the left mouse button
+    primary: Cell<Option<u64>>,
+    /// True between the [`GesturePhase::Started`] and the [`GesturePhase::Ended`] events
+    gesture_active: Cell<bool>,
+}
+
+impl TouchState {
+    /// Update the state for the given touch event and return the mouse events to dispatch.
+    pub fn process(
+        &self,
+        touch_id: u64,
+        position: LogicalPoint,
+        event_type: TouchEventType,
+    ) -> impl Iterator<Item = MouseEvent> {
+        let mut events = [None; 3];
+        let mut points = self.points.borrow_mut();
+        let index = points.iter().position(|(id, _)| *id == touch_id);
+        let is_primary = self.primary.get() == Some(touch_id);
+        match (event_type, index) {
+            (TouchEventType::Pressed, None) => {
+                points.push((touch_id, position));
+                if points.len() == 1 {
+                    self.primary.set(Some(touch_id));
+                    events[0] = Some(MouseEvent::Pressed {
+                        position,
+                        button: PointerEventButton::Left,
+                        click_count: 0,
+                    });
+                } else if !self.gesture_active.replace(true) {
+                    events[0] = Some(Self::gesture_event(&points, GesturePhase::Started));
+                }
+            }
+            (TouchEventType::Moved, Some(index)) => {
+                let (_, previous_distance, previous_angle) = Self::gesture_geometry(&points);
+                points[index].1 = position;
+                if is_primary {
+                    events[0] = Some(MouseEvent::Moved { position });
+                }
+                if index < 2 && self.gesture_active.get() {
+                    let (center, distance, angle) = Self::gesture_geometry(&points);
+                    let rotation_delta = (angle - previous_angle + 540.) % 360. - 180.;
+                    events[1] = Some(MouseEvent::PinchGesture {
+                        position: center,
+                        scale_delta: if previous_distance > 0. {
+                            distance / previous_distance
+                        } else {
+                            1.
+                        },
+                        rotation_delta,
+                        phase: GesturePhase::Updated,
+                    });
+                }
+            }
+            (TouchEventType::Released, Some(index)) => {
+                points[index].1 = position;
+                if index < 2 && self.gesture_active.replace(false) {
+                    events[0] = Some(Self::gesture_event(&points, GesturePhase::Ended));
+                }
+                points.remove(index);
+                if is_primary {
+                    self.primary.set(None);
+                    events[1] = Some(MouseEvent::Released {
+                        position,
+                        button: PointerEventButton::Left,
+                        click_count: 0,
+                    });
+                }
+                // The gesture continues with the remaining touch points
+                if points.len() >= 2 && !self.gesture_active.replace(true) {
+                    events[2] = Some(Self::gesture_event(&points, GesturePhase::Started));
+                }
+            }
+            // Unknown touch point, or pressed twice
+            _ => {}
+        }
+        events.into_iter().flatten()
+    }
+
+    /// Returns the center, the distance and the angle in degrees between the first two touch points,
+    /// or a zero distance if there are less than two touch points.
+    fn gesture_geometry(points: &[(u64, LogicalPoint)]) -> (LogicalPoint, f32, f32) {
+        match points {
+            [(_, a), (_, b), ..] => {
+                let v = *b - *a;
+                (a.lerp(*b, 0.5), v.length() as f32, v.angle_from_x_axis().to_degrees() as f32)
+            }
+            _ => (LogicalPoint::default(), 0., 0.),
+        }
+    }
+
+    fn gesture_event(points: &[(u64, LogicalPoint)], phase: GesturePhase) -> MouseEvent {
+        MouseEvent::PinchGesture {
+            position: Self::gesture_geometry(points).0,
+            scale_delta: 1.,
+            rotation_delta: 0.,
+            phase,
+        }
+    }
+}
+
 /// This value is returned by the `input_event` function of an Item
 /// to notify the run-time about how the event was handled and
 /// what the next steps are.
@@ -615,6 +770,10 @@ pub(crate) fn handle_mouse_grab(
     let input_result = grabber.borrow().as_ref().input_event(event, window_adapter, &grabber);
     if input_result != InputEventResult::GrabMouse {
         mouse_input_state.grabbed = false;
+        if matches!(mouse_event, MouseEvent::PinchGesture { .. }) {
+            // The grabber is not interested in gestures, so let the items under the gesture handle it
+            return Some(mouse_event);
+        }
         // Return a move event so that the new position can be registered properly
         Some(
             mouse_event
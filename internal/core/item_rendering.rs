@@ -18,6 +18,7 @@
 use crate::{Brush, Coord, SharedString};
 use alloc::boxed::Box;
 use alloc::rc::Rc;
+use alloc::vec::Vec;
 use core::cell::{Cell, RefCell};
 use core::pin::Pin;
 #[cfg(feature = "std")]
@@ -397,6 +398,17 @@ fn draw_text_input(
         _self_rc: &ItemRc,
         _size: LogicalSize,
     );
+    /// Draws a `StyledText` element. Renderers that don't support styled text draw
+    /// its plain text instead.
+    fn draw_styled_text(
+        &mut self,
+        styled_text: Pin<&StyledText>,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+        cache: &CachedRenderingData,
+    ) {
+        self.draw_text(styled_text, self_rc, size, cache)
+    }
     #[cfg(feature = "std")]
     fn draw_path(&mut self, path: Pin<&Path>, _self_rc: &ItemRc, _size: LogicalSize);
     fn draw_box_shadow(
@@ -757,6 +769,9 @@ pub struct PartialRenderer<'a, T> {
     pub actual_renderer: T,
     /// The window adapter the renderer is rendering into.
     pub window_adapter: Rc<dyn WindowAdapter>,
+    /// Maps the current item coordinates to the window, to check against the dirty region
+    transform: ItemTransform,
+    transform_stack: Vec<ItemTransform>,
 }
 
 impl<'a, T: ItemRenderer + ItemRendererFeatures> PartialRenderer<'a, T> {
@@ -767,7 +782,14 @@ pub fn new(
         actual_renderer: T,
     ) -> Self {
         let window_adapter = actual_renderer.window().window_adapter();
-        Self { cache, dirty_region: initial_dirty_region, actual_renderer, window_adapter }
+        Self {
+            cache,
+            dirty_region: initial_dirty_region,
+            actual_renderer,
+            window_adapter,
+            transform: ItemTransform::identity(),
+            transform_stack: Vec::new(),
+        }
     }
 
     /// Visit the tree of item and compute what are the dirty regions
@@ -1038,7 +1060,7 @@ fn filter_item(
 
         let clipped_geom = self.get_current_clip().intersection(&item_bounding_rect);
         let draw = clipped_geom.is_some_and(|clipped_geom| {
-            let clipped_geom = clipped_geom.translate(self.translation());
+            let clipped_geom = self.transform.outer_transformed_rect(&clipped_geom.cast()).cast();
             self.dirty_region.draw_intersects(clipped_geom)
         });
 
@@ -1054,6 +1076,7 @@ fn filter_item(
     forward_rendering_call2!(fn draw_image(dyn RenderImage));
     forward_rendering_call2!(fn draw_text(dyn RenderText));
     forward_rendering_call!(fn draw_text_input(TextInput));
+    forward_rendering_call2!(fn draw_styled_text(StyledText));
     #[cfg(feature = "std")]
     forward_rendering_call!(fn draw_path(Path));
     forward_rendering_call!(fn draw_box_shadow(BoxShadow));
@@ -1075,6 +1098,7 @@ fn get_current_clip(&self) -> LogicalRect {
     }
 
     fn translate(&mut self, distance: LogicalVector) {
+        self.transform = ItemTransform::translation(distance.x, distance.y).then(&self.transform);
         self.actual_renderer.translate(distance)
     }
     fn translation(&self) -> LogicalVector {
@@ -1082,6 +1106,8 @@ fn translation(&self) -> LogicalVector {
     }
 
     fn rotate(&mut self, angle_in_degrees: f32) {
+        self.transform =
+            ItemTransform::rotation(euclid::Angle::degrees(angle_in_degrees)).then(&self.transform);
         self.actual_renderer.rotate(angle_in_degrees)
     }
 
@@ -1090,10 +1116,12 @@ fn apply_opacity(&mut self, opacity: f32) {
     }
 
     fn save_state(&mut self) {
+        self.transform_stack.push(self.transform);
         self.actual_renderer.save_state()
     }
 
     fn restore_state(&mut self) {
+        self.transform = self.transform_stack.pop().unwrap_or_default();
         self.actual_renderer.restore_state()
     }
 
@@ -20,6 +20,7 @@
 #![allow(non_upper_case_globals)]
 #![allow(missing_docs)] // because documenting each property of items is redundant
 
+use crate::api::LogicalPosition;
 use crate::graphics::{Brush, Color, FontRequest};
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEventResult,
@@ -50,8 +51,12 @@
 pub use flickable::Flickable;
 mod text;
 pub use text::*;
+mod styled_text;
+pub use styled_text::*;
 mod input_items;
 pub use input_items::*;
+mod drag_n_drop;
+pub use drag_n_drop::*;
 mod image;
 pub use self::image::*;
 #[cfg(feature = "std")]
@@ -71,6 +76,8 @@
 type PointerEventArg = (PointerEvent,);
 type PointerScrollEventArg = (PointerScrollEvent,);
 type PointArg = (crate::api::LogicalPosition,);
+type StringArg = (SharedString,);
+type DropEventArg = (DropEvent,);
 type MenuEntryArg = (MenuEntry,);
 type MenuEntryModel = crate::model::ModelRc<MenuEntry>;
 
@@ -618,6 +625,18 @@ fn slint_get_FocusScopeVTable() -> FocusScopeVTable for FocusScope
     fn slint_get_SwipeGestureHandlerVTable() -> SwipeGestureHandlerVTable for SwipeGestureHandler
 }
 
+declare_item_vtable! {
+    fn slint_get_PinchGestureHandlerVTable() -> PinchGestureHandlerVTable for PinchGestureHandler
+}
+
+declare_item_vtable! {
+    fn slint_get_DragAreaVTable() -> DragAreaVTable for DragArea
+}
+
+declare_item_vtable! {
+    fn slint_get_DropAreaVTable() -> DropAreaVTable for DropArea
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -1020,10 +1039,16 @@ fn render(
     fn bounding_rect(
         self: core::pin::Pin<&Self>,
         _window_adapter: &Rc<dyn WindowAdapter>,
-        _self_rc: &ItemRc,
+        self_rc: &ItemRc,
         geometry: LogicalRect,
     ) -> LogicalRect {
-        geometry
+        // The children cover the item's geometry, rotated around the origin
+        self_rc.children_transform().map_or(geometry, |transform| {
+            transform
+                .outer_transformed_rect(&LogicalRect::from_size(geometry.size).cast())
+                .cast()
+                .translate(geometry.origin.to_vector())
+        })
     }
 
     fn clips_children(self: core::pin::Pin<&Self>) -> bool {
@@ -1061,6 +1086,14 @@ pub struct PropertyAnimation {
     pub direction: AnimationDirection,
     #[rtti_field]
     pub easing: crate::animations::EasingCurve,
+    /// When greater than zero, the animation is a spring animation, and the duration, easing,
+    /// iteration count and direction are ignored.
+    #[rtti_field]
+    pub stiffness: f32,
+    #[rtti_field]
+    pub damping: f32,
+    #[rtti_field]
+    pub mass: f32,
 }
 
 impl Default for PropertyAnimation {
@@ -1073,6 +1106,9 @@ fn default() -> Self {
             iteration_count: 1.,
             direction: Default::default(),
             easing: Default::default(),
+            stiffness: 0.,
+            damping: 10.,
+            mass: 1.,
         }
     }
 }
@@ -1094,6 +1130,7 @@ pub struct WindowItem {
     pub default_font_family: Property<SharedString>,
     pub default_font_size: Property<LogicalLength>,
     pub default_font_weight: Property<i32>,
+    pub layout_direction: Property<LayoutDirection>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -1591,6 +1628,10 @@ fn slint_get_ComponentContainerVTable() -> ComponentContainerVTable for Componen
     fn slint_get_ComplexTextVTable() -> ComplexTextVTable for ComplexText
 }
 
+declare_item_vtable! {
+    fn slint_get_StyledTextVTable() -> StyledTextVTable for StyledText
+}
+
 declare_item_vtable! {
     fn slint_get_SimpleTextVTable() -> SimpleTextVTable for SimpleText
 }
@@ -309,6 +309,20 @@ fn handle_mouse_filter(
                 }
             }
             MouseEvent::Wheel { .. } => InputEventFilterResult::ForwardEvent,
+            MouseEvent::PinchGesture { .. } => {
+                if inner.capture_events {
+                    InputEventFilterResult::Intercept
+                } else {
+                    // Let the children handle the gesture instead of flicking
+                    inner.pressed_time = None;
+                    InputEventFilterResult::ForwardEvent
+                }
+            }
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => {
+                // A drag and drop operation started from within the flickable
+                inner.pressed_time = None;
+                InputEventFilterResult::ForwardEvent
+            }
             // Not the left button
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
@@ -406,6 +420,14 @@ fn handle_mouse(
                 }
                 InputEventResult::EventAccepted
             }
+            MouseEvent::PinchGesture { .. } => {
+                if inner.capture_events {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
         }
     }
 
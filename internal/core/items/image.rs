@@ -11,6 +11,7 @@
     ImageFit, ImageHorizontalAlignment, ImageRendering, ImageTiling, ImageVerticalAlignment, Item,
     ItemConsts, ItemRc, RenderingResult,
 };
+use crate::graphics::ImageInner;
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
     KeyEventResult, MouseEvent,
@@ -39,6 +40,8 @@ pub struct ImageItem {
     pub image_fit: Property<ImageFit>,
     pub image_rendering: Property<ImageRendering>,
     pub colorize: Property<Brush>,
+    pub playing: Property<bool>,
+    pub frame: Property<i32>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -130,7 +133,7 @@ fn target_size(self: Pin<&Self>) -> LogicalSize {
     }
 
     fn source(self: Pin<&Self>) -> crate::graphics::Image {
-        self.source()
+        current_frame(self.source(), self.playing(), self.frame())
     }
 
     fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
@@ -176,6 +179,8 @@ pub struct ClippedImage {
     pub image_fit: Property<ImageFit>,
     pub image_rendering: Property<ImageRendering>,
     pub colorize: Property<Brush>,
+    pub playing: Property<bool>,
+    pub frame: Property<i32>,
     pub source_clip_x: Property<i32>,
     pub source_clip_y: Property<i32>,
     pub source_clip_width: Property<i32>,
@@ -281,7 +286,7 @@ fn target_size(self: Pin<&Self>) -> LogicalSize {
     }
 
     fn source(self: Pin<&Self>) -> crate::graphics::Image {
-        self.source()
+        current_frame(self.source(), self.playing(), self.frame())
     }
 
     fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
@@ -320,3 +325,23 @@ impl ItemConsts for ClippedImage {
         CachedRenderingData,
     > = ClippedImage::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
 }
+
+/// Returns the frame to display if `source` is an animated image, or `source` itself otherwise.
+///
+/// When `playing` is true, the frame is chosen from the animation tick, and the item is
+/// redrawn when the next frame is due. Otherwise the frame at index `frame` is displayed.
+fn current_frame(
+    source: crate::graphics::Image,
+    playing: bool,
+    frame: i32,
+) -> crate::graphics::Image {
+    let inner: &ImageInner = (&source).into();
+    let ImageInner::AnimatedImage(animated) = inner else {
+        return source;
+    };
+    if playing {
+        animated.playing_frame()
+    } else {
+        animated.frame(frame)
+    }
+}
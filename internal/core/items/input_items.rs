@@ -8,8 +8,8 @@
 };
 use crate::api::LogicalPosition;
 use crate::input::{
-    FocusEvent, FocusEventResult, FocusReason, InputEventFilterResult, InputEventResult, KeyEvent,
-    KeyEventResult, KeyEventType, MouseEvent,
+    FocusEvent, FocusEventResult, FocusReason, GesturePhase, InputEventFilterResult,
+    InputEventResult, KeyEvent, KeyEventResult, KeyEventType, MouseEvent,
 };
 use crate::item_rendering::CachedRenderingData;
 use crate::layout::{LayoutInfo, Orientation};
@@ -83,6 +83,13 @@ fn input_event_filter_before_children(
             }
             return InputEventFilterResult::ForwardAndIgnore;
         }
+        if matches!(event, MouseEvent::PinchGesture { .. }) {
+            return InputEventFilterResult::ForwardEvent;
+        }
+        if matches!(event, MouseEvent::DragMove { .. } | MouseEvent::Drop { .. }) {
+            Self::FIELD_OFFSETS.has_hover.apply_pin(self).set(false);
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
         if let Some(pos) = event.position() {
             Self::FIELD_OFFSETS.mouse_x.apply_pin(self).set(pos.x_length());
             Self::FIELD_OFFSETS.mouse_y.apply_pin(self).set(pos.y_length());
@@ -200,6 +207,14 @@ fn input_event(
                     }
                 }
             }
+            MouseEvent::PinchGesture { .. } => {
+                if self.grabbed.get() {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
         }
     }
 
@@ -480,7 +495,10 @@ fn input_event_filter_before_children(
                     }
                 }
             }
-            MouseEvent::Wheel { .. } => InputEventFilterResult::ForwardAndIgnore,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::PinchGesture { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. } => InputEventFilterResult::ForwardAndIgnore,
             // Not the left button
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
@@ -536,7 +554,10 @@ fn input_event(
                 Self::FIELD_OFFSETS.moved.apply_pin(self).call(&());
                 InputEventResult::GrabMouse
             }
-            MouseEvent::Wheel { .. } => InputEventResult::EventIgnored,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::PinchGesture { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
         }
     }
 
@@ -616,3 +637,214 @@ fn cancel_impl(self: Pin<&Self>) {
     let self_rc = ItemRc::new(self_component.clone(), self_index);
     s.cancel(window_adapter, &self_rc);
 }
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct PinchGestureHandler {
+    pub enabled: Property<bool>,
+
+    pub started: Callback<VoidArg>,
+    pub updated: Callback<VoidArg>,
+    pub ended: Callback<VoidArg>,
+    pub cancelled: Callback<VoidArg>,
+
+    pub active: Property<bool>,
+    pub scale: Property<f32>,
+    pub rotation: Property<f32>,
+    pub center: Property<LogicalPosition>,
+    pub pan_x: Property<LogicalLength>,
+    pub pan_y: Property<LogicalLength>,
+
+    // true when the first touch point is pressed on this item
+    pressed: Cell<bool>,
+    // the center of the gesture when it started
+    start_center: Cell<LogicalPoint>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for PinchGestureHandler {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            self.cancel_impl();
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+
+        match event {
+            // Take the events away from the children as soon as a second touch point is pressed
+            MouseEvent::PinchGesture { .. } => InputEventFilterResult::Intercept,
+            MouseEvent::Exit => {
+                self.cancel_impl();
+                InputEventFilterResult::ForwardAndIgnore
+            }
+            MouseEvent::Wheel { .. } | MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => {
+                InputEventFilterResult::ForwardAndIgnore
+            }
+            MouseEvent::Pressed { .. } | MouseEvent::Released { .. } | MouseEvent::Moved { .. } => {
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        match event {
+            MouseEvent::Pressed { button: PointerEventButton::Left, .. } => {
+                self.pressed.set(true);
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Exit => {
+                self.cancel_impl();
+                InputEventResult::EventIgnored
+            }
+            MouseEvent::Released { button: PointerEventButton::Left, .. } => {
+                if self.pressed.replace(false) {
+                    InputEventResult::EventAccepted
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::Moved { .. } => {
+                if self.pressed.get() || self.active() {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::PinchGesture { position, scale_delta, rotation_delta, phase } => {
+                let center = crate::lengths::logical_position_to_api(position);
+                match phase {
+                    GesturePhase::Started | GesturePhase::Updated if !self.active() => {
+                        self.start_center.set(position);
+                        Self::FIELD_OFFSETS.active.apply_pin(self).set(true);
+                        Self::FIELD_OFFSETS.scale.apply_pin(self).set(1.);
+                        Self::FIELD_OFFSETS.rotation.apply_pin(self).set(0.);
+                        Self::FIELD_OFFSETS.center.apply_pin(self).set(center);
+                        Self::FIELD_OFFSETS.pan_x.apply_pin(self).set(LogicalLength::default());
+                        Self::FIELD_OFFSETS.pan_y.apply_pin(self).set(LogicalLength::default());
+                        Self::FIELD_OFFSETS.started.apply_pin(self).call(&());
+                    }
+                    GesturePhase::Started | GesturePhase::Updated => {
+                        let pan = position - self.start_center.get();
+                        Self::FIELD_OFFSETS.scale.apply_pin(self).set(self.scale() * scale_delta);
+                        Self::FIELD_OFFSETS
+                            .rotation
+                            .apply_pin(self)
+                            .set(self.rotation() + rotation_delta);
+                        Self::FIELD_OFFSETS.center.apply_pin(self).set(center);
+                        Self::FIELD_OFFSETS.pan_x.apply_pin(self).set(pan.x_length());
+                        Self::FIELD_OFFSETS.pan_y.apply_pin(self).set(pan.y_length());
+                        Self::FIELD_OFFSETS.updated.apply_pin(self).call(&());
+                    }
+                    GesturePhase::Ended => {
+                        if !self.active() {
+                            return InputEventResult::EventIgnored;
+                        }
+                        Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+                        Self::FIELD_OFFSETS.ended.apply_pin(self).call(&());
+                    }
+                }
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Wheel { .. }
+            | MouseEvent::Pressed { .. }
+            | MouseEvent::Released { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _event: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+        mut geometry: LogicalRect,
+    ) -> LogicalRect {
+        geometry.size = LogicalSize::zero();
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        false
+    }
+}
+
+impl ItemConsts for PinchGestureHandler {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl PinchGestureHandler {
+    pub fn cancel(self: Pin<&Self>, _: &Rc<dyn WindowAdapter>, _: &ItemRc) {
+        self.cancel_impl();
+    }
+
+    fn cancel_impl(self: Pin<&Self>) {
+        self.pressed.set(false);
+        if self.active() {
+            Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+            Self::FIELD_OFFSETS.cancelled.apply_pin(self).call(&());
+        }
+    }
+}
+
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_pinchgesturehandler_cancel(
+    s: Pin<&PinchGestureHandler>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    s.cancel(window_adapter, &self_rc);
+}
@@ -0,0 +1,674 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This module contains the `StyledText` element and the parser for the markup it displays.
+
+When adding an item or a property, it needs to be kept in sync with different place.
+Lookup the [`crate::items`] module documentation.
+*/
+use super::{
+    Item, ItemConsts, ItemRc, KeyEventResult, MouseCursor, PointerEventButton, RenderingResult,
+    StringArg, TextHorizontalAlignment, TextOverflow, TextStrokeStyle, TextVerticalAlignment,
+    TextWrap, WindowItem,
+};
+use crate::graphics::{Brush, Color, FontRequest};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent, MouseEvent,
+};
+use crate::item_rendering::{CachedRenderingData, ItemRenderer, RenderText};
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{
+    LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor, SizeLengths,
+};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::{WindowAdapter, WindowInner};
+use crate::{Callback, Property, SharedString};
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::ops::Range;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+
+/// The style of a range of text in a [`StyledString`]. Fields that are not set use the
+/// value of the `StyledText` element.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpanStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub color: Option<Color>,
+    pub font_size: Option<LogicalLength>,
+    /// The target of the link, if the text is part of a link
+    pub link: Option<SharedString>,
+}
+
+impl SpanStyle {
+    /// Returns true if the text in this style uses the element's font
+    pub fn has_default_font(&self) -> bool {
+        !self.bold && !self.italic && self.font_size.is_none()
+    }
+
+    /// Returns the font request for text in this style, given the element's font request
+    pub fn font_request(&self, base: &FontRequest) -> FontRequest {
+        let mut request = base.clone();
+        if self.bold {
+            request.weight = Some(request.weight.unwrap_or(400).max(700));
+        }
+        request.italic |= self.italic;
+        if let Some(font_size) = self.font_size {
+            request.pixel_size = Some(font_size);
+        }
+        request
+    }
+}
+
+/// A range of the text of a [`StyledString`] with its style
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyledSpan {
+    pub byte_range: Range<usize>,
+    pub style: SpanStyle,
+}
+
+/// Plain text with styled spans, as parsed from the markup of a `StyledText` element.
+///
+/// The spans are sorted, don't overlap, and only cover the text that isn't in the default style.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyledString {
+    pub text: String,
+    pub spans: Vec<StyledSpan>,
+}
+
+impl StyledString {
+    /// Parses the markup supported by the `StyledText` element:
+    ///  - `<b>` and `<strong>` for bold text, `<i>` and `<em>` for italic text
+    ///  - `<span color="#rrggbb" size="20px">` for colored or resized text. Colors can also be written
+    ///    as `#rgb`, `#rgba` or `#rrggbbaa`
+    ///  - `<a href="...">` for links
+    ///  - `<br>` for line breaks
+    ///  - the `&lt;`, `&gt;`, `&amp;`, `&quot;`, `&apos;` and `&nbsp;` entities as well as numeric
+    ///    character references such as `&#169;` or `&#xA9;`
+    ///
+    /// Anything that isn't a known tag or entity is kept as text. Closing tags that don't match an
+    /// opened tag are ignored.
+    pub fn parse(markup: &str) -> Self {
+        let mut result = Self::default();
+        // The open tags with the style of the text within them
+        let mut stack: Vec<(&str, SpanStyle)> = Vec::new();
+        let mut rest = markup;
+
+        while let Some(ch) = rest.chars().next() {
+            let current_style = stack.last().map(|(_, style)| style);
+            if ch == '<' {
+                if let Some((tag, len)) = parse_tag(rest) {
+                    rest = &rest[len..];
+                    match tag {
+                        Tag::LineBreak => result.push_str("\n", current_style),
+                        Tag::Open(name, attributes) => {
+                            let style = open_tag_style(
+                                name,
+                                &attributes,
+                                current_style.cloned().unwrap_or_default(),
+                            );
+                            stack.push((name, style));
+                        }
+                        Tag::Close(name) => {
+                            if let Some(pos) = stack.iter().rposition(|(n, _)| *n == name) {
+                                stack.truncate(pos);
+                            }
+                        }
+                    }
+                    continue;
+                }
+            } else if ch == '&' {
+                if let Some((decoded, len)) = parse_entity(rest) {
+                    rest = &rest[len..];
+                    result.push_str(decoded.encode_utf8(&mut [0; 4]), current_style);
+                    continue;
+                }
+            }
+            let len = rest.find(['<', '&']).filter(|len| *len > 0).unwrap_or(ch.len_utf8());
+            result.push_str(&rest[..len], current_style);
+            rest = &rest[len..];
+        }
+        result
+    }
+
+    fn push_str(&mut self, text: &str, style: Option<&SpanStyle>) {
+        let start = self.text.len();
+        self.text.push_str(text);
+        let Some(style) = style.filter(|style| **style != SpanStyle::default()) else { return };
+        match self.spans.last_mut() {
+            Some(span) if span.byte_range.end == start && span.style == *style => {
+                span.byte_range.end = self.text.len();
+            }
+            _ => self
+                .spans
+                .push(StyledSpan { byte_range: start..self.text.len(), style: style.clone() }),
+        }
+    }
+
+    /// Returns the style of the text at the given byte offset, or None if it has the default style
+    pub fn style_at(&self, byte_offset: usize) -> Option<&SpanStyle> {
+        let index = self.spans.partition_point(|span| span.byte_range.end <= byte_offset);
+        self.spans
+            .get(index)
+            .filter(|span| span.byte_range.start <= byte_offset)
+            .map(|span| &span.style)
+    }
+
+    /// Returns the target of the link at the given byte offset
+    pub fn link_at(&self, byte_offset: usize) -> Option<&SharedString> {
+        self.style_at(byte_offset).and_then(|style| style.link.as_ref())
+    }
+}
+
+enum Tag<'a> {
+    Open(&'a str, Vec<(&'a str, String)>),
+    Close(&'a str),
+    LineBreak,
+}
+
+/// Parses a known tag at the start of `text` and returns it with its length in bytes
+fn parse_tag(text: &str) -> Option<(Tag<'_>, usize)> {
+    let end = text.find('>')?;
+    let content = text[1..end].trim();
+    let (is_close, content) = match content.strip_prefix('/') {
+        Some(content) => (true, content.trim_start()),
+        None => (false, content),
+    };
+    let content = content.strip_suffix('/').unwrap_or(content).trim_end();
+    let name_len = content.find(|c: char| c.is_ascii_whitespace()).unwrap_or(content.len());
+    let name = &content[..name_len];
+    let name = ["b", "strong", "i", "em", "span", "a", "br"]
+        .into_iter()
+        .find(|known| known.eq_ignore_ascii_case(name))?;
+
+    let tag = if name == "br" {
+        Tag::LineBreak
+    } else if is_close {
+        Tag::Close(name)
+    } else {
+        Tag::Open(name, parse_attributes(&content[name_len..])?)
+    };
+    Some((tag, end + 1))
+}
+
+/// Parses attributes of the form `name="value"`, `name='value'` or `name=value`
+fn parse_attributes(mut text: &str) -> Option<Vec<(&str, String)>> {
+    let mut attributes = Vec::new();
+    loop {
+        text = text.trim_start();
+        if text.is_empty() {
+            return Some(attributes);
+        }
+        let (name, rest) = text.split_once('=')?;
+        let rest = rest.trim_start();
+        let (value, rest) = match rest.chars().next()? {
+            quote @ ('"' | '\'') => rest[1..].split_once(quote)?,
+            _ => rest.split_at(rest.find(|c: char| c.is_ascii_whitespace()).unwrap_or(rest.len())),
+        };
+        attributes.push((name.trim(), decode_entities(value)));
+        text = rest;
+    }
+}
+
+fn decode_entities(mut text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    while let Some(pos) = text.find('&') {
+        result.push_str(&text[..pos]);
+        text = &text[pos..];
+        match parse_entity(text) {
+            Some((ch, len)) => {
+                result.push(ch);
+                text = &text[len..];
+            }
+            None => {
+                result.push('&');
+                text = &text[1..];
+            }
+        }
+    }
+    result.push_str(text);
+    result
+}
+
+/// Parses an entity at the start of `text` and returns the character with the length of the entity in bytes
+fn parse_entity(text: &str) -> Option<(char, usize)> {
+    let (end, _) = text.char_indices().take(12).find(|(_, c)| *c == ';')?;
+    let name = &text[1..end];
+    let ch = match name {
+        "lt" => '<',
+        "gt" => '>',
+        "amp" => '&',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            char::from_u32(code)?
+        }
+    };
+    Some((ch, end + 1))
+}
+
+fn open_tag_style(name: &str, attributes: &[(&str, String)], mut style: SpanStyle) -> SpanStyle {
+    let attribute = |attribute_name: &str| {
+        attributes
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(attribute_name))
+            .map(|(_, value)| value.as_str())
+    };
+    match name {
+        "b" | "strong" => style.bold = true,
+        "i" | "em" => style.italic = true,
+        "span" => {
+            if let Some(color) = attribute("color").and_then(parse_color) {
+                style.color = Some(color);
+            }
+            if let Some(size) = attribute("size").and_then(parse_size) {
+                style.font_size = Some(size);
+            }
+        }
+        "a" => style.link = Some(attribute("href").unwrap_or_default().into()),
+        _ => {}
+    }
+    style
+}
+
+/// Parses colors of the form `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`
+fn parse_color(value: &str) -> Option<Color> {
+    let hex = value.trim().strip_prefix('#')?;
+    if !hex.is_ascii() {
+        return None;
+    }
+    let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).ok().map(|d| d * 0x11);
+    let byte = |i: usize| u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok();
+    let (r, g, b, a) = match hex.len() {
+        3 => (digit(0)?, digit(1)?, digit(2)?, 0xff),
+        4 => (digit(0)?, digit(1)?, digit(2)?, digit(3)?),
+        6 => (byte(0)?, byte(1)?, byte(2)?, 0xff),
+        8 => (byte(0)?, byte(1)?, byte(2)?, byte(3)?),
+        _ => return None,
+    };
+    Some(Color::from_argb_u8(a, r, g, b))
+}
+
+/// Parses sizes of the form `20px` or `20`
+fn parse_size(value: &str) -> Option<LogicalLength> {
+    let value = value.trim();
+    let size: f32 = value.strip_suffix("px").unwrap_or(value).trim_end().parse().ok()?;
+    (size > 0.).then(|| LogicalLength::new(size as crate::Coord))
+}
+
+/// The implementation of the `StyledText` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct StyledText {
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub text: Property<SharedString>,
+    pub font_size: Property<LogicalLength>,
+    pub font_weight: Property<i32>,
+    pub color: Property<Brush>,
+    pub link_color: Property<Color>,
+    pub horizontal_alignment: Property<TextHorizontalAlignment>,
+    pub vertical_alignment: Property<TextVerticalAlignment>,
+
+    pub font_family: Property<SharedString>,
+    pub font_italic: Property<bool>,
+    pub wrap: Property<TextWrap>,
+    pub overflow: Property<TextOverflow>,
+    pub letter_spacing: Property<LogicalLength>,
+    pub link_clicked: Callback<StringArg>,
+    /// The target of the link on which the left mouse button was pressed, or empty
+    pressed_link: Cell<SharedString>,
+    /// True while the mouse cursor is shown as a pointer because it is over a link
+    hovering_link: Cell<bool>,
+    /// The text that was last parsed, and the result
+    styled_string_cache: Cell<Option<(SharedString, Rc<StyledString>)>>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for StyledText {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        let window_inner = WindowInner::from_pub(window_adapter.window());
+        let styled_string = self.styled_string();
+        let font_request = RenderText::font_request(self, self_rc);
+        let scale_factor = ScaleFactor::new(window_inner.scale_factor());
+        let implicit_size = |max_width, text_wrap| {
+            window_adapter.renderer().styled_text_size(
+                font_request.clone(),
+                &styled_string,
+                max_width,
+                scale_factor,
+                text_wrap,
+            )
+        };
+        super::text::text_layout_info_for_size(
+            self,
+            font_request.clone(),
+            scale_factor,
+            window_adapter,
+            orientation,
+            Self::FIELD_OFFSETS.width.apply_pin(self),
+            implicit_size,
+        )
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardEvent
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputEventResult {
+        let set_hovering_link = |hovering: bool| {
+            if self.hovering_link.replace(hovering) != hovering {
+                if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                    x.set_mouse_cursor(if hovering {
+                        MouseCursor::Pointer
+                    } else {
+                        MouseCursor::Default
+                    });
+                }
+            }
+        };
+
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                match self.link_at_position(position, window_adapter, self_rc) {
+                    Some(link) => {
+                        self.pressed_link.set(link);
+                        InputEventResult::GrabMouse
+                    }
+                    None => InputEventResult::EventIgnored,
+                }
+            }
+            MouseEvent::Released { position, button: PointerEventButton::Left, .. } => {
+                let pressed_link = self.pressed_link.take();
+                if pressed_link.is_empty() {
+                    return InputEventResult::EventIgnored;
+                }
+                if self.link_at_position(position, window_adapter, self_rc).as_ref()
+                    == Some(&pressed_link)
+                {
+                    Self::FIELD_OFFSETS.link_clicked.apply_pin(self).call(&(pressed_link,));
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Moved { position } => {
+                let over_link = self.link_at_position(position, window_adapter, self_rc).is_some();
+                set_hovering_link(over_link);
+                // Keep the grab while the button is pressed on a link
+                let pressed_link = self.pressed_link.take();
+                let has_pressed_link = !pressed_link.is_empty();
+                self.pressed_link.set(pressed_link);
+                if has_pressed_link {
+                    InputEventResult::GrabMouse
+                } else if over_link {
+                    InputEventResult::EventAccepted
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::Exit => {
+                self.pressed_link.take();
+                set_hovering_link(false);
+                InputEventResult::EventIgnored
+            }
+            MouseEvent::Pressed { .. }
+            | MouseEvent::Released { .. }
+            | MouseEvent::Wheel { .. }
+            | MouseEvent::PinchGesture { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut &mut dyn ItemRenderer,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        (*backend).draw_styled_text(self, self_rc, size, &self.cached_rendering_data);
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+        mut geometry: LogicalRect,
+    ) -> LogicalRect {
+        let window_inner = WindowInner::from_pub(window_adapter.window());
+        let scale_factor = ScaleFactor::new(window_inner.scale_factor());
+        geometry.size = geometry.size.max(window_adapter.renderer().styled_text_size(
+            RenderText::font_request(self, self_rc),
+            &self.styled_string(),
+            Some(geometry.size.width_length()),
+            scale_factor,
+            self.wrap(),
+        ));
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        false
+    }
+}
+
+impl ItemConsts for StyledText {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        StyledText,
+        CachedRenderingData,
+    > = StyledText::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+/// Renderers that can't draw styled text render the `StyledText` element as plain text
+impl RenderText for StyledText {
+    fn target_size(self: Pin<&Self>) -> LogicalSize {
+        LogicalSize::from_lengths(self.width(), self.height())
+    }
+
+    fn text(self: Pin<&Self>) -> SharedString {
+        self.styled_string().text.as_str().into()
+    }
+
+    fn font_request(self: Pin<&Self>, self_rc: &ItemRc) -> FontRequest {
+        WindowItem::resolved_font_request(
+            self_rc,
+            self.font_family(),
+            self.font_weight(),
+            self.font_size(),
+            self.letter_spacing(),
+            self.font_italic(),
+        )
+    }
+
+    fn color(self: Pin<&Self>) -> Brush {
+        self.color()
+    }
+
+    fn alignment(self: Pin<&Self>) -> (TextHorizontalAlignment, TextVerticalAlignment) {
+        (self.horizontal_alignment(), self.vertical_alignment())
+    }
+
+    fn wrap(self: Pin<&Self>) -> TextWrap {
+        self.wrap()
+    }
+
+    fn overflow(self: Pin<&Self>) -> TextOverflow {
+        self.overflow()
+    }
+
+    fn letter_spacing(self: Pin<&Self>) -> LogicalLength {
+        self.letter_spacing()
+    }
+
+    fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
+        Default::default()
+    }
+}
+
+impl StyledText {
+    /// Returns the parsed markup of the `text` property. It is only parsed again when the text changes.
+    pub fn styled_string(self: Pin<&Self>) -> Rc<StyledString> {
+        let text = self.text();
+        let styled_string = match self.styled_string_cache.take() {
+            Some((cached_text, styled_string)) if cached_text == text => styled_string,
+            _ => Rc::new(StyledString::parse(text.as_str())),
+        };
+        self.styled_string_cache.set(Some((text, styled_string.clone())));
+        styled_string
+    }
+
+    /// Returns the color of text in the given style
+    pub fn span_color(self: Pin<&Self>, style: Option<&SpanStyle>) -> Brush {
+        match style {
+            Some(SpanStyle { color: Some(color), .. }) => (*color).into(),
+            Some(SpanStyle { link: Some(_), .. }) => self.link_color().into(),
+            _ => self.color(),
+        }
+    }
+
+    fn link_at_position(
+        self: Pin<&Self>,
+        position: LogicalPoint,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> Option<SharedString> {
+        let window_inner = WindowInner::from_pub(window_adapter.window());
+        let scale_factor = ScaleFactor::new(window_inner.scale_factor());
+        let byte_offset = window_adapter.renderer().styled_text_byte_offset_for_position(
+            self,
+            position,
+            RenderText::font_request(self, self_rc),
+            scale_factor,
+        )?;
+        // `<a>` tags without `href` are not links
+        self.styled_string().link_at(byte_offset).filter(|link| !link.is_empty()).cloned()
+    }
+}
+
+#[test]
+fn test_parse_markup() {
+    let styled = StyledString::parse(
+        "Hello <b>bold <i>both</i></b> and <a href=\"https://slint.dev\">link</a>",
+    );
+    assert_eq!(styled.text, "Hello bold both and link");
+    let bold = SpanStyle { bold: true, ..Default::default() };
+    let bold_italic = SpanStyle { bold: true, italic: true, ..Default::default() };
+    let link = SpanStyle { link: Some("https://slint.dev".into()), ..Default::default() };
+    assert_eq!(
+        styled.spans,
+        [
+            StyledSpan { byte_range: 6..11, style: bold },
+            StyledSpan { byte_range: 11..15, style: bold_italic },
+            StyledSpan { byte_range: 20..24, style: link },
+        ]
+    );
+    assert_eq!(styled.link_at(21).map(|link| link.as_str()), Some("https://slint.dev"));
+    assert_eq!(styled.link_at(19), None);
+    assert_eq!(styled.style_at(0), None);
+}
+
+#[test]
+fn test_parse_markup_span_attributes() {
+    let styled = StyledString::parse(
+        "<span color='#f00' size=\"20px\">red</span><SPAN color=#00ff0080>green</SPAN>",
+    );
+    assert_eq!(styled.text, "redgreen");
+    assert_eq!(
+        styled.spans,
+        [
+            StyledSpan {
+                byte_range: 0..3,
+                style: SpanStyle {
+                    color: Some(Color::from_rgb_u8(0xff, 0, 0)),
+                    font_size: Some(LogicalLength::new(20 as _)),
+                    ..Default::default()
+                }
+            },
+            StyledSpan {
+                byte_range: 3..8,
+                style: SpanStyle {
+                    color: Some(Color::from_argb_u8(0x80, 0, 0xff, 0)),
+                    ..Default::default()
+                }
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_markup_entities_and_line_breaks() {
+    let styled = StyledString::parse("a&lt;b&gt;c&amp;&#65;&#x42;<br>d<br/>e\nf &unknown; &");
+    assert_eq!(styled.text, "a<b>c&AB\nd\ne\nf &unknown; &");
+    assert!(styled.spans.is_empty());
+
+    let styled = StyledString::parse("<a href=\"?a=1&amp;b=2\">x</a>");
+    assert_eq!(styled.link_at(0).map(|link| link.as_str()), Some("?a=1&b=2"));
+
+    // Multi-byte characters after a `&` that isn't an entity
+    let styled = StyledString::parse("利用規約&プライバシーポリシー&amp;é&#233;");
+    assert_eq!(styled.text, "利用規約&プライバシーポリシー&éé");
+}
+
+#[test]
+fn test_parse_markup_unknown_and_unbalanced_tags() {
+    let styled = StyledString::parse("<u>x</u> 1 < 2 </b><b>y");
+    assert_eq!(styled.text, "<u>x</u> 1 < 2 y");
+    assert_eq!(
+        styled.spans,
+        [StyledSpan { byte_range: 15..16, style: SpanStyle { bold: true, ..Default::default() } }]
+    );
+
+    // Misnested tags close everything that was opened after the matching tag
+    let styled = StyledString::parse("<b>a<i>b</b>c</i>");
+    assert_eq!(styled.text, "abc");
+    assert_eq!(styled.spans.len(), 2);
+    assert_eq!(styled.style_at(2), None);
+}
@@ -9,7 +9,7 @@
 */
 use super::{
     EventResult, FontMetrics, InputType, Item, ItemConsts, ItemRc, ItemRef, KeyEventArg,
-    KeyEventResult, KeyEventType, PointArg, PointerEventButton, RenderingResult,
+    KeyEventResult, KeyEventType, PointArg, PointerEventButton, RenderingResult, StringArg,
     TextHorizontalAlignment, TextOverflow, TextStrokeStyle, TextVerticalAlignment, TextWrap,
     VoidArg, WindowItem,
 };
@@ -390,7 +390,28 @@ fn text_layout_info(
             text_wrap,
         )
     };
+    text_layout_info_for_size(
+        text,
+        font_request.clone(),
+        scale_factor,
+        window_adapter,
+        orientation,
+        width,
+        implicit_size,
+    )
+}
 
+/// Computes the layout info of a text element from a function that measures its text
+/// for a given maximum width and wrap mode.
+pub(super) fn text_layout_info_for_size(
+    text: Pin<&dyn RenderText>,
+    font_request: FontRequest,
+    scale_factor: ScaleFactor,
+    window_adapter: &Rc<dyn WindowAdapter>,
+    orientation: Orientation,
+    width: Pin<&Property<LogicalLength>>,
+    implicit_size: impl Fn(Option<LogicalLength>, TextWrap) -> LogicalSize,
+) -> LayoutInfo {
     // Stretch uses `round_layout` to explicitly align the top left and bottom right of layout nodes
     // to pixel boundaries. To avoid rounding down causing the minimum width to become so little that
     // letters will be cut off, apply the ceiling here.
@@ -406,7 +427,7 @@ fn text_layout_info(
                 ),
                 TextOverflow::Clip => match text.wrap() {
                     TextWrap::NoWrap => implicit_size.width,
-                    TextWrap::WordWrap | TextWrap::CharWrap => 0 as Coord,
+                    TextWrap::WordWrap | TextWrap::CharWrap | TextWrap::Hyphenate => 0 as Coord,
                 },
             };
             LayoutInfo {
@@ -418,8 +439,7 @@ fn text_layout_info(
         Orientation::Vertical => {
             let h = match text.wrap() {
                 TextWrap::NoWrap => implicit_size(None, TextWrap::NoWrap).height,
-                TextWrap::WordWrap => implicit_size(Some(width.get()), TextWrap::WordWrap).height,
-                TextWrap::CharWrap => implicit_size(Some(width.get()), TextWrap::CharWrap).height,
+                wrap => implicit_size(Some(width.get()), wrap).height,
             }
             .ceil();
             LayoutInfo { min: h, preferred: h, ..LayoutInfo::default() }
@@ -455,6 +475,9 @@ fn as_option(self) -> Option<core::ops::Range<i32>> {
 enum UndoItemKind {
     TextInsert,
     TextRemove,
+    /// `replaced_text` at `pos` was replaced with `text`, after an input mask or validator
+    /// changed an edit.
+    Replace,
 }
 
 #[repr(C)]
@@ -462,11 +485,23 @@ enum UndoItemKind {
 struct UndoItem {
     pos: usize,
     text: SharedString,
+    /// Only used by [`UndoItemKind::Replace`]
+    replaced_text: SharedString,
     cursor: usize,
     anchor: usize,
     kind: UndoItemKind,
 }
 
+/// The outcome of [`TextInput::filter_edit`]
+enum EditFilterResult {
+    /// The edit can be applied unchanged
+    Accept,
+    /// The edit must be dropped
+    Reject,
+    /// The input mask or the validator changed the edit, and the result was already applied
+    Replaced,
+}
+
 /// The implementation of the `TextInput` element
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
@@ -484,6 +519,7 @@ pub struct TextInput {
     pub vertical_alignment: Property<TextVerticalAlignment>,
     pub wrap: Property<TextWrap>,
     pub input_type: Property<InputType>,
+    pub input_mask: Property<SharedString>,
     pub letter_spacing: Property<LogicalLength>,
     pub width: Property<LogicalLength>,
     pub height: Property<LogicalLength>,
@@ -499,6 +535,7 @@ pub struct TextInput {
     pub edited: Callback<VoidArg>,
     pub key_pressed: Callback<KeyEventArg, EventResult>,
     pub key_released: Callback<KeyEventArg, EventResult>,
+    pub validator: Callback<StringArg, SharedString>,
     pub single_line: Property<bool>,
     pub read_only: Property<bool>,
     pub preedit_text: Property<SharedString>,
@@ -548,7 +585,7 @@ fn layout_info(
                 let implicit_size = implicit_size(None, TextWrap::NoWrap);
                 let min = match self.wrap() {
                     TextWrap::NoWrap => implicit_size.width,
-                    TextWrap::WordWrap | TextWrap::CharWrap => 0 as Coord,
+                    TextWrap::WordWrap | TextWrap::CharWrap | TextWrap::Hyphenate => 0 as Coord,
                 };
                 LayoutInfo {
                     min: min.ceil(),
@@ -559,12 +596,7 @@ fn layout_info(
             Orientation::Vertical => {
                 let h = match self.wrap() {
                     TextWrap::NoWrap => implicit_size(None, TextWrap::NoWrap).height,
-                    TextWrap::WordWrap => {
-                        implicit_size(Some(self.width()), TextWrap::WordWrap).height
-                    }
-                    TextWrap::CharWrap => {
-                        implicit_size(Some(self.width()), TextWrap::CharWrap).height
-                    }
+                    wrap => implicit_size(Some(self.width()), wrap).height,
                 }
                 .ceil();
                 LayoutInfo { min: h, preferred: h, ..LayoutInfo::default() }
@@ -702,6 +734,20 @@ fn key_event(
                 match event.text_shortcut() {
                     Some(text_shortcut) if !self.read_only() => match text_shortcut {
                         TextShortcut::Move(direction) => {
+                            // The left and right arrow keys move visually, so they go the other
+                            // way in a right-to-left paragraph
+                            let text = self.text();
+                            let direction = if event
+                                .text
+                                .starts_with([key_codes::LeftArrow, key_codes::RightArrow])
+                                && crate::textlayout::is_rtl_paragraph_at(
+                                    &text,
+                                    self.cursor_position(&text),
+                                ) {
+                                direction.mirrored()
+                            } else {
+                                direction
+                            };
                             TextInput::move_cursor(
                                 self,
                                 direction,
@@ -820,7 +866,17 @@ fn key_event(
                     return KeyEventResult::EventIgnored;
                 }
 
-                self.delete_selection(window_adapter, self_rc, TextChangeNotify::SkipCallbacks);
+                let (anchor, cursor) = self.selection_anchor_and_cursor();
+                match self.filter_edit(anchor..cursor, &event.text, window_adapter, self_rc) {
+                    EditFilterResult::Accept => (),
+                    EditFilterResult::Reject => return KeyEventResult::EventIgnored,
+                    EditFilterResult::Replaced => {
+                        self.as_ref().show_cursor(window_adapter);
+                        return KeyEventResult::EventAccepted;
+                    }
+                }
+
+                self.remove_selection(window_adapter, self_rc, TextChangeNotify::SkipCallbacks);
 
                 let mut text: String = self.text().into();
 
@@ -831,6 +887,7 @@ fn key_event(
                 self.add_undo_item(UndoItem {
                     pos: insert_pos,
                     text: event.text.clone(),
+                    replaced_text: Default::default(),
                     cursor: real_cursor,
                     anchor: real_anchor,
                     kind: UndoItemKind::TextInsert,
@@ -866,16 +923,18 @@ fn key_event(
                     return KeyEventResult::EventIgnored;
                 }
 
-                let cursor = self.cursor_position(&self.text()) as i32;
-                self.preedit_text.set(event.preedit_text.clone());
-                self.preedit_selection.set(event.preedit_selection.clone().into());
+                let (cursor, anchor) = {
+                    let text = self.text();
+                    (self.cursor_position(&text) as i32, self.anchor_position(&text) as i32)
+                };
 
+                let mut edit_result = EditFilterResult::Accept;
                 if let Some(r) = &event.replacement_range {
                     // Set the selection so the call to insert erases it
                     self.anchor_position_byte_offset.set(cursor.saturating_add(r.start));
                     self.cursor_position_byte_offset.set(cursor.saturating_add(r.end));
                     if event.text.is_empty() {
-                        self.delete_selection(
+                        edit_result = self.filter_and_delete_selection(
                             window_adapter,
                             self_rc,
                             if event.cursor_position.is_none() {
@@ -887,16 +946,48 @@ fn key_event(
                         );
                     }
                 }
-                self.insert(&event.text, window_adapter, self_rc);
-                if let Some(cursor) = event.cursor_position {
-                    self.anchor_position_byte_offset.set(event.anchor_position.unwrap_or(cursor));
-                    self.set_cursor_position(
-                        cursor,
-                        true,
-                        TextChangeNotify::TriggerCallbacks,
-                        window_adapter,
-                        self_rc,
-                    );
+                if let EditFilterResult::Accept = edit_result {
+                    edit_result = self.insert(&event.text, window_adapter, self_rc);
+                }
+
+                let preedit_accepted = self.accepts_preedit(&event.preedit_text);
+                if preedit_accepted {
+                    self.preedit_text.set(event.preedit_text.clone());
+                    self.preedit_selection.set(event.preedit_selection.clone().into());
+                } else {
+                    self.preedit_text.set(Default::default());
+                    self.preedit_selection.set(Default::default());
+                }
+
+                match edit_result {
+                    EditFilterResult::Accept if preedit_accepted => {
+                        if let Some(cursor) = event.cursor_position {
+                            self.anchor_position_byte_offset
+                                .set(event.anchor_position.unwrap_or(cursor));
+                            self.set_cursor_position(
+                                cursor,
+                                true,
+                                TextChangeNotify::TriggerCallbacks,
+                                window_adapter,
+                                self_rc,
+                            );
+                        }
+                    }
+                    // The input method's cursor positions refer to a text that doesn't exist,
+                    // so tell it about the actual text and cursor instead
+                    EditFilterResult::Reject => {
+                        self.anchor_position_byte_offset.set(anchor);
+                        self.set_cursor_position(
+                            cursor,
+                            true,
+                            TextChangeNotify::TriggerCallbacks,
+                            window_adapter,
+                            self_rc,
+                        );
+                    }
+                    EditFilterResult::Accept | EditFilterResult::Replaced => {
+                        self.update_ime(window_adapter, self_rc)
+                    }
                 }
                 KeyEventResult::EventAccepted
             }
@@ -1024,6 +1115,21 @@ pub enum TextCursorDirection {
     PageDown,
 }
 
+impl TextCursorDirection {
+    /// Returns the direction going the other way on the same line, for right-to-left text
+    fn mirrored(self) -> Self {
+        match self {
+            Self::Forward => Self::Backward,
+            Self::Backward => Self::Forward,
+            Self::ForwardByWord => Self::BackwardByWord,
+            Self::BackwardByWord => Self::ForwardByWord,
+            Self::StartOfLine => Self::EndOfLine,
+            Self::EndOfLine => Self::StartOfLine,
+            other => other,
+        }
+    }
+}
+
 impl core::convert::TryFrom<char> for TextCursorDirection {
     type Error = ();
 
@@ -1376,16 +1482,46 @@ fn select_and_delete(
         window_adapter: &Rc<dyn WindowAdapter>,
         self_rc: &ItemRc,
     ) {
-        if !self.has_selection() {
-            self.move_cursor(
-                step,
-                AnchorMode::KeepAnchor,
-                TextChangeNotify::SkipCallbacks,
+        if self.has_selection() {
+            self.delete_selection(window_adapter, self_rc, TextChangeNotify::TriggerCallbacks);
+            return;
+        }
+
+        let original_cursor = self.cursor_position_byte_offset();
+        self.move_cursor(
+            step,
+            AnchorMode::KeepAnchor,
+            TextChangeNotify::SkipCallbacks,
+            window_adapter,
+            self_rc,
+        );
+        if let EditFilterResult::Reject = self.filter_and_delete_selection(
+            window_adapter,
+            self_rc,
+            TextChangeNotify::TriggerCallbacks,
+        ) {
+            // Don't leave the character selected, or the next deletion would be rejected again.
+            // Literals of the input mask can't be deleted, so step over them instead.
+            let (anchor, cursor) = self.selection_anchor_and_cursor();
+            let mask = parse_input_mask(&self.input_mask());
+            let new_cursor = if !mask.is_empty()
+                && self.text()[anchor..cursor]
+                    .chars()
+                    .all(|c| mask.contains(&MaskToken::Literal(c)))
+            {
+                self.cursor_position_byte_offset()
+            } else {
+                original_cursor
+            };
+            self.anchor_position_byte_offset.set(new_cursor);
+            self.set_cursor_position(
+                new_cursor,
+                true,
+                TextChangeNotify::TriggerCallbacks,
                 window_adapter,
                 self_rc,
             );
         }
-        self.delete_selection(window_adapter, self_rc, TextChangeNotify::TriggerCallbacks);
     }
 
     pub fn delete_selection(
@@ -1393,6 +1529,35 @@ pub fn delete_selection(
         window_adapter: &Rc<dyn WindowAdapter>,
         self_rc: &ItemRc,
         trigger_callbacks: TextChangeNotify,
+    ) {
+        self.filter_and_delete_selection(window_adapter, self_rc, trigger_callbacks);
+    }
+
+    /// Deletes the selected text if the input mask and the validator allow it.
+    fn filter_and_delete_selection(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+        trigger_callbacks: TextChangeNotify,
+    ) -> EditFilterResult {
+        let (anchor, cursor) = self.selection_anchor_and_cursor();
+        if anchor == cursor {
+            return EditFilterResult::Accept;
+        }
+        let result = self.filter_edit(anchor..cursor, "", window_adapter, self_rc);
+        if let EditFilterResult::Accept = result {
+            self.remove_selection(window_adapter, self_rc, trigger_callbacks);
+        }
+        result
+    }
+
+    /// Removes the selected text without going through the input mask and the validator.
+    /// Used when the removal is part of an edit that was already filtered.
+    fn remove_selection(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+        trigger_callbacks: TextChangeNotify,
     ) {
         let text: String = self.text().into();
         if text.is_empty() {
@@ -1418,6 +1583,7 @@ pub fn delete_selection(
         self.add_undo_item(UndoItem {
             pos: anchor,
             text: removed_text,
+            replaced_text: Default::default(),
             cursor: real_cursor,
             anchor: real_anchor,
             kind: UndoItemKind::TextRemove,
@@ -1504,9 +1670,9 @@ fn insert(
         text_to_insert: &str,
         window_adapter: &Rc<dyn WindowAdapter>,
         self_rc: &ItemRc,
-    ) {
+    ) -> EditFilterResult {
         if text_to_insert.is_empty() {
-            return;
+            return EditFilterResult::Accept;
         }
 
         let (real_cursor, real_anchor) = {
@@ -1514,20 +1680,26 @@ fn insert(
             (self.cursor_position(&text), self.anchor_position(&text))
         };
 
-        self.delete_selection(window_adapter, self_rc, TextChangeNotify::SkipCallbacks);
-        let mut text: String = self.text().into();
-        let cursor_pos = self.selection_anchor_and_cursor().1;
         let mut inserted_text: SharedString = text_to_insert.into();
         if text_to_insert.contains('\n') && self.single_line() {
             inserted_text = text_to_insert.replace('\n', " ").into();
-            text.insert_str(cursor_pos, &inserted_text);
-        } else {
-            text.insert_str(cursor_pos, text_to_insert);
         }
 
+        let (anchor, cursor) = self.selection_anchor_and_cursor();
+        match self.filter_edit(anchor..cursor, &inserted_text, window_adapter, self_rc) {
+            EditFilterResult::Accept => (),
+            result @ (EditFilterResult::Reject | EditFilterResult::Replaced) => return result,
+        }
+
+        self.remove_selection(window_adapter, self_rc, TextChangeNotify::SkipCallbacks);
+        let mut text: String = self.text().into();
+        let cursor_pos = self.selection_anchor_and_cursor().1;
+        text.insert_str(cursor_pos, &inserted_text);
+
         self.add_undo_item(UndoItem {
             pos: cursor_pos,
             text: inserted_text,
+            replaced_text: Default::default(),
             cursor: real_cursor,
             anchor: real_anchor,
             kind: UndoItemKind::TextInsert,
@@ -1544,6 +1716,7 @@ fn insert(
             self_rc,
         );
         Self::FIELD_OFFSETS.edited.apply_pin(self).call(&());
+        EditFilterResult::Accept
     }
 
     pub fn cut(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
@@ -1864,6 +2037,20 @@ fn undo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &Item
                 text.insert_str(last.pos, &last.text);
                 self.text.set(text.into());
 
+                self.anchor_position_byte_offset.set(last.anchor as i32);
+                self.set_cursor_position(
+                    last.cursor as i32,
+                    true,
+                    TextChangeNotify::TriggerCallbacks,
+                    window_adapter,
+                    self_rc,
+                );
+            }
+            UndoItemKind::Replace => {
+                let mut text: String = self.text().into();
+                text.replace_range(last.pos..last.pos + last.text.len(), &last.replaced_text);
+                self.text.set(text.into());
+
                 self.anchor_position_byte_offset.set(last.anchor as i32);
                 self.set_cursor_position(
                     last.cursor as i32,
@@ -1908,6 +2095,20 @@ fn redo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &Item
                     .concat();
                 self.text.set(text.into());
 
+                self.anchor_position_byte_offset.set(last.anchor as i32);
+                self.set_cursor_position(
+                    last.cursor as i32,
+                    true,
+                    TextChangeNotify::TriggerCallbacks,
+                    window_adapter,
+                    self_rc,
+                );
+            }
+            UndoItemKind::Replace => {
+                let mut text: String = self.text().into();
+                text.replace_range(last.pos..last.pos + last.replaced_text.len(), &last.text);
+                self.text.set(text.into());
+
                 self.anchor_position_byte_offset.set(last.anchor as i32);
                 self.set_cursor_position(
                     last.cursor as i32,
@@ -1952,6 +2153,193 @@ fn accept_text_input(self: Pin<&Self>, text_to_insert: &str) -> bool {
         }
         true
     }
+
+    /// Runs the edit that replaces `range` of the text with `replacement` through the
+    /// `input-mask` and the `validator`. If they change the edit, the changed text is applied
+    /// here as a single undo step.
+    fn filter_edit(
+        self: Pin<&Self>,
+        range: core::ops::Range<usize>,
+        replacement: &str,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> EditFilterResult {
+        if !self.has_edit_filter() {
+            return EditFilterResult::Accept;
+        }
+
+        let old_text = self.text();
+        let proposed_text =
+            [&old_text[..range.start], replacement, &old_text[range.end..]].concat();
+        let proposed_cursor = range.start + replacement.len();
+
+        let Some((new_text, new_cursor)) =
+            self.apply_edit_filter(proposed_text.clone(), proposed_cursor)
+        else {
+            return EditFilterResult::Reject;
+        };
+
+        if new_text == old_text.as_str() {
+            return EditFilterResult::Reject;
+        }
+        if new_text == proposed_text && new_cursor == proposed_cursor {
+            return EditFilterResult::Accept;
+        }
+
+        let (real_cursor, real_anchor) =
+            (self.cursor_position(&old_text), self.anchor_position(&old_text));
+
+        // Only record the part of the text that changed
+        let prefix_len = old_text
+            .char_indices()
+            .zip(new_text.chars())
+            .find(|((_, old), new)| old != new)
+            .map_or(old_text.len().min(new_text.len()), |((offset, _), _)| offset);
+        let suffix_len = old_text[prefix_len..]
+            .chars()
+            .rev()
+            .zip(new_text[prefix_len..].chars().rev())
+            .take_while(|(old, new)| old == new)
+            .map(|(old, _)| old.len_utf8())
+            .sum::<usize>();
+
+        self.add_undo_item(UndoItem {
+            pos: prefix_len,
+            text: new_text[prefix_len..new_text.len() - suffix_len].into(),
+            replaced_text: old_text[prefix_len..old_text.len() - suffix_len].into(),
+            cursor: real_cursor,
+            anchor: real_anchor,
+            kind: UndoItemKind::Replace,
+        });
+
+        self.text.set(new_text.into());
+        self.anchor_position_byte_offset.set(new_cursor as i32);
+        self.set_cursor_position(
+            new_cursor as i32,
+            true,
+            TextChangeNotify::TriggerCallbacks,
+            window_adapter,
+            self_rc,
+        );
+        Self::FIELD_OFFSETS.edited.apply_pin(self).call(&());
+        EditFilterResult::Replaced
+    }
+
+    fn has_edit_filter(self: Pin<&Self>) -> bool {
+        !self.input_mask().is_empty() || Self::FIELD_OFFSETS.validator.apply_pin(self).has_handler()
+    }
+
+    /// Runs `text`, with the cursor at `cursor`, through the `input-mask` and the `validator`.
+    /// Returns the resulting text and cursor, or None if the text doesn't fit the mask.
+    fn apply_edit_filter(self: Pin<&Self>, text: String, cursor: usize) -> Option<(String, usize)> {
+        let mask = self.input_mask();
+        let (mut new_text, mut new_cursor) =
+            if mask.is_empty() { (text, cursor) } else { apply_input_mask(&mask, &text, cursor)? };
+
+        let validator = Self::FIELD_OFFSETS.validator.apply_pin(self);
+        if validator.has_handler() {
+            let validated = validator.call(&(new_text.as_str().into(),));
+            if validated.as_str() != new_text {
+                // Keep the cursor at the same distance from the end of the text
+                let distance_to_end = new_text.len() - new_cursor;
+                new_cursor = validated.len().saturating_sub(distance_to_end);
+                while !validated.is_char_boundary(new_cursor) {
+                    new_cursor -= 1;
+                }
+                new_text = validated.into();
+            }
+        }
+        Some((new_text, new_cursor))
+    }
+
+    /// Returns whether the input mask and the validator would let `preedit` be committed at
+    /// the cursor. The pre-edit text can't be rewritten, so it's not shown otherwise.
+    fn accepts_preedit(self: Pin<&Self>, preedit: &str) -> bool {
+        if preedit.is_empty() || !self.has_edit_filter() {
+            return true;
+        }
+        let text = self.text();
+        let (anchor, cursor) = self.selection_anchor_and_cursor();
+        let proposed_text = [&text[..anchor], preedit, &text[cursor..]].concat();
+        self.apply_edit_filter(proposed_text, anchor + preedit.len())
+            .is_some_and(|(new_text, _)| new_text != text.as_str())
+    }
+}
+
+/// A position in an `input-mask`
+#[derive(Clone, Copy, PartialEq)]
+enum MaskToken {
+    /// `#`: a digit
+    Digit,
+    /// `A`: a letter
+    Letter,
+    /// `*`: a letter or a digit
+    Alphanumeric,
+    /// `?`: any character
+    Any,
+    /// Any other character, or a character escaped with `\`, which is inserted automatically
+    Literal(char),
+}
+
+impl MaskToken {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            MaskToken::Digit => c.is_ascii_digit(),
+            MaskToken::Letter => c.is_alphabetic(),
+            MaskToken::Alphanumeric => c.is_alphanumeric(),
+            MaskToken::Any => true,
+            MaskToken::Literal(literal) => c == literal,
+        }
+    }
+}
+
+fn parse_input_mask(mask: &str) -> alloc::vec::Vec<MaskToken> {
+    let mut chars = mask.chars();
+    core::iter::from_fn(|| {
+        Some(match chars.next()? {
+            '#' => MaskToken::Digit,
+            'A' => MaskToken::Letter,
+            '*' => MaskToken::Alphanumeric,
+            '?' => MaskToken::Any,
+            '\\' => MaskToken::Literal(chars.next().unwrap_or('\\')),
+            c => MaskToken::Literal(c),
+        })
+    })
+    .collect()
+}
+
+/// Formats `text` according to `mask`, inserting the literal characters of the mask where they
+/// are missing and dropping literal characters typed at the wrong place.
+/// Returns the formatted text and the byte offset of `cursor` in it, or None if `text` doesn't fit
+/// the mask.
+fn apply_input_mask(mask: &str, text: &str, cursor: usize) -> Option<(String, usize)> {
+    let tokens = parse_input_mask(mask);
+    let is_literal = |c: char| tokens.contains(&MaskToken::Literal(c));
+    let mut result = String::with_capacity(mask.len());
+    let mut new_cursor = None;
+    let mut tokens_iter = tokens.iter().peekable();
+    for (offset, c) in text.char_indices() {
+        if offset >= cursor && new_cursor.is_none() {
+            new_cursor = Some(result.len());
+        }
+        loop {
+            match tokens_iter.peek() {
+                Some(token) if token.accepts(c) => {
+                    result.push(c);
+                    tokens_iter.next();
+                    break;
+                }
+                Some(MaskToken::Literal(literal)) => {
+                    result.push(*literal);
+                    tokens_iter.next();
+                }
+                _ if is_literal(c) => break,
+                _ => return None,
+            }
+        }
+    }
+    let new_cursor = new_cursor.unwrap_or(result.len());
+    Some((result, new_cursor))
 }
 
 fn next_paragraph_boundary(text: &str, last_cursor_pos: usize) -> usize {
@@ -2103,3 +2491,15 @@ pub fn slint_text_item_fontmetrics(
     let self_ref = self_rc.borrow();
     slint_text_item_fontmetrics(window_adapter, self_ref, &self_rc)
 }
+
+#[test]
+fn test_apply_input_mask() {
+    let mask = "(###) ###-####";
+    assert_eq!(apply_input_mask(mask, "5", 1), Some(("(5".into(), 2)));
+    assert_eq!(apply_input_mask(mask, "(555)1", 6), Some(("(555) 1".into(), 7)));
+    assert_eq!(apply_input_mask(mask, "(5545) 123", 4), Some(("(554) 512-3".into(), 4)));
+    assert_eq!(apply_input_mask(mask, "(55a", 4), None);
+    assert_eq!(apply_input_mask(mask, "(555) 123-45678", 15), None);
+    assert_eq!(apply_input_mask("##\\#A", "12#b", 4), Some(("12#b".into(), 4)));
+    assert_eq!(apply_input_mask("##\\#A", "121", 3), None);
+}
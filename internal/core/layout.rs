@@ -5,7 +5,7 @@
 
 // cspell:ignore coord
 
-use crate::items::{DialogButtonRole, LayoutAlignment};
+use crate::items::{DialogButtonRole, LayoutAlignment, LayoutDirection};
 use crate::{slice::Slice, Coord, SharedVector};
 use alloc::vec::Vec;
 
@@ -497,6 +497,8 @@ pub struct BoxLayoutData<'a> {
     pub spacing: Coord,
     pub padding: Padding,
     pub alignment: LayoutAlignment,
+    /// When `RightToLeft`, the first cell is placed at the end (only used for horizontal layouts)
+    pub direction: LayoutDirection,
     pub cells: Slice<'a, BoxLayoutCellData>,
 }
 
@@ -578,6 +580,11 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
             pos += spacing + it.size;
         }
     }
+    if data.direction == LayoutDirection::RightToLeft {
+        for it in &mut layout_data {
+            it.pos = data.size - it.pos - it.size;
+        }
+    }
 
     let res = result.make_mut_slice();
 
@@ -320,6 +320,27 @@ pub enum WindowEvent {
     },
     /// The pointer exited the window.
     PointerExited,
+    /// A finger or stylus touched a touch screen.
+    ///
+    /// Unlike the pointer events, touch events can be sent for several touch points at once,
+    /// which allows recognizing gestures such as pinching with two fingers.
+    TouchPressed {
+        /// An identifier for the touch point, which stays the same until the touch point is released.
+        touch_id: u64,
+        position: LogicalPosition,
+    },
+    /// A touch point moved.
+    TouchMoved {
+        /// The identifier of the touch point, as passed in [`WindowEvent::TouchPressed`].
+        touch_id: u64,
+        position: LogicalPosition,
+    },
+    /// A touch point was lifted from the touch screen, or the touch was cancelled.
+    TouchReleased {
+        /// The identifier of the touch point, as passed in [`WindowEvent::TouchPressed`].
+        touch_id: u64,
+        position: LogicalPosition,
+    },
     /// A key was pressed.
     KeyPressed {
         /// The unicode representation of the key pressed.
@@ -393,6 +414,9 @@ pub fn position(&self) -> Option<LogicalPosition> {
             WindowEvent::PointerReleased { position, .. } => Some(*position),
             WindowEvent::PointerMoved { position } => Some(*position),
             WindowEvent::PointerScrolled { position, .. } => Some(*position),
+            WindowEvent::TouchPressed { position, .. } => Some(*position),
+            WindowEvent::TouchMoved { position, .. } => Some(*position),
+            WindowEvent::TouchReleased { position, .. } => Some(*position),
             _ => None,
         }
     }
@@ -165,16 +165,29 @@ pub extern "C" fn slint_property_set_constant(handle: &PropertyHandleOpaque) {
     core::ptr::drop_in_place(handle);
 }
 
+/// Safety: `offsets` and `values` must point to `count` elements, or `count` must be zero.
+unsafe fn c_keyframes<T: Clone>(
+    offsets: *const f32,
+    values: *const T,
+    count: usize,
+) -> alloc::vec::Vec<Keyframe<T>> {
+    (0..count)
+        .map(|i| Keyframe { offset: *offsets.add(i), value: (*values.add(i)).clone() })
+        .collect()
+}
+
 fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     handle: &PropertyHandleOpaque,
     from: T,
     to: T,
     animation_data: &PropertyAnimation,
+    keyframes: alloc::vec::Vec<Keyframe<T>>,
 ) {
     let d = RefCell::new(properties_animations::PropertyValueAnimationData::new(
         from,
         to,
         animation_data.clone(),
+        keyframes,
     ));
     // Safety: The BindingCallable is for type T
     unsafe {
@@ -200,8 +213,17 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     from: i32,
     to: i32,
     animation_data: &PropertyAnimation,
+    keyframe_offsets: *const f32,
+    keyframe_values: *const i32,
+    keyframe_count: usize,
 ) {
-    c_set_animated_value(handle, from, to, animation_data)
+    c_set_animated_value(
+        handle,
+        from,
+        to,
+        animation_data,
+        c_keyframes(keyframe_offsets, keyframe_values, keyframe_count),
+    )
 }
 
 /// Internal function to set up a property animation to the specified target value for a float property.
@@ -211,8 +233,17 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     from: f32,
     to: f32,
     animation_data: &PropertyAnimation,
+    keyframe_offsets: *const f32,
+    keyframe_values: *const f32,
+    keyframe_count: usize,
 ) {
-    c_set_animated_value(handle, from, to, animation_data)
+    c_set_animated_value(
+        handle,
+        from,
+        to,
+        animation_data,
+        c_keyframes(keyframe_offsets, keyframe_values, keyframe_count),
+    )
 }
 
 /// Internal function to set up a property animation to the specified target value for a color property.
@@ -222,8 +253,17 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     from: Color,
     to: Color,
     animation_data: &PropertyAnimation,
+    keyframe_offsets: *const f32,
+    keyframe_values: *const Color,
+    keyframe_count: usize,
 ) {
-    c_set_animated_value(handle, from, to, animation_data);
+    c_set_animated_value(
+        handle,
+        from,
+        to,
+        animation_data,
+        c_keyframes(keyframe_offsets, keyframe_values, keyframe_count),
+    );
 }
 
 /// Internal function to set up a property animation to the specified target value for a brush property.
@@ -233,8 +273,17 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     from: &Brush,
     to: &Brush,
     animation_data: &PropertyAnimation,
+    keyframe_offsets: *const f32,
+    keyframe_values: *const Brush,
+    keyframe_count: usize,
 ) {
-    c_set_animated_value(handle, from.clone(), to.clone(), animation_data);
+    c_set_animated_value(
+        handle,
+        from.clone(),
+        to.clone(),
+        animation_data,
+        c_keyframes(keyframe_offsets, keyframe_values, keyframe_count),
+    );
 }
 
 unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
@@ -243,6 +292,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframes: alloc::vec::Vec<Keyframe<T>>,
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
@@ -267,6 +317,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         T::default(),
         T::default(),
         animation_data.cloned().unwrap_or_default(),
+        keyframes,
     ));
     if let Some(transition_data) = transition_data {
         handle.0.set_binding(properties_animations::AnimatedBindingCallable::<T, _> {
@@ -298,6 +349,9 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframe_offsets: *const f32,
+    keyframe_values: *const core::ffi::c_int,
+    keyframe_count: usize,
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
@@ -308,6 +362,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         user_data,
         drop_user_data,
         animation_data,
+        c_keyframes(keyframe_offsets, keyframe_values, keyframe_count),
         transition_data,
     );
 }
@@ -320,6 +375,9 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframe_offsets: *const f32,
+    keyframe_values: *const f32,
+    keyframe_count: usize,
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
@@ -330,6 +388,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         user_data,
         drop_user_data,
         animation_data,
+        c_keyframes(keyframe_offsets, keyframe_values, keyframe_count),
         transition_data,
     );
 }
@@ -342,6 +401,9 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframe_offsets: *const f32,
+    keyframe_values: *const Color,
+    keyframe_count: usize,
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
@@ -352,6 +414,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         user_data,
         drop_user_data,
         animation_data,
+        c_keyframes(keyframe_offsets, keyframe_values, keyframe_count),
         transition_data,
     );
 }
@@ -364,6 +427,9 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframe_offsets: *const f32,
+    keyframe_values: *const Brush,
+    keyframe_count: usize,
     transition_data: Option<
         extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
     >,
@@ -374,6 +440,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         user_data,
         drop_user_data,
         animation_data,
+        c_keyframes(keyframe_offsets, keyframe_values, keyframe_count),
         transition_data,
     );
 }
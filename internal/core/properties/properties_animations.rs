@@ -6,28 +6,65 @@
     items::{AnimationDirection, PropertyAnimation},
     lengths::LogicalLength,
 };
+use alloc::vec::Vec;
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
 
+/// Below this displacement and velocity (relative to the animated distance), a spring animation is considered at rest.
+const SPRING_REST_THRESHOLD: f32 = 1e-3;
+
 enum AnimationState {
     Delaying,
     Animating { current_iteration: u64 },
     Done { iteration_count: u64 },
 }
 
+/// A keyframe of a property animation: the value the property has at `offset`,
+/// between 0 and 1, of the animation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Keyframe<T> {
+    /// The position of the keyframe in the animation, between 0 and 1
+    pub offset: f32,
+    /// The value of the property at this keyframe
+    pub value: T,
+}
+
 pub(super) struct PropertyValueAnimationData<T> {
     from_value: T,
     to_value: T,
     details: PropertyAnimation,
+    keyframes: Vec<Keyframe<T>>,
+    /// The velocity a spring animation starts with, relative to the distance between `from_value` and `to_value`
+    initial_velocity: f32,
+    /// When a spring animation was interrupted, the distance it was animating, that `initial_velocity` is relative to
+    interrupted_distance: Option<f32>,
     start_time: crate::animations::Instant,
     state: AnimationState,
 }
 
 impl<T: InterpolatedPropertyValue + Clone> PropertyValueAnimationData<T> {
-    pub fn new(from_value: T, to_value: T, details: PropertyAnimation) -> Self {
+    pub fn new(
+        from_value: T,
+        to_value: T,
+        details: PropertyAnimation,
+        keyframes: Vec<Keyframe<T>>,
+    ) -> Self {
         let start_time = crate::animations::current_tick();
 
-        Self { from_value, to_value, details, start_time, state: AnimationState::Delaying }
+        Self {
+            from_value,
+            to_value,
+            details,
+            keyframes,
+            initial_velocity: 0.,
+            interrupted_distance: None,
+            start_time,
+            state: AnimationState::Delaying,
+        }
+    }
+
+    fn is_spring(&self) -> bool {
+        self.details.stiffness > 0.
     }
 
     pub fn compute_interpolated_value(&mut self) -> (T, bool) {
@@ -35,6 +72,7 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
         let mut time_progress = new_tick.duration_since(self.start_time).as_millis() as u64;
         let reversed = |iteration: u64| -> bool {
             match self.details.direction {
+                _ if self.details.stiffness > 0. => false,
                 AnimationDirection::Normal => false,
                 AnimationDirection::Reverse => true,
                 AnimationDirection::Alternate => iteration % 2 == 1,
@@ -44,6 +82,16 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
 
         match self.state {
             AnimationState::Delaying => {
+                if let Some(distance) = self.interrupted_distance.take() {
+                    // Keep the absolute velocity of the interrupted spring animation
+                    self.initial_velocity = match self.from_value.distance(&self.to_value) {
+                        Some(new_distance) if new_distance != 0. => {
+                            self.initial_velocity * distance / new_distance
+                        }
+                        _ => 0.,
+                    };
+                }
+
                 if self.details.delay <= 0 {
                     self.state = AnimationState::Animating { current_iteration: 0 };
                     return self.compute_interpolated_value();
@@ -66,6 +114,16 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                     self.compute_interpolated_value()
                 }
             }
+            AnimationState::Animating { .. } if self.is_spring() => {
+                let (displacement, velocity) = self.spring_state(time_progress);
+                if displacement.abs() < SPRING_REST_THRESHOLD
+                    && velocity.abs() < SPRING_REST_THRESHOLD
+                {
+                    self.state = AnimationState::Done { iteration_count: 0 };
+                    return self.compute_interpolated_value();
+                }
+                (self.from_value.interpolate(&self.to_value, 1. + displacement), false)
+            }
             AnimationState::Animating { mut current_iteration } => {
                 if self.details.duration <= 0 || self.details.iteration_count == 0. {
                     self.state = AnimationState::Done { iteration_count: 0 };
@@ -95,10 +153,8 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                             progress
                         }
                     };
-                    let t = crate::animations::easing_curve(&self.details.easing, progress);
-                    let val = self.from_value.interpolate(&self.to_value, t);
 
-                    (val, false)
+                    (self.value_at(progress), false)
                 } else {
                     self.state =
                         AnimationState::Done { iteration_count: current_iteration.max(1) - 1 };
@@ -115,7 +171,91 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
         }
     }
 
+    /// Returns the value at the given progress of the animation. When there are keyframes,
+    /// the easing curve applies to each segment between two keyframes.
+    fn value_at(&self, progress: f32) -> T {
+        if self.keyframes.is_empty() {
+            let t = crate::animations::easing_curve(&self.details.easing, progress);
+            return self.from_value.interpolate(&self.to_value, t);
+        }
+
+        // The start and end values are used when there is no keyframe at 0% or 100%
+        let stops = core::iter::once((0., &self.from_value))
+            .chain(self.keyframes.iter().map(|k| (k.offset, &k.value)))
+            .chain(core::iter::once((1., &self.to_value)))
+            .collect::<Vec<_>>();
+        let segment = stops
+            .windows(2)
+            .find(|w| progress <= w[1].0 && w[0].0 < w[1].0)
+            .unwrap_or(&stops[stops.len() - 2..]);
+        let (start, end) = (segment[0], segment[1]);
+        let local_progress = if end.0 > start.0 {
+            ((progress - start.0) / (end.0 - start.0)).clamp(0., 1.)
+        } else {
+            1.
+        };
+        let t = crate::animations::easing_curve(&self.details.easing, local_progress);
+        start.1.interpolate(end.1, t)
+    }
+
+    /// Returns the displacement from the target value, starting at -1, and the velocity of a
+    /// damped spring after `elapsed_ms` milliseconds. Both are relative to the distance between
+    /// `from_value` and `to_value`, and the velocity is in units per second.
+    fn spring_state(&self, elapsed_ms: u64) -> (f32, f32) {
+        let stiffness = self.details.stiffness;
+        let mass = if self.details.mass > 0. { self.details.mass } else { 1. };
+        let damping = self.details.damping.max(0.);
+        let t = elapsed_ms as f32 / 1000.;
+
+        let omega = (stiffness / mass).sqrt();
+        let zeta = damping / (2. * (stiffness * mass).sqrt());
+        let x0 = -1.;
+        let v0 = self.initial_velocity;
+
+        if (zeta - 1.).abs() < 1e-4 {
+            // Critically damped
+            let b = v0 + omega * x0;
+            let e = (-omega * t).exp();
+            let x = (x0 + b * t) * e;
+            let v = (b - omega * (x0 + b * t)) * e;
+            (x, v)
+        } else if zeta < 1. {
+            // Under-damped: oscillates around the target value
+            let omega_d = omega * (1. - zeta * zeta).sqrt();
+            let a = x0;
+            let b = (v0 + zeta * omega * x0) / omega_d;
+            let e = (-zeta * omega * t).exp();
+            let (sin, cos) = (omega_d * t).sin_cos();
+            let x = e * (a * cos + b * sin);
+            let v = e
+                * ((b * omega_d - zeta * omega * a) * cos - (a * omega_d + zeta * omega * b) * sin);
+            (x, v)
+        } else {
+            // Over-damped
+            let root = omega * (zeta * zeta - 1.).sqrt();
+            let r1 = -zeta * omega + root;
+            let r2 = -zeta * omega - root;
+            let c2 = (v0 - r1 * x0) / (r2 - r1);
+            let c1 = x0 - c2;
+            let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+            (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+        }
+    }
+
     fn reset(&mut self) {
+        if matches!(self.state, AnimationState::Animating { .. }) && self.is_spring() {
+            // The target changes while the spring is moving: remember the velocity so that the
+            // next animation continues from it.
+            let elapsed = crate::animations::current_tick().duration_since(self.start_time);
+            self.initial_velocity = self.spring_state(elapsed.as_millis() as u64).1;
+            self.interrupted_distance = self.from_value.distance(&self.to_value);
+            if self.interrupted_distance.is_none() {
+                self.initial_velocity = 0.;
+            }
+        } else {
+            self.initial_velocity = 0.;
+            self.interrupted_distance = None;
+        }
         self.state = AnimationState::Delaying;
         self.start_time = crate::animations::current_tick();
     }
@@ -204,24 +344,43 @@ pub trait InterpolatedPropertyValue: PartialEq + Default + 'static {
     /// easing curves it may over- or undershoot though.
     #[must_use]
     fn interpolate(&self, target_value: &Self, t: f32) -> Self;
+
+    /// Returns the signed distance from self to target_value, for values that can be
+    /// measured along a single axis. Spring animations use it to keep their velocity
+    /// when the target value changes while they are running.
+    fn distance(&self, _target_value: &Self) -> Option<f32> {
+        None
+    }
 }
 
 impl InterpolatedPropertyValue for f32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + t * (target_value - self)
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        Some(target_value - self)
+    }
 }
 
 impl InterpolatedPropertyValue for i32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32).round() as i32
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        Some((target_value - self) as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for i64 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32).round() as Self
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        Some((target_value - self) as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for u8 {
@@ -235,6 +394,10 @@ impl InterpolatedPropertyValue for LogicalLength {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         LogicalLength::new(self.get().interpolate(&target_value.get(), t))
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        Some(target_value.get() - self.get())
+    }
 }
 
 impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
@@ -245,11 +408,24 @@ impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
     /// If other properties have binding depending of this property, these properties will
     /// be marked as dirty.
     pub fn set_animated_value(&self, value: T, animation_data: PropertyAnimation) {
+        self.set_animated_value_with_keyframes(value, animation_data, [])
+    }
+
+    /// Same as [`Self::set_animated_value`], but the animation goes through the given keyframes.
+    /// The offset of each keyframe is between 0 and 1, and they must be sorted by offset.
+    /// The current value and the new value are used at 0 and 1 when there is no keyframe there.
+    pub fn set_animated_value_with_keyframes(
+        &self,
+        value: T,
+        animation_data: PropertyAnimation,
+        keyframes: impl IntoIterator<Item = Keyframe<T>>,
+    ) {
         // FIXME if the current value is a dirty binding, we must run it, but we do not have the context
         let d = RefCell::new(properties_animations::PropertyValueAnimationData::new(
             self.get_internal(),
             value,
             animation_data,
+            keyframes.into_iter().collect(),
         ));
         // Safety: the BindingCallable will cast its argument to T
         unsafe {
@@ -281,6 +457,17 @@ pub fn set_animated_binding(
         &self,
         binding: impl Binding<T> + 'static,
         animation_data: PropertyAnimation,
+    ) {
+        self.set_animated_binding_with_keyframes(binding, animation_data, [])
+    }
+
+    /// Set a binding to this property, animating through the given keyframes each time the value changes.
+    /// The offset of each keyframe is between 0 and 1, and they must be sorted by offset.
+    pub fn set_animated_binding_with_keyframes(
+        &self,
+        binding: impl Binding<T> + 'static,
+        animation_data: PropertyAnimation,
+        keyframes: impl IntoIterator<Item = Keyframe<T>>,
     ) {
         let binding_callable = properties_animations::AnimatedBindingCallable::<T, _> {
             original_binding: PropertyHandle {
@@ -298,6 +485,7 @@ pub fn set_animated_binding(
                 T::default(),
                 T::default(),
                 animation_data,
+                keyframes.into_iter().collect(),
             )),
             compute_animation_details: || -> properties_animations::AnimationDetail { None },
         };
@@ -340,6 +528,7 @@ pub fn set_animated_binding_for_transition(
                 T::default(),
                 T::default(),
                 PropertyAnimation::default(),
+                Vec::new(),
             )),
             compute_animation_details: move || Some(compute_animation_details()),
         };
@@ -1036,4 +1225,163 @@ fn test_loop_via_binding() {
 
         assert_eq!(get_prop_value(&compo.width), 300);
     }
+
+    #[test]
+    fn properties_test_keyframes_triggered_by_set() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(0);
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value_with_keyframes(
+            0,
+            animation_details,
+            [Keyframe { offset: 0.5, value: 100 }],
+        );
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 50);
+        assert_eq!(get_prop_value(&compo.width_times_two), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 2));
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 3 / 4));
+        assert_eq!(get_prop_value(&compo.width), 50);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 0);
+    }
+
+    #[test]
+    fn properties_test_keyframes_triggered_by_binding() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            ..PropertyAnimation::default()
+        };
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding_with_keyframes(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+            [Keyframe { offset: 0., value: 10 }, Keyframe { offset: 0.5, value: 0 }],
+        );
+
+        compo.feed_property.set(100);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        let start_time = crate::animations::current_tick();
+        compo.feed_property.set(200);
+        // The keyframe at 0% replaces the start value
+        assert_eq!(get_prop_value(&compo.width), 10);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 5);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 3 / 4));
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 200);
+    }
+
+    #[test]
+    fn properties_test_spring_animation() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            stiffness: 100.,
+            damping: 10.,
+            mass: 1.,
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(0);
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value(1000, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + std::time::Duration::from_millis(100))
+        });
+        let value = get_prop_value(&compo.width);
+        assert!(value > 0 && value < 1000, "{value}");
+
+        // An under-damped spring overshoots its target
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + std::time::Duration::from_millis(363))
+        });
+        let value = get_prop_value(&compo.width);
+        assert!(value > 1100, "{value}");
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + std::time::Duration::from_secs(5))
+        });
+        assert_eq!(get_prop_value(&compo.width), 1000);
+        assert!(!crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.has_active_animations()));
+    }
+
+    #[test]
+    fn properties_test_spring_animation_keeps_velocity() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            stiffness: 100.,
+            damping: 20.,
+            mass: 1.,
+            ..PropertyAnimation::default()
+        };
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+        );
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        let start_time = crate::animations::current_tick();
+        compo.feed_property.set(1000);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        let retarget_time = start_time + std::time::Duration::from_millis(100);
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(retarget_time));
+        let value = get_prop_value(&compo.width);
+        assert!(value > 0 && value < 1000, "{value}");
+
+        // Change the target in the other direction: the value keeps moving up for a while
+        compo.feed_property.set(-1000);
+        assert_eq!(get_prop_value(&compo.width), value);
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(retarget_time + std::time::Duration::from_millis(16))
+        });
+        let new_value = get_prop_value(&compo.width);
+        assert!(new_value > value, "{new_value} > {value}");
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(retarget_time + std::time::Duration::from_secs(5))
+        });
+        assert_eq!(get_prop_value(&compo.width), -1000);
+    }
 }
@@ -37,6 +37,32 @@ fn text_size(
         text_wrap: TextWrap,
     ) -> LogicalSize;
 
+    /// Returns the size of the given styled text in logical pixels, like [`Self::text_size`].
+    /// Renderers that can't lay out styled text measure the plain text instead.
+    fn styled_text_size(
+        &self,
+        font_request: crate::graphics::FontRequest,
+        styled_text: &crate::items::StyledString,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        text_wrap: TextWrap,
+    ) -> LogicalSize {
+        self.text_size(font_request, &styled_text.text, max_width, scale_factor, text_wrap)
+    }
+
+    /// Returns the (UTF-8) byte offset in the plain text of a `StyledText` element of the character
+    /// whose glyph is at the given position, or None if there is no glyph at that position.
+    /// This is used to find the link under the mouse cursor.
+    fn styled_text_byte_offset_for_position(
+        &self,
+        _styled_text: Pin<&crate::items::StyledText>,
+        _pos: LogicalPoint,
+        _font_request: crate::graphics::FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        None
+    }
+
     /// Returns the metrics of the given font.
     fn font_metrics(
         &self,
@@ -10,6 +10,7 @@
 
 pub type FieldOffset<T, U> = const_field_offset::FieldOffset<T, U, const_field_offset::AllowPin>;
 use crate::items::PropertyAnimation;
+use crate::properties::Keyframe;
 use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::vec::Vec;
@@ -45,6 +46,7 @@ macro_rules! declare_ValueType_2 {
             crate::graphics::Point,
             crate::items::PointerEvent,
             crate::items::PointerScrollEvent,
+            crate::items::DropEvent,
             crate::lengths::LogicalLength,
             crate::component_factory::ComponentFactory,
             crate::api::LogicalPosition,
@@ -58,19 +60,22 @@ macro_rules! declare_ValueType_2 {
 
 i_slint_common::for_each_enums!(declare_ValueType_2);
 
+/// An animation and its keyframes (which may be empty)
+pub type AnimationWithKeyframes<Value> = (PropertyAnimation, Vec<Keyframe<Value>>);
+
 /// What kind of animation is on a binding
-pub enum AnimatedBindingKind {
+pub enum AnimatedBindingKind<Value> {
     /// No animation is on the binding
     NotAnimated,
     /// Single animation
-    Animation(PropertyAnimation),
+    Animation(AnimationWithKeyframes<Value>),
     /// Transition
     Transition(Box<dyn Fn() -> (PropertyAnimation, crate::animations::Instant)>),
 }
 
-impl AnimatedBindingKind {
-    /// return a PropertyAnimation if self contains AnimatedBindingKind::Animation
-    pub fn as_animation(self) -> Option<PropertyAnimation> {
+impl<Value> AnimatedBindingKind<Value> {
+    /// return the animation if self contains AnimatedBindingKind::Animation
+    pub fn as_animation(self) -> Option<AnimationWithKeyframes<Value>> {
         match self {
             AnimatedBindingKind::NotAnimated => None,
             AnimatedBindingKind::Animation(a) => Some(a),
@@ -85,13 +90,13 @@ fn set(
         &self,
         item: Pin<&Item>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: Option<AnimationWithKeyframes<Value>>,
     ) -> Result<(), ()>;
     fn set_binding(
         &self,
         item: Pin<&Item>,
         binding: Box<dyn Fn() -> Value>,
-        animation: AnimatedBindingKind,
+        animation: AnimatedBindingKind<Value>,
     ) -> Result<(), ()>;
 
     /// The offset of the property in the item.
@@ -128,7 +133,7 @@ fn set(
         &self,
         item: Pin<&Item>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: Option<AnimationWithKeyframes<Value>>,
     ) -> Result<(), ()> {
         if animation.is_some() {
             Err(())
@@ -141,7 +146,7 @@ fn set_binding(
         &self,
         item: Pin<&Item>,
         binding: Box<dyn Fn() -> Value>,
-        animation: AnimatedBindingKind,
+        animation: AnimatedBindingKind<Value>,
     ) -> Result<(), ()> {
         if !matches!(animation, AnimatedBindingKind::NotAnimated) {
             Err(())
@@ -165,6 +170,15 @@ unsafe fn link_two_ways(&self, item: Pin<&Item>, property2: *const ()) {
     }
 }
 
+fn convert_keyframes<Value: TryInto<T>, T>(
+    keyframes: Vec<Keyframe<Value>>,
+) -> Result<Vec<Keyframe<T>>, ()> {
+    keyframes
+        .into_iter()
+        .map(|k| Ok(Keyframe { offset: k.offset, value: k.value.try_into().map_err(|_| ())? }))
+        .collect()
+}
+
 /// Wrapper for a field offset that optionally implement PropertyInfo and uses
 /// the auto deref specialization trick
 #[derive(derive_more::Deref)]
@@ -184,10 +198,14 @@ fn set(
         &self,
         item: Pin<&Item>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: Option<AnimationWithKeyframes<Value>>,
     ) -> Result<(), ()> {
-        if let Some(animation) = animation {
-            self.apply_pin(item).set_animated_value(value.try_into().map_err(|_| ())?, animation);
+        if let Some((animation, keyframes)) = animation {
+            self.apply_pin(item).set_animated_value_with_keyframes(
+                value.try_into().map_err(|_| ())?,
+                animation,
+                convert_keyframes(keyframes)?,
+            );
             Ok(())
         } else {
             self.0.set(item, value, None)
@@ -197,13 +215,13 @@ fn set_binding(
         &self,
         item: Pin<&Item>,
         binding: Box<dyn Fn() -> Value>,
-        animation: AnimatedBindingKind,
+        animation: AnimatedBindingKind<Value>,
     ) -> Result<(), ()> {
         // Put in a function that does not depends on Item to avoid code bloat
         fn set_binding_impl<T, Value>(
             p: Pin<&crate::Property<T>>,
             binding: Box<dyn Fn() -> Value>,
-            animation: AnimatedBindingKind,
+            animation: AnimatedBindingKind<Value>,
         ) -> Result<(), ()>
         where
             T: Clone + TryInto<Value> + crate::properties::InterpolatedPropertyValue + 'static,
@@ -216,8 +234,8 @@ fn set_binding_impl<T, Value>(
                     });
                     Ok(())
                 }
-                AnimatedBindingKind::Animation(animation) => {
-                    p.set_animated_binding(
+                AnimatedBindingKind::Animation((animation, keyframes)) => {
+                    p.set_animated_binding_with_keyframes(
                         move || {
                             binding()
                                 .try_into()
@@ -225,6 +243,7 @@ fn set_binding_impl<T, Value>(
                                 .expect("binding was of the wrong type")
                         },
                         animation,
+                        convert_keyframes(keyframes)?,
                     );
                     Ok(())
                 }
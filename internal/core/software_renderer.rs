@@ -7,11 +7,16 @@
 
 #![warn(missing_docs)]
 
+mod box_shadow;
 mod draw_functions;
 mod fixed;
 mod fonts;
 mod minimal_software_window;
+#[cfg(feature = "std")]
+mod path;
+mod rounded_clip;
 mod scene;
+mod transform;
 
 use self::fonts::GlyphRenderer;
 pub use self::minimal_software_window::MinimalSoftwareWindow;
@@ -25,8 +30,8 @@
 };
 use crate::items::{ItemRc, TextOverflow, TextWrap};
 use crate::lengths::{
-    LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
-    PhysicalPx, PointLengths, RectLengths, ScaleFactor, SizeLengths,
+    ItemTransform, LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize,
+    LogicalVector, PhysicalPx, PointLengths, RectLengths, ScaleFactor, SizeLengths,
 };
 use crate::renderer::RendererSealed;
 use crate::textlayout::{AbstractFont, FontMetrics, TextParagraphLayout};
@@ -423,6 +428,7 @@ pub struct SoftwareRenderer {
     maybe_window_adapter: RefCell<Option<Weak<dyn crate::window::WindowAdapter>>>,
     rotation: Cell<RenderingRotation>,
     rendering_metrics_collector: Option<Rc<RenderingMetricsCollector>>,
+    box_shadow_cache: box_shadow::BoxShadowCache,
 }
 
 impl Default for SoftwareRenderer {
@@ -434,6 +440,7 @@ fn default() -> Self {
             rotation: Default::default(),
             rendering_metrics_collector: RenderingMetricsCollector::new("software"),
             repaint_buffer_type: Default::default(),
+            box_shadow_cache: Default::default(),
         }
     }
 }
@@ -560,6 +567,7 @@ fn render_buffer_impl(
             window_inner,
             RenderToBuffer { buffer, dirty_range_cache: vec![], dirty_region: Default::default() },
             rotation,
+            &self.box_shadow_cache,
         );
         let mut renderer = self.partial_rendering_state.create_partial_renderer(buffer_renderer);
         let window_adapter = renderer.window_adapter.clone();
@@ -725,6 +733,49 @@ fn font_metrics(
         fonts::font_metrics(font_request, scale_factor)
     }
 
+    fn styled_text_size(
+        &self,
+        font_request: crate::graphics::FontRequest,
+        styled_text: &crate::items::StyledString,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        text_wrap: TextWrap,
+    ) -> LogicalSize {
+        fonts::styled_text_size(font_request, styled_text, max_width, scale_factor, text_wrap)
+    }
+
+    fn styled_text_byte_offset_for_position(
+        &self,
+        styled_text: Pin<&crate::items::StyledText>,
+        pos: LogicalPoint,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        let styled_string = styled_text.styled_string();
+
+        let font = fonts::match_font(&font_request, scale_factor);
+        let styled_fonts = fonts::StyledFonts::new(&font_request, &styled_string, scale_factor);
+        let spans = styled_fonts.text_spans(scale_factor);
+
+        let paragraph = TextParagraphLayout {
+            string: &styled_string.text,
+            layout: fonts::text_layout_for_font(&font, &font_request, scale_factor),
+            spans: &spans,
+            max_width: (styled_text.width().cast() * scale_factor).cast(),
+            max_height: (styled_text.height().cast() * scale_factor).cast(),
+            horizontal_alignment: styled_text.horizontal_alignment(),
+            vertical_alignment: styled_text.vertical_alignment(),
+            wrap: styled_text.wrap(),
+            overflow: styled_text.overflow(),
+            single_line: false,
+        };
+
+        let pos = (pos.cast() * scale_factor)
+            .clamp(euclid::point2(0., 0.), euclid::point2(i16::MAX, i16::MAX).cast())
+            .cast();
+        paragraph.glyph_byte_offset_at_position((pos.x_length(), pos.y_length()))
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&crate::items::TextInput>,
@@ -750,6 +801,7 @@ fn text_input_byte_offset_for_position(
                 let paragraph = TextParagraphLayout {
                     string: &visual_representation.text,
                     layout,
+                    spans: &[],
                     max_width: width,
                     max_height: height,
                     horizontal_alignment: text_input.horizontal_alignment(),
@@ -770,6 +822,7 @@ fn text_input_byte_offset_for_position(
                 let paragraph = TextParagraphLayout {
                     string: &visual_representation.text,
                     layout,
+                    spans: &[],
                     max_width: width,
                     max_height: height,
                     horizontal_alignment: text_input.horizontal_alignment(),
@@ -807,6 +860,7 @@ fn text_input_cursor_rect_for_byte_offset(
                 let paragraph = TextParagraphLayout {
                     string: &visual_representation.text,
                     layout,
+                    spans: &[],
                     max_width: width,
                     max_height: height,
                     horizontal_alignment: text_input.horizontal_alignment(),
@@ -825,6 +879,7 @@ fn text_input_cursor_rect_for_byte_offset(
                 let paragraph = TextParagraphLayout {
                     string: &visual_representation.text,
                     layout,
+                    spans: &[],
                     max_width: width,
                     max_height: height,
                     horizontal_alignment: text_input.horizontal_alignment(),
@@ -852,10 +907,11 @@ fn text_input_cursor_rect_for_byte_offset(
 
     fn free_graphics_resources(
         &self,
-        _component: crate::item_tree::ItemTreeRef,
+        component: crate::item_tree::ItemTreeRef,
         items: &mut dyn Iterator<Item = Pin<crate::items::ItemRef<'_>>>,
     ) -> Result<(), crate::platform::PlatformError> {
         self.partial_rendering_state.free_graphics_resources(items);
+        self.box_shadow_cache.component_destroyed(component);
         Ok(())
     }
 
@@ -1049,6 +1105,7 @@ fn prepare_scene(
         window,
         PrepareScene::default(),
         software_renderer.rotation.get(),
+        &software_renderer.box_shadow_cache,
     );
     let mut renderer =
         software_renderer.partial_rendering_state.create_partial_renderer(prepare_scene);
@@ -1580,6 +1637,9 @@ struct SceneBuilder<'a, T> {
     scale_factor: ScaleFactor,
     window: &'a WindowInner,
     rotation: RotationInfo,
+    box_shadow_cache: &'a box_shadow::BoxShadowCache,
+    /// The rounded clips. Only the first `RenderState::rounded_clips` apply to the current state.
+    rounded_clips: Vec<rounded_clip::RoundedClip>,
 }
 
 impl<'a, T: ProcessScene> SceneBuilder<'a, T> {
@@ -1589,6 +1649,7 @@ fn new(
         window: &'a WindowInner,
         processor: T,
         orientation: RenderingRotation,
+        box_shadow_cache: &'a box_shadow::BoxShadowCache,
     ) -> Self {
         Self {
             processor,
@@ -1600,10 +1661,14 @@ fn new(
                     LogicalPoint::default(),
                     (screen_size.cast() / scale_factor).cast(),
                 ),
+                transform: None,
+                rounded_clips: 0,
             },
             scale_factor,
             window,
             rotation: RotationInfo { orientation, screen_size },
+            box_shadow_cache,
+            rounded_clips: Vec::new(),
         }
     }
 
@@ -1613,6 +1678,136 @@ fn should_draw(&self, rect: &LogicalRect) -> bool {
             && self.current_state.clip.intersects(rect)
     }
 
+    /// Returns true if the item with the given geometry must be drawn with [`Self::draw_layer`]
+    /// because it is rotated or overlaps with the corners of a rounded clip.
+    fn needs_layer(&self, geom: &LogicalRect) -> bool {
+        if self.current_state.transform.is_some() {
+            return true;
+        }
+        let rounded_clips = &self.rounded_clips[..self.current_state.rounded_clips];
+        if rounded_clips.is_empty() {
+            return false;
+        }
+        geom.intersection(&self.current_state.clip).is_some_and(|visible| {
+            let visible = visible.translate(self.current_state.offset.to_vector());
+            rounded_clips.iter().any(|clip| clip.touches_corners(&visible))
+        })
+    }
+
+    /// Draw an item through an intermediate buffer: the visible part of `geom` is rendered into
+    /// a layer, which is then mapped into the scene with the current rotation and masked with
+    /// the rounded clips.
+    fn draw_layer(
+        &mut self,
+        geom: LogicalRect,
+        draw: impl FnOnce(&mut dyn crate::item_rendering::ItemRenderer),
+    ) {
+        let transform = self.current_state.transform;
+        // When rotated, keep an extra pixel around the clip so that the interpolation at its
+        // edges is the same regardless of where the clip is
+        let margin = if transform.is_some() { 1. / self.scale_factor.get() } else { 0. };
+        let Some(visible) = geom.intersection(&self.current_state.clip.inflate(margin, margin))
+        else {
+            return;
+        };
+        // The layer is aligned with the physical pixels of the coordinate system of the offset
+        let offset = self.current_state.offset;
+        let source_rect: PhysicalRect =
+            (visible.translate(offset.to_vector()).cast() * self.scale_factor).round_out().cast();
+        if source_rect.is_empty() {
+            return;
+        }
+
+        let width = source_rect.width() as usize;
+        let mut pixels =
+            vec![PremultipliedRgbaColor::default(); width * source_rect.height() as usize];
+        {
+            let mut buffer = TargetPixelSlice { data: &mut pixels, pixel_stride: width };
+            let mut dirty_region = PhysicalRegion::default();
+            dirty_region.rectangles[0] = PhysicalRect::from_size(source_rect.size).to_box2d();
+            dirty_region.count = 1;
+            let mut layer_builder = SceneBuilder::new(
+                source_rect.size,
+                self.scale_factor,
+                self.window,
+                RenderToBuffer { buffer: &mut buffer, dirty_range_cache: vec![], dirty_region },
+                RenderingRotation::NoRotation,
+                self.box_shadow_cache,
+            );
+            layer_builder.current_state = RenderState {
+                alpha: self.current_state.alpha,
+                offset: offset - (source_rect.origin.cast() / self.scale_factor).to_vector(),
+                clip: visible,
+                transform: None,
+                rounded_clips: 0,
+            };
+            draw(&mut layer_builder);
+        }
+
+        // Maps the pixels of the intermediate buffer to physical window coordinates
+        let (to_window, clip) = match transform {
+            Some(layer) => {
+                let scale = self.scale_factor.get();
+                let to_window = euclid::default::Transform2D::translation(
+                    source_rect.origin.x as f32,
+                    source_rect.origin.y as f32,
+                )
+                .then_scale(1. / scale, 1. / scale)
+                .then(&layer.transform.to_untyped())
+                .then_scale(scale, scale);
+                (transform::PhysicalTransform::from_untyped(&to_window), layer.clip)
+            }
+            None => (
+                transform::PhysicalTransform::translation(
+                    source_rect.origin.x as f32,
+                    source_rect.origin.y as f32,
+                ),
+                self.current_state.clip.translate(offset.to_vector()),
+            ),
+        };
+
+        let clip = (clip.cast() * self.scale_factor).round();
+        let Some(target_rect) = to_window
+            .outer_transformed_rect(&PhysicalRect::from_size(source_rect.size).cast())
+            .round_out()
+            .intersection(&clip)
+        else {
+            return;
+        };
+        let target_rect: PhysicalRect = target_rect.cast();
+
+        let Some(mut buffer) = transform::transform_layer(&pixels, width, &to_window, target_rect)
+        else {
+            return;
+        };
+        for clip in &self.rounded_clips[..self.current_state.rounded_clips] {
+            clip.apply(&mut buffer, target_rect.origin, self.scale_factor);
+        }
+
+        let target_rect = target_rect.transformed(self.rotation);
+        let t = target_pixel_buffer::DrawTextureArgs {
+            data: target_pixel_buffer::TextureDataContainer::Shared {
+                source_rect: PhysicalRect::from_size(euclid::size2(
+                    buffer.width() as _,
+                    buffer.height() as _,
+                )),
+                buffer: SharedBufferData::SharedImage(SharedImageBuffer::RGBA8Premultiplied(
+                    buffer,
+                )),
+            },
+            colorize: None,
+            // alpha was already applied when rendering the layer
+            alpha: 255,
+            dst_x: target_rect.origin.x as _,
+            dst_y: target_rect.origin.y as _,
+            dst_width: target_rect.size.width as _,
+            dst_height: target_rect.size.height as _,
+            rotation: self.rotation.orientation,
+            tiling: None,
+        };
+        self.processor.process_target_texture(&t, target_rect);
+    }
+
     fn draw_image_impl(
         &mut self,
         image_inner: &ImageInner,
@@ -1804,35 +1999,65 @@ fn draw_text_paragraph<Font>(
         offset: euclid::Vector2D<f32, PhysicalPx>,
         color: Color,
         selection: Option<SelectionInfo>,
+        span_colors: Option<&SpanColors>,
     ) where
         Font: AbstractFont + crate::textlayout::TextShaper<Length = PhysicalLength> + GlyphRenderer,
     {
         paragraph
             .layout_lines::<()>(
-                |glyphs, line_x, line_y, _, sel| {
-                    let baseline_y = line_y + paragraph.layout.font.ascent();
-                    if let (Some(sel), Some(selection)) = (sel, &selection) {
-                        let geometry = euclid::rect(
-                            line_x.get() + sel.start.get(),
-                            line_y.get(),
-                            (sel.end - sel.start).get(),
-                            paragraph.layout.font.height().get(),
-                        );
-                        if let Some(clipped_src) = geometry.intersection(&physical_clip.cast()) {
-                            let geometry =
-                                clipped_src.translate(offset.cast()).transformed(self.rotation);
-                            let args = target_pixel_buffer::DrawRectangleArgs::from_rect(
-                                geometry.cast(),
-                                selection.selection_background.into(),
+                |glyphs, line_x, line_y, line, sel| {
+                    let baseline_y = line_y + line.ascent;
+                    if let Some(selection) = &selection {
+                        for sel in sel {
+                            let geometry = euclid::rect(
+                                line_x.get() + sel.start.get(),
+                                line_y.get(),
+                                (sel.end - sel.start).get(),
+                                line.height.get(),
                             );
-                            self.processor.process_rectangle(&args, geometry);
+                            if let Some(clipped_src) = geometry.intersection(&physical_clip.cast())
+                            {
+                                let geometry =
+                                    clipped_src.translate(offset.cast()).transformed(self.rotation);
+                                let args = target_pixel_buffer::DrawRectangleArgs::from_rect(
+                                    geometry.cast(),
+                                    selection.selection_background.into(),
+                                );
+                                self.processor.process_rectangle(&args, geometry);
+                            }
                         }
                     }
-                    let scale_delta = paragraph.layout.font.scale_delta();
                     for positioned_glyph in glyphs {
-                        let Some(glyph) =
-                            paragraph.layout.font.render_glyph(positioned_glyph.glyph_id)
-                        else {
+                        let font = paragraph.layout_at(positioned_glyph.text_byte_offset).font;
+                        let (color, underline) = match span_colors
+                            .and_then(|spans| spans.style_at(positioned_glyph.text_byte_offset))
+                        {
+                            Some(style) => style,
+                            None => (color, false),
+                        };
+
+                        if underline {
+                            let thickness = (font.height().get() / 16).max(1);
+                            let geometry = euclid::rect(
+                                line_x.get() + positioned_glyph.x.get(),
+                                baseline_y.get() + thickness,
+                                positioned_glyph.advance.get(),
+                                thickness,
+                            );
+                            if let Some(clipped_src) = geometry.intersection(&physical_clip.cast())
+                            {
+                                let geometry =
+                                    clipped_src.translate(offset.cast()).transformed(self.rotation);
+                                let args = target_pixel_buffer::DrawRectangleArgs::from_rect(
+                                    geometry.cast(),
+                                    color.into(),
+                                );
+                                self.processor.process_rectangle(&args, geometry);
+                            }
+                        }
+
+                        let scale_delta = font.scale_delta();
+                        let Some(glyph) = font.render_glyph(positioned_glyph.glyph_id) else {
                             continue;
                         };
 
@@ -1962,6 +2187,78 @@ fn draw_text_paragraph<Font>(
             .ok();
     }
 
+    /// Fill the rounded rectangle `geom` with a conic gradient. The scene has no command for
+    /// conic gradients, so the pixels are computed here and drawn as a texture.
+    fn draw_conic_gradient(
+        &mut self,
+        geom: LogicalRect,
+        radius: LogicalBorderRadius,
+        gradient: &crate::graphics::ConicGradientBrush,
+    ) {
+        let physical_geom = (geom.translate(self.current_state.offset.to_vector()).cast()
+            * self.scale_factor)
+            .cast::<f32>();
+        let physical_clip =
+            (self.current_state.clip.translate(self.current_state.offset.to_vector()).cast()
+                * self.scale_factor)
+                .round()
+                .cast::<i32>();
+        let Some(target_rect) =
+            physical_geom.round_out().cast::<i32>().intersection(&physical_clip)
+        else {
+            return;
+        };
+        let radius = (radius.cast() * self.scale_factor)
+            .min(BorderRadius::from_length(physical_geom.width_length() / 2.))
+            .min(BorderRadius::from_length(physical_geom.height_length() / 2.))
+            .max(BorderRadius::default());
+
+        let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::new(
+            target_rect.width() as _,
+            target_rect.height() as _,
+        );
+        let width = target_rect.width() as usize;
+        for (i, pixel) in buffer.make_mut_slice().iter_mut().enumerate() {
+            let pos = euclid::point2::<f32, PhysicalPx>(
+                target_rect.min_x() as f32 + (i % width) as f32 + 0.5,
+                target_rect.min_y() as f32 + (i / width) as f32 + 0.5,
+            );
+            let coverage = rounded_clip::rounded_rect_coverage(pos, &physical_geom, &radius, 1.);
+            if coverage <= 0. {
+                continue;
+            }
+            let color = gradient.color_at(
+                (pos - physical_geom.origin).to_point().to_untyped(),
+                physical_geom.size.to_untyped(),
+            );
+            let color: PremultipliedRgbaColor =
+                color.with_alpha(color.alpha() as f32 / 255. * coverage).into();
+            *pixel = Rgba8Pixel::new(color.red, color.green, color.blue, color.alpha);
+        }
+
+        let target_rect = target_rect.cast::<i16>().transformed(self.rotation);
+        let t = target_pixel_buffer::DrawTextureArgs {
+            data: target_pixel_buffer::TextureDataContainer::Shared {
+                source_rect: PhysicalRect::from_size(euclid::size2(
+                    buffer.width() as _,
+                    buffer.height() as _,
+                )),
+                buffer: SharedBufferData::SharedImage(SharedImageBuffer::RGBA8Premultiplied(
+                    buffer,
+                )),
+            },
+            colorize: None,
+            alpha: (self.current_state.alpha * 255.) as u8,
+            dst_x: target_rect.origin.x as _,
+            dst_y: target_rect.origin.y as _,
+            dst_width: target_rect.size.width as _,
+            dst_height: target_rect.size.height as _,
+            rotation: self.rotation.orientation,
+            tiling: None,
+        };
+        self.processor.process_target_texture(&t, target_rect);
+    }
+
     /// Returns the color, mixed with the current_state's alpha
     fn alpha_color(&self, color: Color) -> Color {
         if self.current_state.alpha < 1.0 {
@@ -1990,6 +2287,25 @@ fn alpha_color(color: Color, alpha: u8) -> Color {
     }
 }
 
+/// The colors of the spans of a `StyledText` element, see [`SceneBuilder::draw_text_paragraph`]
+struct SpanColors<'a> {
+    styled_string: &'a crate::items::StyledString,
+    /// The color of each span in `styled_string`
+    colors: Vec<Color>,
+}
+
+impl SpanColors<'_> {
+    /// Returns the color of the text at the given byte offset and whether it is underlined,
+    /// or None if it has the default style
+    fn style_at(&self, byte_offset: usize) -> Option<(Color, bool)> {
+        let index =
+            self.styled_string.spans.partition_point(|span| span.byte_range.end <= byte_offset);
+        let span = self.styled_string.spans.get(index)?;
+        (span.byte_range.start <= byte_offset)
+            .then(|| (self.colors[index], span.style.link.is_some()))
+    }
+}
+
 struct SelectionInfo {
     selection_color: Color,
     selection_background: Color,
@@ -2001,18 +2317,41 @@ struct RenderState {
     alpha: f32,
     offset: LogicalPoint,
     clip: LogicalRect,
+    /// Set when the items are rotated
+    transform: Option<LayerTransform>,
+    /// The number of rounded clips in `SceneBuilder::rounded_clips` that apply
+    rounded_clips: usize,
+}
+
+/// Items in a rotated coordinate system are rendered in an intermediate buffer which is then
+/// mapped into the scene with this transform.
+#[derive(Clone, Copy, Debug)]
+struct LayerTransform {
+    /// Maps the coordinate system in which [`RenderState::offset`] is expressed to the window
+    transform: ItemTransform,
+    /// The clip, in window coordinates, that was in effect when the first rotation was applied
+    clip: LogicalRect,
 }
 
 impl<T: ProcessScene> crate::item_rendering::ItemRenderer for SceneBuilder<'_, T> {
     fn draw_rectangle(
         &mut self,
         rect: Pin<&dyn RenderRectangle>,
-        _: &ItemRc,
+        self_rc: &ItemRc,
         size: LogicalSize,
-        _cache: &CachedRenderingData,
+        cache: &CachedRenderingData,
     ) {
         let geom = LogicalRect::from(size);
+        if self.needs_layer(&geom) {
+            return self.draw_layer(geom, |r| r.draw_rectangle(rect, self_rc, size, cache));
+        }
         if self.should_draw(&geom) {
+            let background = rect.background();
+            if let Brush::ConicGradient(g) = &background {
+                self.draw_conic_gradient(geom, Default::default(), g);
+                return;
+            }
+
             let geom = (geom.translate(self.current_state.offset.to_vector()).cast()
                 * self.scale_factor)
                 .transformed(self.rotation);
@@ -2024,8 +2363,7 @@ fn draw_rectangle(
                     .cast()
                     .transformed(self.rotation);
 
-            let mut args =
-                target_pixel_buffer::DrawRectangleArgs::from_rect(geom, rect.background());
+            let mut args = target_pixel_buffer::DrawRectangleArgs::from_rect(geom, background);
             args.alpha = (self.current_state.alpha * 255.) as u8;
             args.rotation = self.rotation.orientation;
             self.processor.process_rectangle(&args, clipped);
@@ -2035,12 +2373,22 @@ fn draw_rectangle(
     fn draw_border_rectangle(
         &mut self,
         rect: Pin<&dyn RenderBorderRectangle>,
-        _: &ItemRc,
+        self_rc: &ItemRc,
         size: LogicalSize,
-        _: &CachedRenderingData,
+        cache: &CachedRenderingData,
     ) {
         let geom = LogicalRect::from(size);
+        if self.needs_layer(&geom) {
+            return self.draw_layer(geom, |r| r.draw_border_rectangle(rect, self_rc, size, cache));
+        }
         if self.should_draw(&geom) {
+            let mut background = rect.background();
+            if let Brush::ConicGradient(g) = &background {
+                // The background is rendered separately, then the border is drawn on top of it
+                self.draw_conic_gradient(geom, rect.border_radius(), g);
+                background = Brush::default();
+            }
+
             let geom = (geom.translate(self.current_state.offset.to_vector()).cast()
                 * self.scale_factor)
                 .transformed(self.rotation);
@@ -2071,7 +2419,7 @@ fn draw_border_rectangle(
                 bottom_right_radius: radius.bottom_right,
                 bottom_left_radius: radius.bottom_left,
                 border_width: border.get(),
-                background: rect.background(),
+                background,
                 border: border_color,
                 alpha: (self.current_state.alpha * 255.) as u8,
                 rotation: self.rotation.orientation,
@@ -2095,11 +2443,14 @@ fn draw_window_background(
     fn draw_image(
         &mut self,
         image: Pin<&dyn RenderImage>,
-        _: &ItemRc,
+        self_rc: &ItemRc,
         size: LogicalSize,
-        _: &CachedRenderingData,
+        cache: &CachedRenderingData,
     ) {
         let geom = LogicalRect::from(size);
+        if self.needs_layer(&geom) {
+            return self.draw_layer(geom, |r| r.draw_image(image, self_rc, size, cache));
+        }
         if self.should_draw(&geom) {
             let source = image.source();
 
@@ -2146,7 +2497,7 @@ fn draw_text(
         text: Pin<&dyn crate::item_rendering::RenderText>,
         self_rc: &ItemRc,
         size: LogicalSize,
-        _cache: &CachedRenderingData,
+        cache: &CachedRenderingData,
     ) {
         let string = text.text();
         if string.trim().is_empty() {
@@ -2156,6 +2507,9 @@ fn draw_text(
         if !self.should_draw(&geom) {
             return;
         }
+        if self.needs_layer(&geom) {
+            return self.draw_layer(geom, |r| r.draw_text(text, self_rc, size, cache));
+        }
 
         let font_request = text.font_request(self_rc);
 
@@ -2183,6 +2537,7 @@ fn draw_text(
                 let paragraph = TextParagraphLayout {
                     string: &string,
                     layout,
+                    spans: &[],
                     max_width: max_size.width_length(),
                     max_height: max_size.height_length(),
                     horizontal_alignment,
@@ -2192,7 +2547,7 @@ fn draw_text(
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None, None);
             }
             #[cfg(feature = "software-renderer-systemfonts")]
             fonts::Font::VectorFont(vf) => {
@@ -2202,6 +2557,7 @@ fn draw_text(
                 let paragraph = TextParagraphLayout {
                     string: &string,
                     layout,
+                    spans: &[],
                     max_width: max_size.width_length(),
                     max_height: max_size.height_length(),
                     horizontal_alignment,
@@ -2211,11 +2567,81 @@ fn draw_text(
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None, None);
             }
         }
     }
 
+    fn draw_styled_text(
+        &mut self,
+        styled_text: Pin<&crate::items::StyledText>,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+        cache: &CachedRenderingData,
+    ) {
+        let styled_string = styled_text.styled_string();
+        if styled_string.text.trim().is_empty() {
+            return;
+        }
+        let geom = LogicalRect::from(size);
+        if !self.should_draw(&geom) {
+            return;
+        }
+        if self.needs_layer(&geom) {
+            return self
+                .draw_layer(geom, |r| r.draw_styled_text(styled_text, self_rc, size, cache));
+        }
+
+        let font_request = crate::item_rendering::RenderText::font_request(styled_text, self_rc);
+
+        let color = self.alpha_color(styled_text.color().color());
+        let span_colors = SpanColors {
+            styled_string: &styled_string,
+            colors: styled_string
+                .spans
+                .iter()
+                .map(|span| self.alpha_color(styled_text.span_color(Some(&span.style)).color()))
+                .collect(),
+        };
+        let max_size = (geom.size.cast() * self.scale_factor).cast();
+
+        // Clip glyphs against the geometry of the element, like for the Text element
+        let physical_clip = if let Some(logical_clip) = self.current_state.clip.intersection(&geom)
+        {
+            logical_clip.cast() * self.scale_factor
+        } else {
+            return; // This should have been caught earlier already
+        };
+        let offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
+
+        let font = fonts::match_font(&font_request, self.scale_factor);
+        let styled_fonts =
+            fonts::StyledFonts::new(&font_request, &styled_string, self.scale_factor);
+        let spans = styled_fonts.text_spans(self.scale_factor);
+
+        let paragraph = TextParagraphLayout {
+            string: &styled_string.text,
+            layout: fonts::text_layout_for_font(&font, &font_request, self.scale_factor),
+            spans: &spans,
+            max_width: max_size.width_length(),
+            max_height: max_size.height_length(),
+            horizontal_alignment: styled_text.horizontal_alignment(),
+            vertical_alignment: styled_text.vertical_alignment(),
+            wrap: styled_text.wrap(),
+            overflow: styled_text.overflow(),
+            single_line: false,
+        };
+
+        self.draw_text_paragraph(
+            &paragraph,
+            physical_clip,
+            offset,
+            color,
+            None,
+            Some(&span_colors),
+        );
+    }
+
     fn draw_text_input(
         &mut self,
         text_input: Pin<&crate::items::TextInput>,
@@ -2226,6 +2652,9 @@ fn draw_text_input(
         if !self.should_draw(&geom) {
             return;
         }
+        if self.needs_layer(&geom) {
+            return self.draw_layer(geom, |r| r.draw_text_input(text_input, self_rc, size));
+        }
 
         let font_request = text_input.font_request(self_rc);
         let max_size = (geom.size.cast() * self.scale_factor).cast();
@@ -2258,6 +2687,7 @@ fn draw_text_input(
                 let paragraph = TextParagraphLayout {
                     string: &text_visual_representation.text,
                     layout: fonts::text_layout_for_font(&pf, &font_request, self.scale_factor),
+                    spans: &[],
                     max_width: max_size.width_length(),
                     max_height: max_size.height_length(),
                     horizontal_alignment: text_input.horizontal_alignment(),
@@ -2267,7 +2697,7 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection, None);
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), pf.height())
@@ -2278,6 +2708,7 @@ fn draw_text_input(
                 let paragraph = TextParagraphLayout {
                     string: &text_visual_representation.text,
                     layout: fonts::text_layout_for_font(&vf, &font_request, self.scale_factor),
+                    spans: &[],
                     max_width: max_size.width_length(),
                     max_height: max_size.height_length(),
                     horizontal_alignment: text_input.horizontal_alignment(),
@@ -2287,7 +2718,7 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection, None);
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), vf.height())
@@ -2330,28 +2761,166 @@ fn draw_text_input(
     }
 
     #[cfg(feature = "std")]
-    fn draw_path(&mut self, _path: Pin<&crate::items::Path>, _: &ItemRc, _size: LogicalSize) {
-        // TODO
+    fn draw_path(&mut self, path: Pin<&crate::items::Path>, self_rc: &ItemRc, size: LogicalSize) {
+        let geom = LogicalRect::from(size);
+        if !self.should_draw(&geom) {
+            return;
+        }
+        if self.needs_layer(&geom) {
+            return self.draw_layer(geom, |r| r.draw_path(path, self_rc, size));
+        }
+
+        let Some((offset, path_events)) = path.fitted_path_events(self_rc) else {
+            return;
+        };
+
+        let physical_geom = (geom.translate(self.current_state.offset.to_vector()).cast()
+            * self.scale_factor)
+            .cast::<f32>();
+        let physical_clip =
+            (self.current_state.clip.translate(self.current_state.offset.to_vector()).cast()
+                * self.scale_factor)
+                .round()
+                .cast::<i32>();
+        // Only rasterize the part of the path that is visible
+        let Some(target_rect) =
+            physical_geom.round_out().cast::<i32>().intersection(&physical_clip)
+        else {
+            return;
+        };
+
+        let fill = path.fill();
+        let stroke = path.stroke();
+        let params = path::PathRasterizationParams {
+            fill: &fill,
+            fill_rule: path.fill_rule(),
+            stroke: &stroke,
+            stroke_width: (path.stroke_width().cast() * self.scale_factor).get(),
+            line_cap: path.stroke_line_cap(),
+            anti_alias: path.anti_alias(),
+            item_size: physical_geom.size.to_untyped(),
+            origin: (target_rect.origin.cast::<f32>() - physical_geom.origin)
+                .to_point()
+                .to_untyped(),
+            size: target_rect.size.cast().to_untyped(),
+        };
+
+        let scale = self.scale_factor.get();
+        let transform = lyon_path::math::Transform::translation(offset.x as f32, offset.y as f32)
+            .then_scale(scale, scale);
+        let Some(buffer) =
+            path::rasterize_path(path_events.iter().map(|e| e.transformed(&transform)), &params)
+        else {
+            return;
+        };
+
+        let target_rect = target_rect.transformed(self.rotation);
+        let t = target_pixel_buffer::DrawTextureArgs {
+            data: target_pixel_buffer::TextureDataContainer::Shared {
+                source_rect: PhysicalRect::from_size(euclid::size2(
+                    buffer.width() as _,
+                    buffer.height() as _,
+                )),
+                buffer: SharedBufferData::SharedImage(SharedImageBuffer::RGBA8Premultiplied(
+                    buffer,
+                )),
+            },
+            colorize: None,
+            alpha: (self.current_state.alpha * 255.) as u8,
+            dst_x: target_rect.origin.x as _,
+            dst_y: target_rect.origin.y as _,
+            dst_width: target_rect.size.width as _,
+            dst_height: target_rect.size.height as _,
+            rotation: self.rotation.orientation,
+            tiling: None,
+        };
+        self.processor.process_target_texture(&t, target_rect.cast());
     }
 
     fn draw_box_shadow(
         &mut self,
-        _box_shadow: Pin<&crate::items::BoxShadow>,
-        _: &ItemRc,
-        _size: LogicalSize,
+        box_shadow: Pin<&crate::items::BoxShadow>,
+        self_rc: &ItemRc,
+        size: LogicalSize,
     ) {
-        // TODO
+        let color = self.alpha_color(box_shadow.color());
+        let blur = box_shadow.blur().max(LogicalLength::new(0.));
+        let offset = LogicalVector::from_lengths(box_shadow.offset_x(), box_shadow.offset_y());
+        if color.alpha() == 0 || (blur.get() <= 0. && offset.x == 0. && offset.y == 0.) {
+            return;
+        }
+
+        let geom = LogicalRect::new(offset.to_point(), size).inflate(blur.get(), blur.get());
+        if !self.should_draw(&geom) {
+            return;
+        }
+        if self.needs_layer(&geom) {
+            return self.draw_layer(geom, |r| r.draw_box_shadow(box_shadow, self_rc, size));
+        }
+
+        let Some(shadow) =
+            self.box_shadow_cache.get_box_shadow(self_rc, box_shadow, self.scale_factor)
+        else {
+            return;
+        };
+
+        let physical_clip =
+            (self.current_state.clip.translate(self.current_state.offset.to_vector()).cast()
+                * self.scale_factor)
+                .round()
+                .cast();
+        let origin = ((geom.origin + self.current_state.offset.to_vector()).cast()
+            * self.scale_factor)
+            .round()
+            .cast();
+        let target_rect =
+            PhysicalRect::new(origin, PhysicalSize::new(shadow.width() as _, shadow.height() as _));
+        let Some(clipped_target) = physical_clip.intersection(&target_rect) else {
+            return;
+        };
+        let source_rect = PhysicalRect::from_size(target_rect.size);
+        let target_rect = target_rect.transformed(self.rotation);
+
+        let t = target_pixel_buffer::DrawTextureArgs {
+            data: target_pixel_buffer::TextureDataContainer::Shared { buffer: shadow, source_rect },
+            colorize: Some(color),
+            // color already is mixed with global alpha
+            alpha: color.alpha(),
+            dst_x: target_rect.origin.x as _,
+            dst_y: target_rect.origin.y as _,
+            dst_width: target_rect.size.width as _,
+            dst_height: target_rect.size.height as _,
+            rotation: self.rotation.orientation,
+            tiling: None,
+        };
+        self.processor.process_target_texture(&t, clipped_target.transformed(self.rotation));
     }
 
     fn combine_clip(
         &mut self,
         other: LogicalRect,
-        _radius: LogicalBorderRadius,
-        _border_width: LogicalLength,
+        radius: LogicalBorderRadius,
+        border_width: LogicalLength,
     ) -> bool {
+        // Like in the other renderers, the clip is in the middle of the border
+        let half_border = border_width.get().max(0.) / 2.;
+        let other = other.inflate(-half_border, -half_border);
         match self.current_state.clip.intersection(&other) {
             Some(r) => {
                 self.current_state.clip = r;
+                if !radius.is_zero() {
+                    let clip = rounded_clip::RoundedClip {
+                        rect: other.translate(self.current_state.offset.to_vector()),
+                        radius,
+                        from_window: self
+                            .current_state
+                            .transform
+                            .and_then(|t| t.transform.inverse()),
+                    };
+                    self.rounded_clips.truncate(self.current_state.rounded_clips);
+                    self.rounded_clips.push(clip);
+                    self.current_state.rounded_clips += 1;
+                }
                 true
             }
             None => {
@@ -2359,7 +2928,6 @@ fn combine_clip(
                 false
             }
         }
-        // TODO: handle radius and border
     }
 
     fn get_current_clip(&self) -> LogicalRect {
@@ -2375,8 +2943,26 @@ fn translation(&self) -> LogicalVector {
         self.current_state.offset.to_vector()
     }
 
-    fn rotate(&mut self, _angle_in_degrees: f32) {
-        // TODO (#6068)
+    fn rotate(&mut self, angle_in_degrees: f32) {
+        if angle_in_degrees % 360. == 0. {
+            return;
+        }
+        let offset = self.current_state.offset.to_vector();
+        let angle = euclid::Angle::degrees(angle_in_degrees);
+        let (parent, clip) = match self.current_state.transform {
+            Some(layer) => (layer.transform, layer.clip),
+            None => (ItemTransform::identity(), self.current_state.clip.translate(offset)),
+        };
+        // The rotation is around the current origin
+        let transform = ItemTransform::translation(-offset.x, -offset.y)
+            .then_rotate(angle)
+            .then_translate(offset)
+            .then(&parent);
+        self.current_state.transform = Some(LayerTransform { transform, clip });
+        // The clip is not axis aligned anymore in the rotated coordinate system, so use its
+        // bounding box. The actual clip is applied when mapping the layer into the scene.
+        self.current_state.clip =
+            ItemTransform::rotation(-angle).outer_transformed_rect(&self.current_state.clip);
     }
 
     fn apply_opacity(&mut self, opacity: f32) {
@@ -2448,6 +3034,7 @@ fn draw_string(&mut self, string: &str, color: Color) {
                 let paragraph = TextParagraphLayout {
                     string,
                     layout,
+                    spans: &[],
                     max_width: clip.width_length().cast(),
                     max_height: clip.height_length().cast(),
                     horizontal_alignment: Default::default(),
@@ -2457,7 +3044,7 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
+                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None, None);
             }
             #[cfg(feature = "software-renderer-systemfonts")]
             fonts::Font::VectorFont(vf) => {
@@ -2466,6 +3053,7 @@ fn draw_string(&mut self, string: &str, color: Color) {
                 let paragraph = TextParagraphLayout {
                     string,
                     layout,
+                    spans: &[],
                     max_width: clip.width_length().cast(),
                     max_height: clip.height_length().cast(),
                     horizontal_alignment: Default::default(),
@@ -2475,7 +3063,7 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
+                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None, None);
             }
         }
     }
@@ -2494,5 +3082,5 @@ fn as_any(&mut self) -> Option<&mut dyn core::any::Any> {
 }
 
 impl<T: ProcessScene> crate::item_rendering::ItemRendererFeatures for SceneBuilder<'_, T> {
-    const SUPPORTS_TRANSFORMATIONS: bool = false;
+    const SUPPORTS_TRANSFORMATIONS: bool = true;
 }
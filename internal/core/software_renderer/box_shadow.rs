@@ -0,0 +1,176 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the code to render the `BoxShadow` element into an alpha map
+
+use super::scene::SharedBufferData;
+use crate::items::{BoxShadow, ItemRc};
+use crate::lengths::ScaleFactor;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::pin::Pin;
+#[allow(unused)]
+use num_traits::Float;
+
+/// Render a rounded rectangle of the given size, blurred with a gaussian blur.
+///
+/// The blur is the blur radius, in physical pixels. The resulting alpha map is bigger than the
+/// rectangle by `blur` pixels on each side.
+pub fn render_box_shadow(
+    width: f32,
+    height: f32,
+    radius: f32,
+    blur: f32,
+) -> Option<SharedBufferData> {
+    let blur = blur.max(0.);
+    let map_width = (width + blur * 2.).ceil() as usize;
+    let map_height = (height + blur * 2.).ceil() as usize;
+    if map_width == 0 || map_height == 0 || map_width > u16::MAX as usize {
+        return None;
+    }
+
+    // Signed distance field of the rounded rectangle gives an anti-aliased coverage
+    let half_width = width / 2.;
+    let half_height = height / 2.;
+    let radius = radius.clamp(0., half_width.min(half_height));
+    let mut alpha = Vec::with_capacity(map_width * map_height);
+    for y in 0..map_height {
+        for x in 0..map_width {
+            let qx = (x as f32 + 0.5 - blur - half_width).abs() - (half_width - radius);
+            let qy = (y as f32 + 0.5 - blur - half_height).abs() - (half_height - radius);
+            let outside = (qx.max(0.) * qx.max(0.) + qy.max(0.) * qy.max(0.)).sqrt();
+            let distance = outside + qx.max(qy).min(0.) - radius;
+            alpha.push((0.5 - distance).clamp(0., 1.));
+        }
+    }
+
+    // Same as the blur of the other renderers, which use the blur radius as twice the standard deviation
+    let sigma = blur / 2.;
+    if sigma >= 0.5 {
+        let kernel = gaussian_kernel(sigma);
+        let mut tmp = alloc::vec![0.; alpha.len()];
+        blur_pass(&alpha, &mut tmp, map_width, map_height, 1, map_width, &kernel);
+        blur_pass(&tmp, &mut alpha, map_height, map_width, map_width, 1, &kernel);
+    }
+
+    let data = alpha.into_iter().map(|a| (a * 255. + 0.5) as u8).collect::<Rc<[u8]>>();
+    Some(SharedBufferData::AlphaMap { data, width: map_width as u16 })
+}
+
+/// Returns the normalized weights of a one dimensional gaussian kernel, centered in the middle
+fn gaussian_kernel(sigma: f32) -> Vec<f32> {
+    let radius = (sigma * 3.).ceil() as i32;
+    let mut kernel = (-radius..=radius)
+        .map(|i| (-((i * i) as f32) / (2. * sigma * sigma)).exp())
+        .collect::<Vec<_>>();
+    let sum: f32 = kernel.iter().sum();
+    kernel.iter_mut().for_each(|k| *k /= sum);
+    kernel
+}
+
+/// Convolve each of the `lines` of `src` with the kernel into `dst`.
+/// `step` is the distance between two samples of a line, and `line_step` between two lines.
+fn blur_pass(
+    src: &[f32],
+    dst: &mut [f32],
+    len: usize,
+    lines: usize,
+    step: usize,
+    line_step: usize,
+    kernel: &[f32],
+) {
+    let radius = (kernel.len() / 2) as isize;
+    for line in 0..lines {
+        let base = line * line_step;
+        for i in 0..len as isize {
+            let mut acc = 0.;
+            for (k, weight) in kernel.iter().enumerate() {
+                let j = i + k as isize - radius;
+                if j >= 0 && j < len as isize {
+                    acc += src[base + j as usize * step] * weight;
+                }
+            }
+            dst[base + i as usize * step] = acc;
+        }
+    }
+}
+
+/// Keeps the rendered shadows between frames.
+#[derive(Default)]
+pub struct BoxShadowCache {
+    #[cfg(feature = "std")]
+    shadows: crate::graphics::boxshadowcache::BoxShadowCache<SharedBufferData>,
+    #[cfg(feature = "std")]
+    items: crate::item_rendering::ItemCache<Option<SharedBufferData>>,
+}
+
+impl BoxShadowCache {
+    /// Returns the shadow texture for the given item, rendering it if it isn't cached.
+    pub fn get_box_shadow(
+        &self,
+        item_rc: &ItemRc,
+        box_shadow: Pin<&BoxShadow>,
+        scale_factor: ScaleFactor,
+    ) -> Option<SharedBufferData> {
+        #[cfg(feature = "std")]
+        return self.shadows.get_box_shadow(
+            item_rc,
+            &self.items,
+            box_shadow,
+            scale_factor,
+            |options| {
+                render_box_shadow(
+                    options.width.get(),
+                    options.height.get(),
+                    options.radius.get(),
+                    options.blur.get(),
+                )
+            },
+        );
+        #[cfg(not(feature = "std"))]
+        {
+            use crate::lengths::RectLengths;
+            let geometry = item_rc.geometry();
+            let width = geometry.width_length() * scale_factor;
+            let height = geometry.height_length() * scale_factor;
+            if box_shadow.color().alpha() == 0 || width.get() < 1. || height.get() < 1. {
+                return None;
+            }
+            render_box_shadow(
+                width.get(),
+                height.get(),
+                (box_shadow.border_radius() * scale_factor).get(),
+                (box_shadow.blur() * scale_factor).get(),
+            )
+        }
+    }
+
+    /// Free the cached shadows of the items of that component
+    pub fn component_destroyed(&self, _component: crate::item_tree::ItemTreeRef) {
+        #[cfg(feature = "std")]
+        self.items.component_destroyed(_component);
+    }
+}
+
+#[test]
+fn box_shadow_alpha_map() {
+    let Some(SharedBufferData::AlphaMap { data, width }) = render_box_shadow(10., 6., 0., 0.)
+    else {
+        panic!("expected an alpha map")
+    };
+    assert_eq!((width, data.len()), (10, 60));
+    assert!(data.iter().all(|a| *a == 255));
+
+    let Some(SharedBufferData::AlphaMap { data, width }) = render_box_shadow(10., 6., 3., 4.)
+    else {
+        panic!("expected an alpha map")
+    };
+    assert_eq!((width, data.len()), (18, 18 * 14));
+    let at = |x: usize, y: usize| data[y * width as usize + x];
+    // The center is opaque, and it fades out towards the edges
+    assert!(at(9, 7) > 200);
+    assert!(at(9, 7) > at(4, 7));
+    assert!(at(4, 7) > at(0, 7));
+    // Symmetric
+    assert!(at(2, 3).abs_diff(at(15, 10)) <= 1);
+}
@@ -9,7 +9,7 @@
 use crate::graphics::{BitmapFont, FontRequest};
 use crate::items::TextWrap;
 use crate::lengths::{LogicalLength, LogicalSize, ScaleFactor};
-use crate::textlayout::{FontMetrics, TextLayout};
+use crate::textlayout::{FontMetrics, Glyph, TextLayout, TextShaper, TextSpan};
 use crate::Coord;
 
 crate::thread_local! {
@@ -103,6 +103,59 @@ fn cap_height(&self) -> PhysicalLength {
     }
 }
 
+/// Dispatches to the font of each variant, so that text using a mix of pixel and vector fonts
+/// can be laid out together, as in styled text.
+impl TextShaper for Font {
+    type LengthPrimitive = i16;
+    type Length = PhysicalLength;
+
+    fn shape_text<GlyphStorage: core::iter::Extend<Glyph<PhysicalLength>>>(
+        &self,
+        text: &str,
+        glyphs: &mut GlyphStorage,
+    ) {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.shape_text(text, glyphs),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.shape_text(text, glyphs),
+        }
+    }
+
+    fn glyph_for_char(&self, ch: char) -> Option<Glyph<PhysicalLength>> {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.glyph_for_char(ch),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.glyph_for_char(ch),
+        }
+    }
+
+    fn max_lines(&self, max_height: PhysicalLength) -> usize {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.max_lines(max_height),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.max_lines(max_height),
+        }
+    }
+}
+
+impl GlyphRenderer for Font {
+    fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> Option<RenderableGlyph> {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.render_glyph(glyph_id),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.render_glyph(glyph_id),
+        }
+    }
+
+    fn scale_delta(&self) -> Fixed<u16, 8> {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.scale_delta(),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.scale_delta(),
+        }
+    }
+}
+
 pub fn match_font(request: &FontRequest, scale_factor: ScaleFactor) -> Font {
     let requested_weight = request
         .weight
@@ -214,6 +267,71 @@ pub fn text_size(
     (PhysicalSize::from_lengths(longest_line_width, height).cast() / scale_factor).cast()
 }
 
+/// The fonts of the styled spans of a text, see [`Self::text_spans`]
+pub struct StyledFonts {
+    fonts: Vec<(FontRequest, Font)>,
+    /// The byte range and the index in `fonts` of each span that doesn't use the default font
+    spans: Vec<(core::ops::Range<usize>, usize)>,
+}
+
+impl StyledFonts {
+    pub fn new(
+        font_request: &FontRequest,
+        styled_text: &crate::items::StyledString,
+        scale_factor: ScaleFactor,
+    ) -> Self {
+        let mut fonts: Vec<(FontRequest, Font)> = Vec::new();
+        let mut spans = Vec::new();
+        for span in styled_text.spans.iter().filter(|span| !span.style.has_default_font()) {
+            let request = span.style.font_request(font_request);
+            let index = match fonts.iter().position(|(r, _)| *r == request) {
+                Some(index) => index,
+                None => {
+                    let font = match_font(&request, scale_factor);
+                    fonts.push((request, font));
+                    fonts.len() - 1
+                }
+            };
+            spans.push((span.byte_range.clone(), index));
+        }
+        Self { fonts, spans }
+    }
+
+    /// Returns the spans to pass to the text layout
+    pub fn text_spans(&self, scale_factor: ScaleFactor) -> Vec<TextSpan<'_, Font>> {
+        self.spans
+            .iter()
+            .map(|(byte_range, index)| {
+                let (request, font) = &self.fonts[*index];
+                TextSpan {
+                    byte_range: byte_range.clone(),
+                    layout: text_layout_for_font(font, request, scale_factor),
+                }
+            })
+            .collect()
+    }
+}
+
+pub fn styled_text_size(
+    font_request: FontRequest,
+    styled_text: &crate::items::StyledString,
+    max_width: Option<LogicalLength>,
+    scale_factor: ScaleFactor,
+    text_wrap: TextWrap,
+) -> LogicalSize {
+    let font = match_font(&font_request, scale_factor);
+    let layout = text_layout_for_font(&font, &font_request, scale_factor);
+    let styled_fonts = StyledFonts::new(&font_request, styled_text, scale_factor);
+    let (longest_line_width, height) = layout.styled_text_size(
+        &styled_fonts.text_spans(scale_factor),
+        &styled_text.text,
+        max_width.map(|max_width| (max_width.cast() * scale_factor).cast()),
+        text_wrap,
+    );
+
+    (PhysicalSize::from_lengths(longest_line_width, height).cast() / scale_factor).cast()
+}
+
 pub fn font_metrics(
     font_request: FontRequest,
     scale_factor: ScaleFactor,
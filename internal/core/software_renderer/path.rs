@@ -0,0 +1,461 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains a small scanline rasterizer used to render the `Path` element
+//! into a premultiplied RGBA texture.
+
+use crate::graphics::{gradient_color_at, Brush, Rgba8Pixel, SharedPixelBuffer};
+use crate::items::{FillRule, LineCap};
+use crate::Color;
+use alloc::vec::Vec;
+use euclid::default::{Point2D, Size2D, Vector2D};
+use lyon_path::iterator::PathIterator;
+
+type Point = Point2D<f32>;
+
+/// Maximum distance between a curve and its flattened approximation, in physical pixels.
+const FLATTENING_TOLERANCE: f32 = 0.1;
+/// Number of sub-scanlines used per pixel row when anti-aliasing.
+const SUBSAMPLES: usize = 8;
+/// SVG's default `stroke-miterlimit`.
+const MITER_LIMIT: f32 = 4.;
+
+/// The parameters of a path to rasterize. All coordinates are in physical pixels.
+pub struct PathRasterizationParams<'a> {
+    /// Brush for the interior of the path
+    pub fill: &'a Brush,
+    pub fill_rule: FillRule,
+    /// Brush for the outline of the path
+    pub stroke: &'a Brush,
+    pub stroke_width: f32,
+    pub line_cap: LineCap,
+    pub anti_alias: bool,
+    /// The size of the `Path` item, used to map the gradients
+    pub item_size: Size2D<f32>,
+    /// The position of the top-left corner of the resulting texture, relative to the item
+    pub origin: Point,
+    /// The size of the resulting texture
+    pub size: Size2D<u32>,
+}
+
+/// A sub-path flattened into a polyline
+struct Polyline {
+    points: Vec<Point>,
+    closed: bool,
+}
+
+/// Flatten the path events (already in physical item coordinates) into a list of polylines
+fn flatten(
+    events: impl Iterator<Item = lyon_path::Event<lyon_path::math::Point, lyon_path::math::Point>>,
+) -> Vec<Polyline> {
+    let mut result = Vec::new();
+    let mut current = Vec::new();
+    let push_point = |current: &mut Vec<Point>, p: lyon_path::math::Point| {
+        let p = Point::new(p.x, p.y);
+        if current.last().is_none_or(|last: &Point| (*last - p).square_length() > 1e-6) {
+            current.push(p);
+        }
+    };
+    for event in events.flattened(FLATTENING_TOLERANCE) {
+        match event {
+            lyon_path::Event::Begin { at } => {
+                current.clear();
+                push_point(&mut current, at);
+            }
+            lyon_path::Event::Line { to, .. } => push_point(&mut current, to),
+            lyon_path::Event::End { close, .. } => {
+                let mut points = core::mem::take(&mut current);
+                if close
+                    && points.len() > 1
+                    && (points[0] - *points.last().unwrap()).square_length() <= 1e-6
+                {
+                    points.pop();
+                }
+                if !points.is_empty() {
+                    result.push(Polyline { points, closed: close });
+                }
+            }
+            lyon_path::Event::Quadratic { .. } | lyon_path::Event::Cubic { .. } => {
+                unreachable!("curves are removed by the flattening")
+            }
+        }
+    }
+    result
+}
+
+#[derive(Clone, Copy)]
+struct Edge {
+    from: Point,
+    to: Point,
+    /// +1 if the edge goes down, -1 if it goes up
+    winding: i32,
+}
+
+/// Accumulates edges of polygons and computes their coverage for each pixel.
+struct Rasterizer {
+    width: usize,
+    height: usize,
+    edges: Vec<Edge>,
+}
+
+impl Rasterizer {
+    fn new(size: Size2D<u32>) -> Self {
+        Self { width: size.width as usize, height: size.height as usize, edges: Vec::new() }
+    }
+
+    fn add_line(&mut self, from: Point, to: Point) {
+        if from.y == to.y {
+            // horizontal edges never cross a scanline
+            return;
+        }
+        if from.y < to.y {
+            self.edges.push(Edge { from, to, winding: 1 });
+        } else {
+            self.edges.push(Edge { from: to, to: from, winding: -1 });
+        }
+    }
+
+    /// Adds a closed polygon.
+    fn add_polygon(&mut self, points: &[Point]) {
+        for (i, p) in points.iter().enumerate() {
+            self.add_line(*p, points[(i + 1) % points.len()]);
+        }
+    }
+
+    /// Adds a closed polygon, normalizing its orientation so that overlapping polygons
+    /// never cancel each other with the non-zero fill rule.
+    fn add_oriented_polygon(&mut self, points: &[Point]) {
+        let area: f32 = points
+            .iter()
+            .enumerate()
+            .map(|(i, p)| p.to_vector().cross(points[(i + 1) % points.len()].to_vector()))
+            .sum();
+        if area < 0. {
+            self.add_polygon(points);
+        } else {
+            let reversed = points.iter().rev().copied().collect::<Vec<_>>();
+            self.add_polygon(&reversed);
+        }
+    }
+
+    /// Compute the coverage of each pixel, from 0 to 255
+    fn coverage(mut self, fill_rule: FillRule, anti_alias: bool) -> Vec<u8> {
+        let mut result = alloc::vec![0u8; self.width * self.height];
+        if self.edges.is_empty() || self.width == 0 {
+            return result;
+        }
+        self.edges.sort_unstable_by(|a, b| a.from.y.total_cmp(&b.from.y));
+
+        let subsamples = if anti_alias { SUBSAMPLES } else { 1 };
+        let weight = 1. / subsamples as f32;
+        let mut row = alloc::vec![0f32; self.width + 1];
+        let mut active: Vec<Edge> = Vec::new();
+        let mut crossings: Vec<(f32, i32)> = Vec::new();
+        let mut next_edge = 0;
+
+        for y in 0..self.height {
+            row.fill(0.);
+            for s in 0..subsamples {
+                let sample_y = y as f32 + (s as f32 + 0.5) * weight;
+                while next_edge < self.edges.len() && self.edges[next_edge].from.y <= sample_y {
+                    active.push(self.edges[next_edge]);
+                    next_edge += 1;
+                }
+                active.retain(|e| e.to.y > sample_y);
+
+                crossings.clear();
+                crossings.extend(active.iter().filter(|e| e.from.y <= sample_y).map(|e| {
+                    let t = (sample_y - e.from.y) / (e.to.y - e.from.y);
+                    (e.from.x + t * (e.to.x - e.from.x), e.winding)
+                }));
+                crossings.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+                let mut winding = 0;
+                for w in crossings.windows(2) {
+                    winding += w[0].1;
+                    let inside = match fill_rule {
+                        FillRule::Nonzero => winding != 0,
+                        FillRule::Evenodd => winding % 2 != 0,
+                    };
+                    if inside {
+                        if anti_alias {
+                            add_span(&mut row, w[0].0, w[1].0, weight);
+                        } else {
+                            // Only consider the pixel whose center is inside the span
+                            add_span(&mut row, (w[0].0 + 0.5).floor(), (w[1].0 + 0.5).floor(), 1.);
+                        }
+                    }
+                }
+            }
+            for (dst, cov) in result[y * self.width..][..self.width].iter_mut().zip(row.iter()) {
+                *dst = (cov.clamp(0., 1.) * 255. + 0.5) as u8;
+            }
+        }
+        result
+    }
+}
+
+/// Add `weight` times the horizontal coverage of the span `[begin, end[` to the pixels of the row
+fn add_span(row: &mut [f32], begin: f32, end: f32, weight: f32) {
+    let width = (row.len() - 1) as f32;
+    let begin = begin.clamp(0., width);
+    let end = end.clamp(0., width);
+    if end <= begin {
+        return;
+    }
+    let first = begin as usize;
+    let last = end as usize;
+    if first == last {
+        row[first] += (end - begin) * weight;
+        return;
+    }
+    row[first] += (first as f32 + 1. - begin) * weight;
+    for px in &mut row[first + 1..last] {
+        *px += weight;
+    }
+    row[last] += (end - last as f32) * weight;
+}
+
+/// Adds the outline of the polylines, `width` wide, as a set of polygons to the rasterizer.
+fn add_stroke(rasterizer: &mut Rasterizer, polylines: &[Polyline], width: f32, line_cap: LineCap) {
+    let half = width / 2.;
+    let normal = |d: Vector2D<f32>| Vector2D::new(-d.y, d.x) * half;
+
+    let add_circle = |rasterizer: &mut Rasterizer, center: Point| {
+        // Enough segments so that the error stays below the tolerance
+        let segments = ((core::f32::consts::PI
+            / (1. - FLATTENING_TOLERANCE / half.max(FLATTENING_TOLERANCE)).acos())
+        .ceil() as usize)
+            .clamp(8, 256);
+        let points = (0..segments)
+            .map(|i| {
+                let (s, c) = (i as f32 * core::f32::consts::TAU / segments as f32).sin_cos();
+                center + Vector2D::new(c, s) * half
+            })
+            .collect::<Vec<_>>();
+        rasterizer.add_oriented_polygon(&points);
+    };
+
+    for polyline in polylines {
+        let points = &polyline.points;
+        if points.len() == 1 {
+            let p = points[0];
+            match line_cap {
+                LineCap::Butt => {}
+                LineCap::Round => add_circle(rasterizer, p),
+                LineCap::Square => rasterizer.add_oriented_polygon(&[
+                    p + Vector2D::new(-half, -half),
+                    p + Vector2D::new(half, -half),
+                    p + Vector2D::new(half, half),
+                    p + Vector2D::new(-half, half),
+                ]),
+            }
+            continue;
+        }
+
+        let segment_count = if polyline.closed { points.len() } else { points.len() - 1 };
+        let segment = |i: usize| (points[i], points[(i + 1) % points.len()]);
+
+        for i in 0..segment_count {
+            let (mut from, mut to) = segment(i);
+            let d = (to - from).normalize();
+            if !polyline.closed && line_cap == LineCap::Square {
+                if i == 0 {
+                    from -= d * half;
+                }
+                if i == segment_count - 1 {
+                    to += d * half;
+                }
+            }
+            let n = normal(d);
+            rasterizer.add_oriented_polygon(&[from + n, to + n, to - n, from - n]);
+        }
+
+        // Joins
+        let joins = if polyline.closed { 0..points.len() } else { 1..points.len() - 1 };
+        for i in joins {
+            let prev = points[(i + points.len() - 1) % points.len()];
+            let p = points[i];
+            let next = points[(i + 1) % points.len()];
+            let d1 = (p - prev).normalize();
+            let d2 = (next - p).normalize();
+            let cross = d1.cross(d2);
+            if cross.abs() < 1e-6 && d1.dot(d2) > 0. {
+                // Collinear segments don't need a join
+                continue;
+            }
+            // The outer side of the corner
+            let (n1, n2) =
+                if cross > 0. { (-normal(d1), -normal(d2)) } else { (normal(d1), normal(d2)) };
+            let cos_theta = d1.dot(d2);
+            // ratio between the miter length and the stroke width is 1/sin(θ/2)
+            let miter_ratio = (2. / (1. + cos_theta).max(1e-6)).sqrt();
+            if miter_ratio <= MITER_LIMIT {
+                let miter = (n1 + n2) / (1. + cos_theta);
+                rasterizer.add_oriented_polygon(&[p, p + n1, p + miter, p + n2]);
+            } else {
+                rasterizer.add_oriented_polygon(&[p, p + n1, p + n2]);
+            }
+        }
+
+        if !polyline.closed && line_cap == LineCap::Round {
+            add_circle(rasterizer, points[0]);
+            add_circle(rasterizer, *points.last().unwrap());
+        }
+    }
+}
+
+/// Helper to compute the color of a brush at a given position of the item.
+struct BrushSampler<'a> {
+    brush: &'a Brush,
+    /// For linear gradients: the start point and the vector to the end point, divided by its squared length
+    linear: (Point, Vector2D<f32>),
+    /// For radial gradients: the center and the radius
+    radial: (Point, f32),
+    /// The size of the item, for conic gradients
+    size: Size2D<f32>,
+}
+
+impl<'a> BrushSampler<'a> {
+    fn new(brush: &'a Brush, size: Size2D<f32>) -> Self {
+        let linear = match brush {
+            Brush::LinearGradient(g) => {
+                let (start, end) = crate::graphics::line_for_angle(g.angle(), size);
+                let v = end - start;
+                (start, v / v.square_length().max(f32::EPSILON))
+            }
+            _ => Default::default(),
+        };
+        let center = Point::new(size.width / 2., size.height / 2.);
+        let radius = 0.5 * (size.width * size.width + size.height * size.height).sqrt();
+        Self { brush, linear, radial: (center, radius), size }
+    }
+
+    fn sample(&self, pos: Point) -> Color {
+        match self.brush {
+            Brush::LinearGradient(g) => {
+                gradient_color_at(g.stops(), (pos - self.linear.0).dot(self.linear.1))
+            }
+            Brush::RadialGradient(g) => gradient_color_at(
+                g.stops(),
+                (pos - self.radial.0).length() / self.radial.1.max(f32::EPSILON),
+            ),
+            Brush::ConicGradient(g) => g.color_at(pos, self.size),
+            brush => brush.color(),
+        }
+    }
+}
+
+/// Rasterize the path events into a premultiplied RGBA buffer of the given size.
+///
+/// The events must be in physical coordinates relative to the item.
+/// Returns None if there is nothing to draw.
+pub fn rasterize_path(
+    events: impl Iterator<Item = lyon_path::Event<lyon_path::math::Point, lyon_path::math::Point>>,
+    params: &PathRasterizationParams,
+) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
+    let has_fill = !params.fill.is_transparent();
+    let has_stroke = !params.stroke.is_transparent() && params.stroke_width > 0.;
+    if (!has_fill && !has_stroke) || params.size.is_empty() {
+        return None;
+    }
+
+    let origin = params.origin.to_vector();
+    let polylines =
+        flatten(events.map(|e| {
+            e.transformed(&lyon_path::math::Transform::translation(-origin.x, -origin.y))
+        }));
+    if polylines.is_empty() {
+        return None;
+    }
+
+    let fill_coverage = has_fill.then(|| {
+        let mut rasterizer = Rasterizer::new(params.size);
+        for polyline in polylines.iter().filter(|p| p.points.len() > 2) {
+            rasterizer.add_polygon(&polyline.points);
+        }
+        rasterizer.coverage(params.fill_rule, params.anti_alias)
+    });
+    let stroke_coverage = has_stroke.then(|| {
+        let mut rasterizer = Rasterizer::new(params.size);
+        add_stroke(&mut rasterizer, &polylines, params.stroke_width, params.line_cap);
+        rasterizer.coverage(FillRule::Nonzero, params.anti_alias)
+    });
+
+    let fill = BrushSampler::new(params.fill, params.item_size);
+    let stroke = BrushSampler::new(params.stroke, params.item_size);
+
+    let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::new(params.size.width, params.size.height);
+    let width = params.size.width as usize;
+    let mut is_empty = true;
+    for (i, pixel) in buffer.make_mut_slice().iter_mut().enumerate() {
+        let pos = Point::new((i % width) as f32 + 0.5, (i / width) as f32 + 0.5) + origin;
+        let mut result = super::PremultipliedRgbaColor::default();
+        let mut blend = |coverage: Option<&Vec<u8>>, sampler: &BrushSampler| {
+            let coverage = coverage.map_or(0, |c| c[i]);
+            if coverage > 0 {
+                let color = sampler.sample(pos);
+                let color = color.with_alpha(color.alpha() as f32 / 255. * coverage as f32 / 255.);
+                super::TargetPixel::blend(&mut result, color.into());
+            }
+        };
+        blend(fill_coverage.as_ref(), &fill);
+        blend(stroke_coverage.as_ref(), &stroke);
+        if result.alpha > 0 {
+            is_empty = false;
+        }
+        *pixel = Rgba8Pixel::new(result.red, result.green, result.blue, result.alpha);
+    }
+
+    (!is_empty).then_some(buffer)
+}
+
+#[test]
+fn rasterize_rectangle() {
+    let mut rasterizer = Rasterizer::new(Size2D::new(4, 4));
+    rasterizer.add_polygon(&[
+        Point::new(1., 1.),
+        Point::new(3., 1.),
+        Point::new(3., 2.5),
+        Point::new(1., 2.5),
+    ]);
+    let coverage = rasterizer.coverage(FillRule::Nonzero, true);
+    #[rustfmt::skip]
+    assert_eq!(coverage, [
+        0, 0, 0, 0,
+        0, 255, 255, 0,
+        0, 128, 128, 0,
+        0, 0, 0, 0,
+    ]);
+}
+
+#[test]
+fn rasterize_fill_rule() {
+    let square = |x: f32, y: f32, s: f32| {
+        [Point::new(x, y), Point::new(x + s, y), Point::new(x + s, y + s), Point::new(x, y + s)]
+    };
+    let rasterize = |fill_rule| {
+        let mut rasterizer = Rasterizer::new(Size2D::new(3, 3));
+        rasterizer.add_polygon(&square(0., 0., 3.));
+        rasterizer.add_polygon(&square(1., 1., 1.));
+        rasterizer.coverage(fill_rule, false)
+    };
+    assert_eq!(rasterize(FillRule::Nonzero)[4], 255);
+    assert_eq!(rasterize(FillRule::Evenodd)[4], 0);
+    assert_eq!(rasterize(FillRule::Evenodd)[0], 255);
+}
+
+#[test]
+fn stroke_open_line() {
+    let polylines =
+        [Polyline { points: alloc::vec![Point::new(1., 2.), Point::new(5., 2.)], closed: false }];
+    let coverage = |line_cap| {
+        let mut rasterizer = Rasterizer::new(Size2D::new(7, 4));
+        add_stroke(&mut rasterizer, &polylines, 2., line_cap);
+        rasterizer.coverage(FillRule::Nonzero, false)
+    };
+    let butt = coverage(LineCap::Butt);
+    assert_eq!(&butt[7..14], &[0, 255, 255, 255, 255, 0, 0]);
+    let square = coverage(LineCap::Square);
+    assert_eq!(&square[7..14], &[255, 255, 255, 255, 255, 255, 0]);
+}
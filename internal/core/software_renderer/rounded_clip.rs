@@ -0,0 +1,154 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the code to clip with rounded corners.
+//!
+//! The items that overlap with the corners of a rounded clip are rendered in a layer, which
+//! is then multiplied with the coverage of the clip before being drawn.
+
+use super::PhysicalPoint;
+use crate::graphics::{BorderRadius, Rgba8Pixel, SharedPixelBuffer};
+use crate::lengths::{ItemTransform, LogicalBorderRadius, LogicalPoint, LogicalRect, ScaleFactor};
+#[allow(unused)]
+use num_traits::Float;
+
+#[derive(Clone, Copy, Debug)]
+pub struct RoundedClip {
+    /// The clip rectangle, in logical window coordinates, or in the coordinates given by `from_window`
+    pub rect: LogicalRect,
+    pub radius: LogicalBorderRadius,
+    /// Maps the logical window coordinates to the coordinates of `rect`, when the clip was
+    /// applied on rotated items
+    pub from_window: Option<ItemTransform>,
+}
+
+impl RoundedClip {
+    /// Returns true if the given rectangle, in logical window coordinates, overlaps with one of
+    /// the rounded corners of the clip, and must therefore be drawn with a mask.
+    pub fn touches_corners(&self, rect: &LogicalRect) -> bool {
+        if self.from_window.is_some() {
+            return true;
+        }
+        let r = self.radius();
+        let c = &self.rect;
+        [
+            LogicalRect::new(c.origin, (r.top_left, r.top_left).into()),
+            LogicalRect::new(
+                LogicalPoint::new(c.max_x() - r.top_right, c.min_y()),
+                (r.top_right, r.top_right).into(),
+            ),
+            LogicalRect::new(
+                LogicalPoint::new(c.max_x() - r.bottom_right, c.max_y() - r.bottom_right),
+                (r.bottom_right, r.bottom_right).into(),
+            ),
+            LogicalRect::new(
+                LogicalPoint::new(c.min_x(), c.max_y() - r.bottom_left),
+                (r.bottom_left, r.bottom_left).into(),
+            ),
+        ]
+        .iter()
+        .any(|corner| !corner.is_empty() && corner.intersects(rect))
+    }
+
+    /// The radius, reduced so that the corners don't overlap
+    fn radius(&self) -> LogicalBorderRadius {
+        let max = self.rect.width().min(self.rect.height()) / 2.;
+        let r = self.radius;
+        LogicalBorderRadius::new(
+            r.top_left.clamp(0., max),
+            r.top_right.clamp(0., max),
+            r.bottom_right.clamp(0., max),
+            r.bottom_left.clamp(0., max),
+        )
+    }
+
+    /// Returns the coverage of the clip (between 0 and 1) at the given point in logical window coordinates.
+    fn coverage(&self, point: LogicalPoint, radius: &LogicalBorderRadius, scale: f32) -> f32 {
+        let point = self.from_window.map_or(point, |t| t.transform_point(point));
+        rounded_rect_coverage(point, &self.rect, radius, scale)
+    }
+
+    /// Multiply the pixels of the premultiplied `buffer`, placed at `origin` in physical window
+    /// coordinates, with the coverage of the clip.
+    pub fn apply(
+        &self,
+        buffer: &mut SharedPixelBuffer<Rgba8Pixel>,
+        origin: PhysicalPoint,
+        scale_factor: ScaleFactor,
+    ) {
+        let width = buffer.width() as usize;
+        let radius = self.radius();
+        let scale = scale_factor.get();
+        for (i, pixel) in buffer.make_mut_slice().iter_mut().enumerate() {
+            if pixel.a == 0 {
+                continue;
+            }
+            let point = LogicalPoint::new(
+                (origin.x as f32 + (i % width) as f32 + 0.5) / scale,
+                (origin.y as f32 + (i / width) as f32 + 0.5) / scale,
+            );
+            let coverage = self.coverage(point, &radius, scale);
+            if coverage < 1. {
+                let mul = |c: u8| (c as f32 * coverage + 0.5) as u8;
+                *pixel = Rgba8Pixel {
+                    r: mul(pixel.r),
+                    g: mul(pixel.g),
+                    b: mul(pixel.b),
+                    a: mul(pixel.a),
+                };
+            }
+        }
+    }
+}
+
+/// Returns the coverage (between 0 and 1) of the rounded rectangle at the given point, with an
+/// anti-aliasing of one pixel, `scale` being the number of pixels per unit.
+/// The radius must not exceed half the size of the rectangle.
+pub fn rounded_rect_coverage<U>(
+    point: euclid::Point2D<f32, U>,
+    rect: &euclid::Rect<f32, U>,
+    radius: &BorderRadius<f32, U>,
+    scale: f32,
+) -> f32 {
+    let center = rect.center();
+    let half_width = rect.width() / 2.;
+    let half_height = rect.height() / 2.;
+    let r = match (point.x < center.x, point.y < center.y) {
+        (true, true) => radius.top_left,
+        (false, true) => radius.top_right,
+        (false, false) => radius.bottom_right,
+        (true, false) => radius.bottom_left,
+    };
+    // Signed distance to the rounded rectangle
+    let qx = (point.x - center.x).abs() - (half_width - r);
+    let qy = (point.y - center.y).abs() - (half_height - r);
+    let outside = (qx.max(0.) * qx.max(0.) + qy.max(0.) * qy.max(0.)).sqrt();
+    let distance = outside + qx.max(qy).min(0.) - r;
+    (0.5 - distance * scale).clamp(0., 1.)
+}
+
+#[test]
+fn rounded_clip_coverage() {
+    let clip = RoundedClip {
+        rect: LogicalRect::new(LogicalPoint::new(10., 10.), (20., 10.).into()),
+        radius: LogicalBorderRadius::new(5., 0., 20., 0.),
+        from_window: None,
+    };
+    let radius = clip.radius();
+    // The radius is limited to half the height
+    assert_eq!(radius.bottom_right, 5.);
+
+    let coverage = |x: f32, y: f32| clip.coverage(LogicalPoint::new(x, y), &radius, 1.);
+    assert_eq!(coverage(20., 15.), 1.);
+    assert_eq!(coverage(5., 15.), 0.);
+    // Top left corner is rounded, top right is not
+    assert_eq!(coverage(10.5, 10.5), 0.);
+    assert_eq!(coverage(29.5, 10.5), 1.);
+    // On the edge
+    assert_eq!(coverage(10., 15.), 0.5);
+
+    assert!(clip.touches_corners(&LogicalRect::new(LogicalPoint::new(0., 0.), (12., 12.).into())));
+    assert!(!clip.touches_corners(&LogicalRect::new(LogicalPoint::new(16., 10.), (8., 10.).into())));
+    // The top right corner is square
+    assert!(!clip.touches_corners(&LogicalRect::new(LogicalPoint::new(26., 8.), (8., 2.).into())));
+}
@@ -0,0 +1,110 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the code to map a rendered layer through an arbitrary affine transform.
+//! It is used to render rotated items.
+
+use super::{PhysicalRect, PremultipliedRgbaColor};
+use crate::graphics::{Rgba8Pixel, SharedPixelBuffer};
+use crate::lengths::PhysicalPx;
+#[allow(unused)]
+use num_traits::Float;
+
+pub type PhysicalTransform = euclid::Transform2D<f32, PhysicalPx, PhysicalPx>;
+
+/// Resample the `source` image (which is `width` pixels wide) into a buffer covering `target`.
+///
+/// `transform` maps the source pixel coordinates to the target coordinates. Every target pixel is
+/// mapped back into the source and bilinearly interpolated, so the edges are anti-aliased.
+/// The result is premultiplied.
+pub fn transform_layer(
+    source: &[PremultipliedRgbaColor],
+    width: usize,
+    transform: &PhysicalTransform,
+    target: PhysicalRect,
+) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
+    if width == 0 || target.is_empty() {
+        return None;
+    }
+    let height = source.len() / width;
+    let inverse = transform.inverse()?;
+    let mut buffer =
+        SharedPixelBuffer::<Rgba8Pixel>::new(target.width() as _, target.height() as _);
+    let target_width = target.width() as usize;
+    let pixel = |x: isize, y: isize| {
+        if x < 0 || y < 0 || x >= width as isize || y >= height as isize {
+            [0.; 4]
+        } else {
+            let p = source[y as usize * width + x as usize];
+            [p.red as f32, p.green as f32, p.blue as f32, p.alpha as f32]
+        }
+    };
+
+    for (i, dst) in buffer.make_mut_slice().iter_mut().enumerate() {
+        let x = target.min_x() as f32 + (i % target_width) as f32 + 0.5;
+        let y = target.min_y() as f32 + (i / target_width) as f32 + 0.5;
+        let src = inverse.transform_point(euclid::point2(x, y));
+        // Coordinates relative to the center of the top-left pixel of the 2x2 neighbourhood
+        let sx = src.x - 0.5;
+        let sy = src.y - 0.5;
+        if sx <= -1. || sy <= -1. || sx >= width as f32 || sy >= height as f32 {
+            continue;
+        }
+        let (x0, y0) = (sx.floor(), sy.floor());
+        let (fx, fy) = (sx - x0, sy - y0);
+        let (x0, y0) = (x0 as isize, y0 as isize);
+        let mut acc = [0.; 4];
+        for (px, py, weight) in [
+            (x0, y0, (1. - fx) * (1. - fy)),
+            (x0 + 1, y0, fx * (1. - fy)),
+            (x0, y0 + 1, (1. - fx) * fy),
+            (x0 + 1, y0 + 1, fx * fy),
+        ] {
+            if weight > 0. {
+                let p = pixel(px, py);
+                acc.iter_mut().zip(p).for_each(|(a, c)| *a += c * weight);
+            }
+        }
+        let [r, g, b, a] = acc.map(|c| (c + 0.5).clamp(0., 255.) as u8);
+        *dst = Rgba8Pixel { r, g, b, a };
+    }
+    Some(buffer)
+}
+
+#[test]
+fn transform_layer_rotation() {
+    let red = PremultipliedRgbaColor { red: 255, green: 0, blue: 0, alpha: 255 };
+    let blue = PremultipliedRgbaColor { red: 0, green: 0, blue: 255, alpha: 255 };
+    // A 4x2 image with the left half red and the right half blue
+    let source = [red, red, blue, blue, red, red, blue, blue];
+
+    // Identity keeps the pixels as they are
+    let buffer =
+        transform_layer(&source, 4, &PhysicalTransform::identity(), euclid::rect(0, 0, 4, 2))
+            .unwrap();
+    let colors = buffer.as_slice().iter().map(|p| (p.r, p.b, p.a)).collect::<alloc::vec::Vec<_>>();
+    assert_eq!(colors[..4], [(255, 0, 255), (255, 0, 255), (0, 255, 255), (0, 255, 255)]);
+
+    // Rotated by 90° around the origin, then moved back into the positive quadrant
+    let transform =
+        PhysicalTransform::rotation(euclid::Angle::frac_pi_2()).then_translate((2., 0.).into());
+    let buffer = transform_layer(&source, 4, &transform, euclid::rect(0, 0, 2, 4)).unwrap();
+    let at = |x: usize, y: usize| {
+        let p = buffer.as_slice()[y * 2 + x];
+        (p.r, p.b, p.a)
+    };
+    assert_eq!(at(0, 0), (255, 0, 255));
+    assert_eq!(at(1, 1), (255, 0, 255));
+    assert_eq!(at(0, 3), (0, 255, 255));
+    assert_eq!(at(1, 2), (0, 255, 255));
+
+    // Outside of the source is transparent
+    let buffer = transform_layer(
+        &source,
+        4,
+        &PhysicalTransform::translation(10., 10.),
+        euclid::rect(0, 0, 4, 4),
+    )
+    .unwrap();
+    assert!(buffer.as_slice().iter().all(|p| p.a == 0));
+}
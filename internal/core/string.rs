@@ -339,6 +339,95 @@ pub fn shared_string_from_number_precision(n: f64, precision: usize) -> SharedSt
     }
 }
 
+/// Returns the index, in characters (grapheme clusters), of the first occurrence of `needle` in `s`,
+/// or -1 if `needle` is not found.
+pub fn shared_string_find(s: &str, needle: &str) -> i32 {
+    match s.find(needle) {
+        Some(offset) => {
+            unicode_segmentation::UnicodeSegmentation::graphemes(&s[..offset], true).count() as i32
+        }
+        None => -1,
+    }
+}
+
+/// Returns the part of `s` starting at the character (grapheme cluster) `start` and containing
+/// at most `length` characters.
+pub fn shared_string_substring(s: &str, start: i32, length: i32) -> SharedString {
+    unicode_segmentation::UnicodeSegmentation::graphemes(s, true)
+        .skip(start.max(0) as usize)
+        .take(length.max(0) as usize)
+        .collect::<String>()
+        .into()
+}
+
+/// Replaces all occurrences of `from` in `s` by `to`.
+pub fn shared_string_replace(s: &str, from: &str, to: &str) -> SharedString {
+    if from.is_empty() {
+        s.into()
+    } else {
+        s.replace(from, to).into()
+    }
+}
+
+/// Splits `s` at every occurrence of `separator`.
+/// If the separator is empty, the string is split into its characters (grapheme clusters).
+pub fn shared_string_split(s: &str, separator: &str) -> SharedVector<SharedString> {
+    if separator.is_empty() {
+        unicode_segmentation::UnicodeSegmentation::graphemes(s, true).map(Into::into).collect()
+    } else {
+        s.split(separator).map(Into::into).collect()
+    }
+}
+
+/// Pads `s` with repetitions of `fill` until it is `length` characters (grapheme clusters) long.
+/// The padding is inserted at the start of the string if `at_start` is true, otherwise at the end.
+pub fn shared_string_pad(s: &str, length: i32, fill: &str, at_start: bool) -> SharedString {
+    let count = unicode_segmentation::UnicodeSegmentation::graphemes(s, true).count();
+    let missing = (length.max(0) as usize).saturating_sub(count);
+    if missing == 0 || fill.is_empty() {
+        return s.into();
+    }
+    let padding = unicode_segmentation::UnicodeSegmentation::graphemes(fill, true)
+        .cycle()
+        .take(missing)
+        .collect::<String>();
+    let mut result = SharedString::new();
+    if at_start {
+        result.push_str(&padding);
+        result.push_str(s);
+    } else {
+        result.push_str(s);
+        result.push_str(&padding);
+    }
+    result
+}
+
+#[test]
+fn string_manipulation() {
+    assert_eq!(shared_string_find("Hello World", "World"), 6);
+    assert_eq!(shared_string_find("👍🏼 ok", "ok"), 2);
+    assert_eq!(shared_string_find("Hello", "x"), -1);
+    assert_eq!(shared_string_find("Hello", ""), 0);
+
+    assert_eq!(shared_string_substring("Hello World", 6, 3), "Wor");
+    assert_eq!(shared_string_substring("Hello", 3, 100), "lo");
+    assert_eq!(shared_string_substring("Hello", -2, 2), "He");
+    assert_eq!(shared_string_substring("Hello", 10, 2), "");
+    assert_eq!(shared_string_substring("a👍🏼b", 1, 1), "👍🏼");
+
+    assert_eq!(shared_string_replace("a-b-c", "-", "+"), "a+b+c");
+    assert_eq!(shared_string_replace("abc", "", "+"), "abc");
+
+    assert_eq!(shared_string_split("a,b,,c", ",").as_slice(), ["a", "b", "", "c"]);
+    assert_eq!(shared_string_split("", ",").as_slice(), [""]);
+    assert_eq!(shared_string_split("a👍🏼", "").as_slice(), ["a", "👍🏼"]);
+
+    assert_eq!(shared_string_pad("42", 5, "0", true), "00042");
+    assert_eq!(shared_string_pad("42", 5, "ab", false), "42aba");
+    assert_eq!(shared_string_pad("Hello", 3, " ", true), "Hello");
+    assert_eq!(shared_string_pad("42", 5, "", true), "42");
+}
+
 #[test]
 fn simple_test() {
     use std::string::ToString;
@@ -667,6 +756,56 @@ fn test_slint_shared_string_to_uppercase() {
         }
         assert_eq!(out.as_str(), "HELLO");
     }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_shared_string_find(ss: &SharedString, needle: &SharedString) -> i32 {
+        shared_string_find(ss, needle)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_shared_string_substring(
+        out: &mut SharedString,
+        ss: &SharedString,
+        start: i32,
+        length: i32,
+    ) {
+        *out = shared_string_substring(ss, start, length);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_shared_string_replace(
+        out: &mut SharedString,
+        ss: &SharedString,
+        from: &SharedString,
+        to: &SharedString,
+    ) {
+        *out = shared_string_replace(ss, from, to);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_shared_string_trim(out: &mut SharedString, ss: &SharedString) {
+        *out = ss.trim().into();
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_shared_string_split(
+        out: &mut SharedVector<SharedString>,
+        ss: &SharedString,
+        separator: &SharedString,
+    ) {
+        *out = shared_string_split(ss, separator);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_shared_string_pad(
+        out: &mut SharedString,
+        ss: &SharedString,
+        length: i32,
+        fill: &SharedString,
+        at_start: bool,
+    ) {
+        *out = shared_string_pad(ss, length, fill, at_start);
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -37,8 +37,11 @@
 #[cfg(not(feature = "unicode-linebreak"))]
 use linebreak_simple::{BreakOpportunity, LineBreakIterator};
 
+mod bidi;
+pub use bidi::is_rtl_paragraph_at;
 mod fragments;
 mod glyphclusters;
+mod hyphenation;
 mod shaping;
 use shaping::ShapeBuffer;
 pub use shaping::{AbstractFont, FontMetrics, Glyph, TextShaper};
@@ -77,6 +80,95 @@ pub fn text_size(
 
         (max_line_width, self.font.height() * line_count.into())
     }
+
+    // Like `text_size`, but the given spans of the text are shaped with their own font.
+    // The height of each line is determined by the tallest font used in the line.
+    pub fn styled_text_size(
+        &self,
+        spans: &[TextSpan<'_, Font>],
+        text: &str,
+        max_width: Option<Font::Length>,
+        text_wrap: TextWrap,
+    ) -> (Font::Length, Font::Length) {
+        let mut max_line_width = Font::Length::zero();
+        let mut height = Font::Length::zero();
+        let shape_buffer = ShapeBuffer::new_with_spans(self, spans, text);
+
+        for line in TextLineBreaker::<Font>::new(text, &shape_buffer, max_width, None, text_wrap) {
+            max_line_width = euclid::approxord::max(max_line_width, line.text_width);
+            height += line_metrics(self, spans, line.byte_range).0;
+        }
+
+        (max_line_width, height)
+    }
+}
+
+/// A range of the text that is shaped with a different font (or letter spacing) than
+/// the rest of the paragraph. Spans must be sorted and must not overlap.
+pub struct TextSpan<'a, Font: AbstractFont> {
+    pub byte_range: core::ops::Range<usize>,
+    pub layout: TextLayout<'a, Font>,
+}
+
+/// Returns the layout of the span that covers the given byte offset, or the paragraph's layout
+fn layout_at<'b, 'a, Font: AbstractFont>(
+    layout: &'b TextLayout<'a, Font>,
+    spans: &'b [TextSpan<'a, Font>],
+    byte_offset: usize,
+) -> &'b TextLayout<'a, Font> {
+    let index = spans.partition_point(|span| span.byte_range.end <= byte_offset);
+    match spans.get(index) {
+        Some(span) if span.byte_range.start <= byte_offset => &span.layout,
+        _ => layout,
+    }
+}
+
+/// Returns the height and the ascent of a line covering the given byte range, that is
+/// the metrics of the tallest of the fonts used in the line.
+fn line_metrics<Font: AbstractFont>(
+    layout: &TextLayout<'_, Font>,
+    spans: &[TextSpan<'_, Font>],
+    byte_range: core::ops::Range<usize>,
+) -> (Font::Length, Font::Length) {
+    if spans.is_empty() || byte_range.is_empty() {
+        let font = layout_at(layout, spans, byte_range.start).font;
+        return (font.height(), font.ascent());
+    }
+
+    fn add_font<'f, Font>(fonts: &mut Vec<&'f Font>, font: &'f Font) {
+        if !fonts.iter().any(|f| core::ptr::eq(*f, font)) {
+            fonts.push(font);
+        }
+    }
+    let mut fonts = Vec::new();
+    let mut covered_until = byte_range.start;
+    let first_span = spans.partition_point(|span| span.byte_range.end <= byte_range.start);
+    for span in spans[first_span..].iter().take_while(|span| span.byte_range.start < byte_range.end)
+    {
+        if span.byte_range.start > covered_until {
+            add_font(&mut fonts, layout.font);
+        }
+        add_font(&mut fonts, span.layout.font);
+        covered_until = span.byte_range.end;
+    }
+    if covered_until < byte_range.end {
+        add_font(&mut fonts, layout.font);
+    }
+
+    match fonts.as_slice() {
+        [font] => (font.height(), font.ascent()),
+        _ => {
+            let ascent = fonts
+                .iter()
+                .map(|font| font.ascent())
+                .fold(Font::Length::zero(), euclid::approxord::max);
+            let descent = fonts
+                .iter()
+                .map(|font| font.descent())
+                .fold(Font::Length::zero(), euclid::approxord::min);
+            (ascent - descent, ascent)
+        }
+    }
 }
 
 pub struct PositionedGlyph<Length> {
@@ -85,11 +177,15 @@ pub struct PositionedGlyph<Length> {
     pub advance: Length,
     pub glyph_id: core::num::NonZeroU16,
     pub text_byte_offset: usize,
+    /// True if the glyph is part of right-to-left text, so that its leading edge is on the right
+    pub is_rtl: bool,
 }
 
 pub struct TextParagraphLayout<'a, Font: AbstractFont> {
     pub string: &'a str,
     pub layout: TextLayout<'a, Font>,
+    /// Ranges of the string that are shaped with a different font than `layout`
+    pub spans: &'a [TextSpan<'a, Font>],
     pub max_width: Font::Length,
     pub max_height: Font::Length,
     pub horizontal_alignment: TextHorizontalAlignment,
@@ -99,9 +195,17 @@ pub struct TextParagraphLayout<'a, Font: AbstractFont> {
     pub single_line: bool,
 }
 
-impl<Font: AbstractFont> TextParagraphLayout<'_, Font> {
+impl<'a, Font: AbstractFont> TextParagraphLayout<'a, Font> {
+    /// Returns the layout used for the glyphs at the given byte offset. Renderers use this
+    /// to find the font of a positioned glyph.
+    pub fn layout_at(&self, byte_offset: usize) -> &TextLayout<'a, Font> {
+        layout_at(&self.layout, self.spans, byte_offset)
+    }
+
     /// Layout the given string in lines, and call the `layout_line` callback with the line to draw at position y.
     /// The signature of the `layout_line` function is: `(glyph_iterator, line_x, line_y, text_line, selection)`.
+    /// The glyphs are iterated in visual order, from left to right. The selection is a list of horizontal
+    /// ranges relative to `line_x`; there can be more than one when the line has bidirectional text.
     /// Returns the baseline y coordinate as Ok, or the break value if `line_callback` returns `core::ops::ControlFlow::Break`.
     pub fn layout_lines<R>(
         &self,
@@ -110,7 +214,7 @@ pub fn layout_lines<R>(
             Font::Length,
             Font::Length,
             &TextLine<Font::Length>,
-            Option<core::ops::Range<Font::Length>>,
+            &[core::ops::Range<Font::Length>],
         ) -> core::ops::ControlFlow<R>,
         selection: Option<core::ops::Range<usize>>,
     ) -> Result<Font::Length, R> {
@@ -124,7 +228,7 @@ pub fn layout_lines<R>(
         let elide_width = elide_glyph.as_ref().map_or(Font::Length::zero(), |g| g.advance);
         let max_width_without_elision = self.max_width - elide_width;
 
-        let shape_buffer = ShapeBuffer::new(&self.layout, self.string);
+        let shape_buffer = ShapeBuffer::new_with_spans(&self.layout, self.spans, self.string);
 
         let new_line_break_iter = || {
             TextLineBreaker::<Font>::new(
@@ -141,8 +245,16 @@ pub fn layout_lines<R>(
             if self.single_line {
                 self.layout.font.height()
             } else {
-                text_lines = Some(new_line_break_iter().collect::<Vec<_>>());
-                self.layout.font.height() * (text_lines.as_ref().unwrap().len() as i16).into()
+                let lines = new_line_break_iter().collect::<Vec<_>>();
+                let height = if self.spans.is_empty() {
+                    self.layout.font.height() * (lines.len() as i16).into()
+                } else {
+                    lines.iter().fold(Font::Length::zero(), |height, line| {
+                        height + line_metrics(&self.layout, self.spans, line.byte_range.clone()).0
+                    })
+                };
+                text_lines = Some(lines);
+                height
             }
         };
 
@@ -157,11 +269,16 @@ pub fn layout_lines<R>(
         let mut y = baseline_y;
 
         let mut process_line = |line: &TextLine<Font::Length>, glyphs: &[Glyph<Font::Length>]| {
+            let mut line = line.clone();
+            line.is_rtl_paragraph = shape_buffer.is_rtl_paragraph_at(line.byte_range.start);
+            (line.height, line.ascent) =
+                line_metrics(&self.layout, self.spans, line.byte_range.clone());
+            let line = &line;
             let elide_long_line =
                 elide && (self.single_line || !wrap) && line.text_width > self.max_width;
             let elide_last_line = elide
                 && line.glyph_range.end < glyphs.len()
-                && y + self.layout.font.height() * two > self.max_height;
+                && y + line.height * two > self.max_height;
 
             let text_width = || {
                 if elide_long_line || elide_last_line {
@@ -177,47 +294,127 @@ pub fn layout_lines<R>(
                 euclid::approxord::min(self.max_width, line.text_width)
             };
 
+            // The alignment is absolute: right-to-left paragraphs are aligned to the left too, unless
+            // the alignment says otherwise, as the default `left` can't be told apart from an explicit one
             let x = match self.horizontal_alignment {
-                TextHorizontalAlignment::Left => Font::Length::zero(),
+                TextHorizontalAlignment::Left | TextHorizontalAlignment::Justify => {
+                    Font::Length::zero()
+                }
                 TextHorizontalAlignment::Center => self.max_width / two - text_width() / two,
                 TextHorizontalAlignment::Right => self.max_width - text_width(),
             };
 
             let mut elide_glyph = elide_glyph.as_ref();
 
-            let selection = selection
-                .as_ref()
-                .filter(|selection| {
-                    line.byte_range.start < selection.end && selection.start < line.byte_range.end
+            let line_glyphs = &glyphs[line.glyph_range.clone()];
+
+            // Justified lines stretch the white space between words, except for the last line of a paragraph
+            let is_word_gap = |glyph: &Glyph<Font::Length>| {
+                glyph.text_byte_offset < line.byte_range.end
+                    && self.string[glyph.text_byte_offset..].starts_with(char::is_whitespace)
+            };
+            let mut gap_stretch = Font::Length::zero();
+            if self.horizontal_alignment == TextHorizontalAlignment::Justify
+                && line.wrapped
+                && !elide_long_line
+                && !elide_last_line
+                && line.text_width < self.max_width
+            {
+                let gaps = line_glyphs.iter().filter(|glyph| is_word_gap(glyph)).count();
+                if gaps > 0 {
+                    gap_stretch = (self.max_width - line.text_width)
+                        / Font::LengthPrimitive::from(gaps as i16);
+                }
+            }
+            let stretched_advance = |glyph: &Glyph<Font::Length>| {
+                if is_word_gap(glyph) {
+                    glyph.advance + gap_stretch
+                } else {
+                    glyph.advance
+                }
+            };
+
+            // Lines broken within a word end with a hyphen, drawn with the font of the last glyph
+            let hyphen_glyph = if line.hyphenated
+                && !line.is_rtl_paragraph
+                && !elide_long_line
+                && !elide_last_line
+            {
+                line_glyphs.iter().map(|glyph| glyph.text_byte_offset).max().and_then(|offset| {
+                    let glyph = self.layout_at(offset).font.glyph_for_char('-')?;
+                    Some(PositionedGlyph {
+                        x: Font::Length::zero(),
+                        y: Font::Length::zero(),
+                        advance: glyph.advance,
+                        glyph_id: glyph.glyph_id?,
+                        text_byte_offset: offset,
+                        is_rtl: false,
+                    })
                 })
-                .map(|selection| {
-                    let mut begin = Font::Length::zero();
-                    let mut end = Font::Length::zero();
-                    for glyph in glyphs[line.glyph_range.clone()].iter() {
-                        if glyph.text_byte_offset < selection.start {
-                            begin += glyph.advance;
-                        }
-                        if glyph.text_byte_offset >= selection.end {
-                            break;
+            } else {
+                None
+            };
+            let levels = shape_buffer
+                .glyph_levels(line.glyph_range.clone(), line.byte_range.end)
+                .unwrap_or_default();
+            let visual_order = bidi::visual_order(&levels);
+            // Returns the glyph at the given visual index and whether it is right-to-left
+            let visual_glyph = |index: usize| match visual_order.get(index) {
+                Some(&logical) => (&line_glyphs[logical], bidi::is_rtl(levels[logical])),
+                None => (&line_glyphs[index], false),
+            };
+
+            let mut selection_ranges: Vec<core::ops::Range<Font::Length>> = Vec::new();
+            if let Some(selection) = selection.as_ref().filter(|selection| {
+                line.byte_range.start < selection.end && selection.start < line.byte_range.end
+            }) {
+                let mut glyph_x = Font::Length::zero();
+                let mut previous_selected = false;
+                for index in 0..line_glyphs.len() {
+                    let (glyph, _) = visual_glyph(index);
+                    let selected = selection.contains(&glyph.text_byte_offset);
+                    let advance = stretched_advance(glyph);
+                    if selected {
+                        match selection_ranges.last_mut() {
+                            Some(range) if previous_selected => range.end += advance,
+                            _ => selection_ranges.push(glyph_x..glyph_x + advance),
                         }
-                        end += glyph.advance;
                     }
-                    begin..end
-                });
+                    previous_selected = selected;
+                    glyph_x += advance;
+                }
+            }
 
-            let glyph_it = glyphs[line.glyph_range.clone()].iter();
             let mut glyph_x = Font::Length::zero();
-            let mut positioned_glyph_it = glyph_it.enumerate().filter_map(|(index, glyph)| {
+            let mut hyphen_glyph = hyphen_glyph;
+            let glyph_count = line_glyphs.len() + usize::from(hyphen_glyph.is_some());
+            let mut positioned_glyph_it = (0..glyph_count).filter_map(|index| {
+                if index == line_glyphs.len() {
+                    return hyphen_glyph
+                        .take()
+                        .filter(|_| glyph_x <= self.max_width)
+                        .map(|hyphen| PositionedGlyph { x: glyph_x, ..hyphen });
+                }
+                let (glyph, is_rtl) = visual_glyph(index);
                 // TODO: cut off at grapheme boundaries
                 if glyph_x > self.max_width {
                     return None;
                 }
                 let elide_long_line = (elide_long_line || elide_last_line)
                     && x + glyph_x + glyph.advance > max_width_without_elision;
-                let elide_last_line =
-                    elide_last_line && line.glyph_range.start + index == line.glyph_range.end - 1;
+                let elide_last_line = elide_last_line && index == line_glyphs.len() - 1;
                 if elide_long_line || elide_last_line {
                     if let Some(elide_glyph) = elide_glyph.take() {
+                        // The renderer draws the ellipsis with the font of the glyph it replaces
+                        let span_elide_glyph = if self.spans.is_empty() {
+                            None
+                        } else {
+                            self.layout_at(glyph.text_byte_offset)
+                                .font
+                                .glyph_for_char('…')
+                                .filter(|glyph| glyph.glyph_id.is_some())
+                        };
+                        let elide_glyph = span_elide_glyph.as_ref().unwrap_or(elide_glyph);
                         let x = glyph_x;
                         glyph_x += elide_glyph.advance;
                         return Some(PositionedGlyph {
@@ -226,29 +423,32 @@ pub fn layout_lines<R>(
                             advance: elide_glyph.advance,
                             glyph_id: elide_glyph.glyph_id.unwrap(), // checked earlier when initializing elide_glyph
                             text_byte_offset: glyph.text_byte_offset,
+                            is_rtl,
                         });
                     } else {
                         return None;
                     }
                 }
                 let x = glyph_x;
-                glyph_x += glyph.advance;
+                let advance = stretched_advance(glyph);
+                glyph_x += advance;
 
                 glyph.glyph_id.map(|existing_glyph_id| PositionedGlyph {
                     x,
                     y: Font::Length::zero(),
-                    advance: glyph.advance,
+                    advance,
                     glyph_id: existing_glyph_id,
                     text_byte_offset: glyph.text_byte_offset,
+                    is_rtl,
                 })
             });
 
             if let core::ops::ControlFlow::Break(break_val) =
-                line_callback(&mut positioned_glyph_it, x, y, line, selection)
+                line_callback(&mut positioned_glyph_it, x, y, line, &selection_ranges)
             {
                 return core::ops::ControlFlow::Break(break_val);
             }
-            y += self.layout.font.height();
+            y += line.height;
 
             core::ops::ControlFlow::Continue(())
         };
@@ -274,36 +474,48 @@ pub fn layout_lines<R>(
         Ok(baseline_y)
     }
 
-    /// Returns the leading edge of the glyph at the given byte offset
+    /// Returns the leading edge of the glyph at the given byte offset.
+    /// That is the left edge for left-to-right text and the right edge for right-to-left text.
     pub fn cursor_pos_for_byte_offset(&self, byte_offset: usize) -> (Font::Length, Font::Length) {
-        let mut last_glyph_right_edge = Font::Length::zero();
+        let mut line_end_x = Font::Length::zero();
         let mut last_line_y = Font::Length::zero();
 
         match self.layout_lines(
             |glyphs, line_x, line_y, line, _| {
-                last_glyph_right_edge = euclid::approxord::min(
-                    self.max_width,
-                    line_x + line.width_including_trailing_whitespace(),
-                );
+                // The end of a line is on the left for right-to-left paragraphs
+                line_end_x = if line.is_rtl_paragraph {
+                    line_x
+                } else {
+                    euclid::approxord::min(
+                        self.max_width,
+                        line_x + line.width_including_trailing_whitespace(),
+                    )
+                };
                 last_line_y = line_y;
                 if byte_offset >= line.byte_range.end + line.trailing_whitespace_bytes {
                     return core::ops::ControlFlow::Continue(());
                 }
 
+                let mut leading_edge = None;
                 for positioned_glyph in glyphs {
                     if positioned_glyph.text_byte_offset == byte_offset {
-                        return core::ops::ControlFlow::Break((
-                            euclid::approxord::min(self.max_width, line_x + positioned_glyph.x),
-                            last_line_y,
-                        ));
+                        if !positioned_glyph.is_rtl {
+                            leading_edge = Some(positioned_glyph.x);
+                            break;
+                        }
+                        // The glyphs of a right-to-left cluster are visually reversed, keep the rightmost edge
+                        leading_edge = Some(positioned_glyph.x + positioned_glyph.advance);
                     }
                 }
 
-                core::ops::ControlFlow::Break((last_glyph_right_edge, last_line_y))
+                core::ops::ControlFlow::Break(match leading_edge {
+                    Some(x) => (euclid::approxord::min(self.max_width, line_x + x), last_line_y),
+                    None => (line_end_x, last_line_y),
+                })
             },
             None,
         ) {
-            Ok(_) => (last_glyph_right_edge, last_line_y),
+            Ok(_) => (line_end_x, last_line_y),
             Err(position) => position,
         }
     }
@@ -315,7 +527,7 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
 
         match self.layout_lines(
             |glyphs, line_x, line_y, line, _| {
-                if pos_y >= line_y + self.layout.font.height() {
+                if pos_y >= line_y + line.height {
                     byte_offset = line.byte_range.end;
                     return core::ops::ControlFlow::Continue(());
                 }
@@ -324,21 +536,37 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
                     return core::ops::ControlFlow::Break(line.byte_range.start);
                 }
 
-                while let Some(positioned_glyph) = glyphs.next() {
+                let glyphs = glyphs.collect::<Vec<_>>();
+                for positioned_glyph in &glyphs {
                     if pos_x >= line_x + positioned_glyph.x
                         && pos_x <= line_x + positioned_glyph.x + positioned_glyph.advance
                     {
-                        if pos_x < line_x + positioned_glyph.x + positioned_glyph.advance / two {
+                        let on_leading_half = (pos_x
+                            < line_x + positioned_glyph.x + positioned_glyph.advance / two)
+                            != positioned_glyph.is_rtl;
+                        if on_leading_half {
                             return core::ops::ControlFlow::Break(
                                 positioned_glyph.text_byte_offset,
                             );
-                        } else if let Some(next_glyph) = glyphs.next() {
-                            return core::ops::ControlFlow::Break(next_glyph.text_byte_offset);
                         }
+                        // The position after the glyph is the start of the next cluster in logical order
+                        let next_offset = glyphs
+                            .iter()
+                            .map(|glyph| glyph.text_byte_offset)
+                            .filter(|offset| *offset > positioned_glyph.text_byte_offset)
+                            .min();
+                        return core::ops::ControlFlow::Break(
+                            next_offset.unwrap_or(line.byte_range.end),
+                        );
                     }
                 }
 
-                core::ops::ControlFlow::Break(line.byte_range.end)
+                let before_line = glyphs.first().is_some_and(|glyph| pos_x < line_x + glyph.x);
+                core::ops::ControlFlow::Break(if before_line != line.is_rtl_paragraph {
+                    line.byte_range.start
+                } else {
+                    line.byte_range.end
+                })
             },
             None,
         ) {
@@ -346,6 +574,33 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
             Err(position) => position,
         }
     }
+
+    /// Returns the byte offset of the glyph that covers the given position, or None if there
+    /// is no glyph at that position.
+    pub fn glyph_byte_offset_at_position(
+        &self,
+        (pos_x, pos_y): (Font::Length, Font::Length),
+    ) -> Option<usize> {
+        self.layout_lines(
+            |glyphs, line_x, line_y, line, _| {
+                if pos_y < line_y {
+                    return core::ops::ControlFlow::Break(None);
+                }
+                if pos_y >= line_y + line.height {
+                    return core::ops::ControlFlow::Continue(());
+                }
+                for glyph in glyphs {
+                    if pos_x >= line_x + glyph.x && pos_x < line_x + glyph.x + glyph.advance {
+                        return core::ops::ControlFlow::Break(Some(glyph.text_byte_offset));
+                    }
+                }
+                core::ops::ControlFlow::Break(None)
+            },
+            None,
+        )
+        .err()
+        .flatten()
+    }
 }
 
 #[test]
@@ -432,6 +687,7 @@ fn test_elision() {
     let paragraph = TextParagraphLayout {
         string: text,
         layout: TextLayout { font: &font, letter_spacing: None },
+        spans: &[],
         max_width: 13. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -474,6 +730,7 @@ fn test_exact_fit() {
     let paragraph = TextParagraphLayout {
         string: text,
         layout: TextLayout { font: &font, letter_spacing: None },
+        spans: &[],
         max_width: 4. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -516,6 +773,7 @@ fn test_no_line_separators_characters_rendered() {
     let paragraph = TextParagraphLayout {
         string: text,
         layout: TextLayout { font: &font, letter_spacing: None },
+        spans: &[],
         max_width: 13. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -553,6 +811,73 @@ fn test_no_line_separators_characters_rendered() {
     debug_assert_eq!(rendered_text, std::vec!["Hello", "World"]);
 }
 
+#[test]
+fn test_justify() {
+    let font = FixedTestFont;
+    let text = "ab cd ef gh";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None },
+        spans: &[],
+        max_width: 70.,
+        max_height: 100.,
+        horizontal_alignment: TextHorizontalAlignment::Justify,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+    };
+    let mut lines = Vec::new();
+    paragraph
+        .layout_lines::<()>(
+            |glyphs, line_x, _, _, _| {
+                lines.push(glyphs.map(|glyph| line_x + glyph.x).collect::<Vec<_>>());
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+
+    // The space in the first line is stretched, the last line is aligned to the left
+    assert_eq!(lines, [std::vec![0., 10., 20., 50., 60., 70.], std::vec![0., 10., 20., 30., 40.]]);
+}
+
+#[test]
+fn test_soft_hyphen_rendering() {
+    let font = FixedTestFont;
+    let text = "Won\u{ad}der";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None },
+        spans: &[],
+        max_width: 50.,
+        max_height: 100.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+    };
+    let mut lines = Vec::new();
+    paragraph
+        .layout_lines::<()>(
+            |glyphs, _, _, _, _| {
+                lines.push(
+                    glyphs
+                        .map(|glyph| char::from_u32(glyph.glyph_id.get() as u32).unwrap())
+                        .collect::<std::string::String>(),
+                );
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(lines, ["Won-", "der"]);
+}
+
 #[test]
 fn test_cursor_position() {
     let font = FixedTestFont;
@@ -561,6 +886,7 @@ fn test_cursor_position() {
     let paragraph = TextParagraphLayout {
         string: text,
         layout: TextLayout { font: &font, letter_spacing: None },
+        spans: &[],
         max_width: 10. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -601,6 +927,7 @@ fn test_cursor_position_with_newline() {
     let paragraph = TextParagraphLayout {
         string: text,
         layout: TextLayout { font: &font, letter_spacing: None },
+        spans: &[],
         max_width: 100. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -621,6 +948,7 @@ fn byte_offset_for_empty_line() {
     let paragraph = TextParagraphLayout {
         string: text,
         layout: TextLayout { font: &font, letter_spacing: None },
+        spans: &[],
         max_width: 100. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -643,6 +971,7 @@ fn test_byte_offset() {
     let paragraph = TextParagraphLayout {
         string: text,
         layout: TextLayout { font: &font, letter_spacing: None },
+        spans: &[],
         max_width: 10. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -699,3 +1028,82 @@ fn test_byte_offset() {
     assert_eq!(paragraph.byte_offset_for_position((45., 10.)), end_offset);
     assert_eq!(paragraph.byte_offset_for_position((0., 20.)), end_offset);
 }
+
+#[test]
+#[cfg_attr(
+    not(feature = "unicode-bidi"),
+    ignore = "Not supported without the unicode-bidi feature"
+)]
+fn test_bidi_cursor_position_and_byte_offset() {
+    let font = FixedTestFont;
+    let paragraph = |text| TextParagraphLayout {
+        string: text,
+        layout: TextLayout { font: &font, letter_spacing: None },
+        spans: &[],
+        max_width: 10. * 10.,
+        max_height: 10.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+    };
+
+    // Left-to-right paragraph, displayed as "ab גבא"
+    let text = "ab אבג";
+    let alef = text.find('א').unwrap();
+    let bet = text.find('ב').unwrap();
+    let gimel = text.find('ג').unwrap();
+    let paragraph_ltr = paragraph(text);
+    let mut glyph_offsets = std::vec::Vec::new();
+    paragraph_ltr
+        .layout_lines::<()>(
+            |glyphs, _, _, _, _| {
+                glyph_offsets.extend(glyphs.map(|g| (g.x, g.text_byte_offset, g.is_rtl)));
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        glyph_offsets,
+        std::vec![
+            (0., 0, false),
+            (10., 1, false),
+            (20., 2, false),
+            (30., gimel, true),
+            (40., bet, true),
+            (50., alef, true)
+        ]
+    );
+    assert_eq!(paragraph_ltr.cursor_pos_for_byte_offset(alef), (60., 0.));
+    assert_eq!(paragraph_ltr.cursor_pos_for_byte_offset(gimel), (40., 0.));
+    assert_eq!(paragraph_ltr.cursor_pos_for_byte_offset(text.len()), (60., 0.));
+    assert_eq!(paragraph_ltr.byte_offset_for_position((58., 0.)), alef);
+    assert_eq!(paragraph_ltr.byte_offset_for_position((52., 0.)), bet);
+    assert_eq!(paragraph_ltr.byte_offset_for_position((32., 0.)), text.len());
+
+    // Right-to-left paragraph, displayed as "cd בא"
+    let text = "אב cd";
+    let bet = text.find('ב').unwrap();
+    let c_offset = text.find('c').unwrap();
+    let paragraph_rtl = paragraph(text);
+    assert_eq!(paragraph_rtl.cursor_pos_for_byte_offset(0), (50., 0.));
+    assert_eq!(paragraph_rtl.cursor_pos_for_byte_offset(c_offset), (0., 0.));
+    assert_eq!(paragraph_rtl.cursor_pos_for_byte_offset(text.len()), (0., 0.));
+    assert_eq!(paragraph_rtl.byte_offset_for_position((48., 0.)), 0);
+    assert_eq!(paragraph_rtl.byte_offset_for_position((1., 0.)), c_offset);
+    assert_eq!(paragraph_rtl.byte_offset_for_position((70., 0.)), 0);
+
+    let mut selections = std::vec::Vec::new();
+    paragraph_rtl
+        .layout_lines::<()>(
+            |_, _, _, _, selection| {
+                selections.extend_from_slice(selection);
+                core::ops::ControlFlow::Continue(())
+            },
+            Some(bet..c_offset + 1),
+        )
+        .unwrap();
+    assert_eq!(selections, std::vec![0. ..10., 20. ..40.]);
+}
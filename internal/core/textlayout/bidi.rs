@@ -0,0 +1,154 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Support for bidirectional text, following the Unicode Bidirectional Algorithm (UAX #9).
+//!
+//! The embedding levels are resolved on the whole text, which is then shaped and broken into lines in
+//! logical order. Each line is reordered for display with [`visual_order`].
+//! Without the `unicode-bidi` feature, all the text is left-to-right.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// A range of text in which all the characters have the same embedding level, within one paragraph
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelRun {
+    pub byte_range: Range<usize>,
+    /// The embedding level of the characters. Odd levels are right-to-left.
+    pub level: u8,
+    /// The embedding level of the paragraph, used for the trailing whitespace of lines.
+    pub paragraph_level: u8,
+}
+
+pub fn is_rtl(level: u8) -> bool {
+    level % 2 == 1
+}
+
+/// Splits the text in runs of characters that have the same embedding level
+pub fn level_runs(text: &str) -> Vec<LevelRun> {
+    #[cfg(feature = "unicode-bidi")]
+    {
+        let info = unicode_bidi::BidiInfo::new(text, None);
+        let mut runs = Vec::new();
+        for paragraph in &info.paragraphs {
+            let paragraph_level = paragraph.level.number();
+            let mut start = paragraph.range.start;
+            for (offset, _) in text[paragraph.range.clone()].char_indices().skip(1) {
+                let offset = paragraph.range.start + offset;
+                if info.levels[offset] != info.levels[start] {
+                    let level = info.levels[start].number();
+                    runs.push(LevelRun { byte_range: start..offset, level, paragraph_level });
+                    start = offset;
+                }
+            }
+            if start < paragraph.range.end {
+                let level = info.levels[start].number();
+                runs.push(LevelRun {
+                    byte_range: start..paragraph.range.end,
+                    level,
+                    paragraph_level,
+                });
+            }
+        }
+        runs
+    }
+    #[cfg(not(feature = "unicode-bidi"))]
+    {
+        if text.is_empty() {
+            Vec::new()
+        } else {
+            alloc::vec![LevelRun { byte_range: 0..text.len(), level: 0, paragraph_level: 0 }]
+        }
+    }
+}
+
+/// Returns the indices of `levels` in the order in which they are displayed from left to right.
+///
+/// This is rule L2 of UAX #9: from the highest level down to the lowest odd level, every sequence
+/// of items at that level or higher is reversed.
+pub fn visual_order(levels: &[u8]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..levels.len()).collect();
+    let Some(max_level) = levels.iter().copied().max() else { return order };
+    let Some(min_odd_level) = levels.iter().copied().filter(|l| is_rtl(*l)).min() else {
+        return order;
+    };
+    for level in (min_odd_level..=max_level).rev() {
+        let mut i = 0;
+        while i < order.len() {
+            if levels[order[i]] < level {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < order.len() && levels[order[i]] >= level {
+                i += 1;
+            }
+            order[start..i].reverse();
+        }
+    }
+    order
+}
+
+/// Returns true if the paragraph that contains the given byte offset has a right-to-left base
+/// direction, determined by its first strong character
+pub fn is_rtl_paragraph_at(text: &str, byte_offset: usize) -> bool {
+    #[cfg(feature = "unicode-bidi")]
+    {
+        let is_separator = |c: char| matches!(c, '\n' | '\r' | '\u{85}' | '\u{2029}');
+        let byte_offset = byte_offset.min(text.len());
+        let start = text[..byte_offset]
+            .char_indices()
+            .rev()
+            .find(|(_, c)| is_separator(*c))
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        let end = text[byte_offset..].find(is_separator).map_or(text.len(), |i| byte_offset + i);
+        unicode_bidi::get_base_direction(&text[start..end]) == unicode_bidi::Direction::Rtl
+    }
+    #[cfg(not(feature = "unicode-bidi"))]
+    {
+        let _ = (text, byte_offset);
+        false
+    }
+}
+
+#[test]
+fn test_visual_order() {
+    assert_eq!(visual_order(&[]), std::vec::Vec::<usize>::new());
+    assert_eq!(visual_order(&[0, 0, 0]), std::vec![0, 1, 2]);
+    assert_eq!(visual_order(&[1, 1, 1]), std::vec![2, 1, 0]);
+    assert_eq!(visual_order(&[0, 1, 1, 0]), std::vec![0, 2, 1, 3]);
+    // An English word with digits inside a right-to-left paragraph
+    assert_eq!(visual_order(&[1, 1, 2, 2, 1]), std::vec![4, 2, 3, 1, 0]);
+}
+
+#[test]
+#[cfg_attr(
+    not(feature = "unicode-bidi"),
+    ignore = "Not supported without the unicode-bidi feature"
+)]
+fn test_level_runs() {
+    let text = "abc אבג def";
+    let runs = level_runs(text);
+    let alef = text.find('א').unwrap();
+    let space_after_gimel = text.rfind(' ').unwrap();
+    assert_eq!(
+        runs,
+        std::vec![
+            LevelRun { byte_range: 0..alef, level: 0, paragraph_level: 0 },
+            LevelRun { byte_range: alef..space_after_gimel, level: 1, paragraph_level: 0 },
+            LevelRun { byte_range: space_after_gimel..text.len(), level: 0, paragraph_level: 0 },
+        ]
+    );
+
+    let text = "אבג 12\nabc";
+    let runs = level_runs(text);
+    assert_eq!(runs.len(), 4);
+    assert_eq!(runs[0], LevelRun { byte_range: 0..7, level: 1, paragraph_level: 1 });
+    assert_eq!(runs[1], LevelRun { byte_range: 7..9, level: 2, paragraph_level: 1 });
+    assert_eq!(runs[2], LevelRun { byte_range: 9..10, level: 1, paragraph_level: 1 });
+    assert_eq!(runs[3], LevelRun { byte_range: 10..13, level: 0, paragraph_level: 0 });
+
+    assert!(is_rtl_paragraph_at(text, 2));
+    assert!(!is_rtl_paragraph_at(text, 11));
+    assert!(!is_rtl_paragraph_at("abc", 0));
+}
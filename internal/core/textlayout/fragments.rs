@@ -6,7 +6,9 @@
 use euclid::num::Zero;
 
 use super::glyphclusters::GlyphClusterIterator;
+use super::hyphenation::{hyphenation_points, SOFT_HYPHEN};
 use super::{BreakOpportunity, LineBreakIterator, ShapeBuffer};
+use crate::SharedVector;
 
 #[derive(Debug, PartialEq, Eq, Default)]
 pub struct TextFragment<Length> {
@@ -16,13 +18,23 @@ pub struct TextFragment<Length> {
     pub trailing_whitespace_width: Length,
     pub trailing_whitespace_bytes: usize,
     pub trailing_mandatory_break: bool,
+    /// True if the fragment ends within a word, after a soft hyphen or at a hyphenation point,
+    /// so that a hyphen is drawn if the line is broken after it
+    pub hyphenated: bool,
 }
 
 #[derive(Clone)]
 pub struct TextFragmentIterator<'a, Length> {
     line_breaks: LineBreakIterator<'a>,
     glyph_clusters: GlyphClusterIterator<'a, Length>,
-    text_len: usize,
+    text: &'a str,
+    /// The hyphenation points that were not reached yet, empty unless hyphenation is enabled
+    hyphenation_points: SharedVector<usize>,
+    next_hyphenation_point: usize,
+    /// A break opportunity that comes after a hyphenation point, to be used by the next fragment
+    pending_break: Option<(usize, BreakOpportunity)>,
+    /// True if hyphenation is enabled, so that words broken anywhere also get a hyphen
+    hyphenate: bool,
     pub break_anywhere: bool,
 }
 
@@ -31,10 +43,21 @@ pub fn new(text: &'a str, shape_buffer: &'a ShapeBuffer<Length>) -> Self {
         Self {
             line_breaks: LineBreakIterator::new(text),
             glyph_clusters: GlyphClusterIterator::new(text, shape_buffer),
-            text_len: text.len(),
+            text,
+            hyphenation_points: SharedVector::default(),
+            next_hyphenation_point: 0,
+            pending_break: None,
+            hyphenate: false,
             break_anywhere: false,
         }
     }
+
+    /// Also break words at the hyphenation points found by [`hyphenation_points`]
+    pub fn with_hyphenation(mut self) -> Self {
+        self.hyphenation_points = hyphenation_points(self.text).into_iter().collect();
+        self.hyphenate = true;
+        self
+    }
 }
 
 impl<Length: Clone + Default + core::ops::AddAssign + Zero + Copy> Iterator
@@ -52,14 +75,44 @@ fn next(&mut self) -> Option<Self::Item> {
                 fragment.trailing_mandatory_break = true;
             }
             0
-        } else if let Some((next_break_offset, break_type)) = self.line_breaks.next() {
-            if matches!(break_type, BreakOpportunity::Mandatory) {
-                fragment.trailing_mandatory_break = true;
-            }
-            next_break_offset
         } else {
-            self.text_len
+            let next_break = self.pending_break.take().or_else(|| self.line_breaks.next());
+            let fragment_start = first_glyph_cluster.byte_range.start;
+            while self
+                .hyphenation_points
+                .get(self.next_hyphenation_point)
+                .is_some_and(|point| *point <= fragment_start)
+            {
+                self.next_hyphenation_point += 1;
+            }
+            let hyphenation_point = self.hyphenation_points.get(self.next_hyphenation_point);
+            match (next_break, hyphenation_point) {
+                (next_break, Some(&point))
+                    if next_break
+                        .is_none_or(|(next_break_offset, _)| point < next_break_offset) =>
+                {
+                    self.pending_break = next_break;
+                    self.next_hyphenation_point += 1;
+                    fragment.hyphenated = true;
+                    point
+                }
+                (Some((next_break_offset, break_type)), _) => {
+                    if matches!(break_type, BreakOpportunity::Mandatory) {
+                        fragment.trailing_mandatory_break = true;
+                    }
+                    fragment.hyphenated = self.text[..next_break_offset].ends_with(SOFT_HYPHEN);
+                    next_break_offset
+                }
+                (None, _) => self.text.len(),
+            }
         };
+        fragment.hyphenated &= next_break_offset < self.text.len();
+        if self.break_anywhere && self.hyphenate {
+            // A word that is too long for the line is broken anywhere, and still gets a hyphen
+            let end = first_glyph_cluster.byte_range.end;
+            fragment.hyphenated = self.text[..end].ends_with(char::is_alphanumeric)
+                && self.text[end..].starts_with(char::is_alphanumeric);
+        }
 
         if first_glyph_cluster.is_whitespace {
             fragment.trailing_whitespace_width = first_glyph_cluster.width;
@@ -135,6 +188,7 @@ fn fragment_iterator_simple() {
             width: 10.,
             trailing_whitespace_width: 10.,
             trailing_mandatory_break: false,
+            hyphenated: false,
             trailing_whitespace_bytes: 1,
         },
         TextFragment {
@@ -143,6 +197,7 @@ fn fragment_iterator_simple() {
             width: 20.,
             trailing_whitespace_width: 0.,
             trailing_mandatory_break: false,
+            hyphenated: false,
             trailing_whitespace_bytes: 0,
         },
     ];
@@ -162,6 +217,7 @@ fn fragment_iterator_simple_v2() {
             width: 50.,
             trailing_whitespace_width: 10.,
             trailing_mandatory_break: false,
+            hyphenated: false,
             trailing_whitespace_bytes: 1,
         },
         TextFragment {
@@ -171,6 +227,7 @@ fn fragment_iterator_simple_v2() {
             trailing_whitespace_width: 0.,
             trailing_whitespace_bytes: 0,
             trailing_mandatory_break: false,
+            hyphenated: false,
         },
     ];
     assert_eq!(fragments, expected);
@@ -192,6 +249,7 @@ fn fragment_iterator_forced_break() {
                 trailing_whitespace_width: 0.,
                 trailing_whitespace_bytes: 1,
                 trailing_mandatory_break: true,
+                hyphenated: false,
             },
             TextFragment {
                 byte_range: Range { start: 2, end: 3 },
@@ -200,6 +258,7 @@ fn fragment_iterator_forced_break() {
                 trailing_whitespace_width: 0.,
                 trailing_whitespace_bytes: 0,
                 trailing_mandatory_break: false,
+                hyphenated: false,
             },
         ]
     );
@@ -221,6 +280,7 @@ fn fragment_iterator_forced_break_multi() {
                 trailing_whitespace_width: 0.,
                 trailing_whitespace_bytes: 1,
                 trailing_mandatory_break: true,
+                hyphenated: false,
             },
             TextFragment {
                 byte_range: Range { start: 2, end: 2 },
@@ -229,6 +289,7 @@ fn fragment_iterator_forced_break_multi() {
                 trailing_whitespace_width: 10.,
                 trailing_whitespace_bytes: 1,
                 trailing_mandatory_break: true,
+                hyphenated: false,
             },
             TextFragment {
                 byte_range: Range { start: 3, end: 3 },
@@ -237,6 +298,7 @@ fn fragment_iterator_forced_break_multi() {
                 trailing_whitespace_width: 10.,
                 trailing_whitespace_bytes: 1,
                 trailing_mandatory_break: true,
+                hyphenated: false,
             },
             TextFragment {
                 byte_range: Range { start: 4, end: 5 },
@@ -245,6 +307,7 @@ fn fragment_iterator_forced_break_multi() {
                 trailing_whitespace_width: 0.,
                 trailing_whitespace_bytes: 0,
                 trailing_mandatory_break: false,
+                hyphenated: false,
             },
         ]
     );
@@ -266,6 +329,7 @@ fn fragment_iterator_nbsp() {
                 trailing_whitespace_width: 10.,
                 trailing_whitespace_bytes: 1,
                 trailing_mandatory_break: false,
+                hyphenated: false,
             },
             TextFragment {
                 byte_range: Range { start: 2, end: 6 },
@@ -274,6 +338,7 @@ fn fragment_iterator_nbsp() {
                 trailing_whitespace_width: 0.,
                 trailing_whitespace_bytes: 0,
                 trailing_mandatory_break: false,
+                hyphenated: false,
             }
         ]
     );
@@ -294,6 +359,7 @@ fn fragment_iterator_break_anywhere() {
             trailing_whitespace_width: 0.,
             trailing_whitespace_bytes: 1,
             trailing_mandatory_break: true,
+            hyphenated: false,
         })
     );
     assert_eq!(
@@ -305,6 +371,7 @@ fn fragment_iterator_break_anywhere() {
             trailing_whitespace_width: 0.,
             trailing_whitespace_bytes: 1,
             trailing_mandatory_break: true,
+            hyphenated: false,
         },)
     );
     fragments.break_anywhere = true;
@@ -319,6 +386,7 @@ fn fragment_iterator_break_anywhere() {
                 trailing_whitespace_width: 0.,
                 trailing_whitespace_bytes: 0,
                 trailing_mandatory_break: false,
+                hyphenated: false,
             },
             TextFragment {
                 byte_range: Range { start: 7, end: 8 },
@@ -327,6 +395,7 @@ fn fragment_iterator_break_anywhere() {
                 trailing_whitespace_width: 0.,
                 trailing_whitespace_bytes: 0,
                 trailing_mandatory_break: false,
+                hyphenated: false,
             },
         ]
     );
@@ -348,6 +417,7 @@ fn fragment_iterator_leading_nbsp() {
                 trailing_whitespace_width: 0.,
                 trailing_whitespace_bytes: 1,
                 trailing_mandatory_break: true,
+                hyphenated: false,
             },
             TextFragment {
                 byte_range: Range { start: 2, end: 8 },
@@ -356,6 +426,7 @@ fn fragment_iterator_leading_nbsp() {
                 trailing_whitespace_width: 0.,
                 trailing_whitespace_bytes: 0,
                 trailing_mandatory_break: false,
+                hyphenated: false,
             }
         ]
     );
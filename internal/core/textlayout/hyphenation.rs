@@ -0,0 +1,105 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Dictionary-free hyphenation, used with `TextWrap::Hyphenate`.
+//!
+//! Words written in the latin script are split between syllables approximated with two rules:
+//! a consonant between two vowels starts a new syllable (`ho-tel`), and two consonants between
+//! vowels are split (`win-dow`). Common digraphs such as `ch` or `th` count as a single consonant
+//! (`ele-phant`).
+//! At least two letters are kept before and three letters after a hyphenation point.
+
+use alloc::vec::Vec;
+
+/// The soft hyphen, which marks a hyphenation point and is only visible when a line is broken there
+pub(crate) const SOFT_HYPHEN: char = '\u{ad}';
+
+const MIN_PREFIX: usize = 2;
+const MIN_SUFFIX: usize = 3;
+
+fn is_latin_letter(c: char) -> bool {
+    c.is_alphabetic() && (c as u32) < 0x250
+}
+
+fn is_vowel(c: char) -> bool {
+    c.to_lowercase().any(|c| "aeiouyàáâãäåæèéêëìíîïòóôõöøœùúûüý".contains(c))
+}
+
+fn is_digraph(first: char, second: char) -> bool {
+    const DIGRAPHS: [[char; 2]; 8] = [
+        ['c', 'h'],
+        ['c', 'k'],
+        ['g', 'h'],
+        ['p', 'h'],
+        ['q', 'u'],
+        ['s', 'h'],
+        ['t', 'h'],
+        ['w', 'h'],
+    ];
+    DIGRAPHS.contains(&[first.to_ascii_lowercase(), second.to_ascii_lowercase()])
+}
+
+/// Returns the byte offsets in `text`, in ascending order, at which a word can be hyphenated.
+pub fn hyphenation_points(text: &str) -> Vec<usize> {
+    let mut points = Vec::new();
+    let mut word: Vec<(usize, char)> = Vec::new();
+    let mut add_word_points = |word: &[(usize, char)]| {
+        if word.len() < MIN_PREFIX + MIN_SUFFIX {
+            return;
+        }
+        let letter = |index: usize| word.get(index).map(|(_, c)| *c);
+        let vowel_at = |index: usize| letter(index).is_some_and(is_vowel);
+        let consonant_at = |index: usize| letter(index).is_some_and(|c| !is_vowel(c));
+        let digraph_at = |index: usize| matches!((letter(index), letter(index + 1)), (Some(a), Some(b)) if is_digraph(a, b));
+        let candidates = word.iter().enumerate().take(word.len() + 1 - MIN_SUFFIX).skip(MIN_PREFIX);
+        for (index, (offset, _)) in candidates {
+            // A single consonant, or a digraph, between two vowels starts the next syllable
+            let single_consonant = vowel_at(index - 1)
+                && consonant_at(index)
+                && (vowel_at(index + 1) || (digraph_at(index) && vowel_at(index + 2)));
+            // Two consonants between vowels are split, unless they form a digraph
+            let two_consonants = vowel_at(index - 2)
+                && consonant_at(index - 1)
+                && consonant_at(index)
+                && vowel_at(index + 1)
+                && !digraph_at(index - 1);
+            if single_consonant || two_consonants {
+                points.push(*offset);
+            }
+        }
+    };
+    for (offset, c) in text.char_indices() {
+        if is_latin_letter(c) {
+            word.push((offset, c));
+        } else {
+            add_word_points(&word);
+            word.clear();
+        }
+    }
+    add_word_points(&word);
+    points
+}
+
+#[test]
+fn test_hyphenation_points() {
+    let points = |text: &str| {
+        let points = hyphenation_points(text);
+        let mut result = alloc::string::String::new();
+        let mut last = 0;
+        for point in points {
+            result += &text[last..point];
+            result.push('-');
+            last = point;
+        }
+        result += &text[last..];
+        result
+    };
+    assert_eq!(points("hotel"), "ho-tel");
+    assert_eq!(points("window paper"), "win-dow pa-per");
+    assert_eq!(points("hyphenation"), "hy-phe-na-tion");
+    assert_eq!(points("Wonderful elephants"), "Won-der-ful ele-phants");
+    assert_eq!(points("teacher"), "tea-cher");
+    assert_eq!(points("strengths"), "strengths");
+    assert_eq!(points("a 123456 über"), "a 123456 über");
+    assert_eq!(points("Überraschung"), "Über-raschung");
+}
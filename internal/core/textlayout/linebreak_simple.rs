@@ -27,10 +27,11 @@ fn next(&mut self) -> Option<Self::Item> {
                 '\u{2028}' | '\u{2029}' => Some(BreakOpportunity::Mandatory), // unicode line- and paragraph separators
                 '\n' => Some(BreakOpportunity::Mandatory),                    // ascii line break
                 _ if char.is_ascii_whitespace() => Some(BreakOpportunity::Allowed),
+                '\u{ad}' => Some(BreakOpportunity::Allowed), // soft hyphen
                 _ => None,
             };
             if let Some(opportunity) = maybe_opportunity {
-                return Some((byte_offset + 1, opportunity));
+                return Some((byte_offset + char.len_utf8(), opportunity));
             }
         }
 
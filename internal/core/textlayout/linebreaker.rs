@@ -19,6 +19,15 @@ pub struct TextLine<Length: Default + Clone> {
     pub(crate) glyph_range: Range<usize>,
     trailing_whitespace: Length,
     pub(crate) text_width: Length, // with as occupied by the glyphs
+    // true if the line is part of a right-to-left paragraph (set when laying out the lines)
+    pub(crate) is_rtl_paragraph: bool,
+    // height of the line and distance from its top to the baseline (set when laying out the lines)
+    pub height: Length,
+    pub ascent: Length,
+    // true if the line was broken because the next fragment didn't fit
+    pub(crate) wrapped: bool,
+    // true if the line was broken within a word, so that a hyphen is drawn at its end. text_width includes the hyphen.
+    pub(crate) hyphenated: bool,
 }
 
 impl<
@@ -62,6 +71,7 @@ pub fn add_fragment(&mut self, fragment: &TextFragment<Length>) {
         self.text_width += fragment.width;
         self.trailing_whitespace += fragment.trailing_whitespace_width;
         self.trailing_whitespace_bytes += fragment.trailing_whitespace_bytes;
+        self.hyphenated = fragment.hyphenated;
     }
 }
 
@@ -73,6 +83,7 @@ pub struct TextLineBreaker<'a, Font: TextShaper> {
     mandatory_line_break_on_next_iteration: bool,
     max_lines: Option<usize>,
     text_wrap: TextWrap,
+    hyphen_width: Font::Length,
 }
 
 impl<'a, Font: TextShaper> TextLineBreaker<'a, Font> {
@@ -83,14 +94,20 @@ pub fn new(
         max_lines: Option<usize>,
         text_wrap: TextWrap,
     ) -> Self {
+        let fragments = TextFragmentIterator::new(text, shape_buffer);
         Self {
-            fragments: TextFragmentIterator::new(text, shape_buffer),
+            fragments: if text_wrap == TextWrap::Hyphenate {
+                fragments.with_hyphenation()
+            } else {
+                fragments
+            },
             available_width,
             current_line: Default::default(),
             num_emitted_lines: 0,
             mandatory_line_break_on_next_iteration: false,
             max_lines,
             text_wrap,
+            hyphen_width: shape_buffer.hyphen_width,
         }
     }
 }
@@ -136,7 +153,11 @@ fn next(&mut self) -> Option<Self::Item> {
             }
 
             if let Some(available_width) = self.available_width {
-                if self.current_line.width_including_trailing_whitespace() + fragment.width
+                let hyphen_width =
+                    if fragment.hyphenated { self.hyphen_width } else { Font::Length::zero() };
+                if self.current_line.width_including_trailing_whitespace()
+                    + fragment.width
+                    + hyphen_width
                     > available_width
                 {
                     if self.current_line.is_empty() {
@@ -150,11 +171,18 @@ fn next(&mut self) -> Option<Self::Item> {
                             // Just use it anywhere otherwise we would return many empty lines
                             self.fragments = fragments;
                             self.current_line.add_fragment(&fragment);
+                            if self.current_line.hyphenated {
+                                self.current_line.text_width += self.hyphen_width;
+                            }
                             break Some(core::mem::take(&mut self.current_line));
                         }
                     }
 
-                    let next_line = core::mem::take(&mut self.current_line);
+                    let mut next_line = core::mem::take(&mut self.current_line);
+                    next_line.wrapped = true;
+                    if next_line.hyphenated {
+                        next_line.text_width += self.hyphen_width;
+                    }
                     self.mandatory_line_break_on_next_iteration = fragment.trailing_mandatory_break;
 
                     if self.text_wrap != TextWrap::CharWrap
@@ -639,3 +667,73 @@ fn char_wrap_sentences() {
     .collect::<std::vec::Vec<_>>();
     assert_eq!(lines, ["Hello wo", "rld", "How are", "you?"]);
 }
+
+#[test]
+fn soft_hyphen() {
+    let font = FixedTestFont;
+    let text = "Hyphen\u{ad}ation works";
+    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let lines = TextLineBreaker::<FixedTestFont>::new(
+        text,
+        &shape_buffer,
+        Some(100.),
+        None,
+        TextWrap::WordWrap,
+    )
+    .collect::<std::vec::Vec<_>>();
+    assert_eq!(
+        lines.iter().map(|line| line.line_text(text)).collect::<std::vec::Vec<_>>(),
+        ["Hyphen\u{ad}", "ation", "works"]
+    );
+    // The soft hyphen itself has no width, but the hyphen drawn at the end of the line has
+    assert!(lines[0].hyphenated);
+    assert_eq!(lines[0].text_width, 70.);
+    assert!(!lines[1].hyphenated);
+    assert!(!lines[2].hyphenated);
+}
+
+#[test]
+fn hyphenate() {
+    let font = FixedTestFont;
+    let text = "Wonderful elephants";
+    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let lines = TextLineBreaker::<FixedTestFont>::new(
+        text,
+        &shape_buffer,
+        Some(60.),
+        None,
+        TextWrap::Hyphenate,
+    )
+    .collect::<std::vec::Vec<_>>();
+    assert_eq!(
+        lines.iter().map(|line| line.line_text(text)).collect::<std::vec::Vec<_>>(),
+        ["Won", "derful", "ele", "phants"]
+    );
+    assert_eq!(
+        lines.iter().map(|line| line.hyphenated).collect::<std::vec::Vec<_>>(),
+        [true, false, true, false]
+    );
+}
+
+#[test]
+fn hyphenate_long_word() {
+    let font = FixedTestFont;
+    // No hyphenation point, so the word is broken where it doesn't fit anymore, with a hyphen
+    let text = "Strengths";
+    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let lines = TextLineBreaker::<FixedTestFont>::new(
+        text,
+        &shape_buffer,
+        Some(60.),
+        None,
+        TextWrap::Hyphenate,
+    )
+    .collect::<std::vec::Vec<_>>();
+    assert_eq!(
+        lines.iter().map(|line| line.line_text(text)).collect::<std::vec::Vec<_>>(),
+        ["Stren", "gths"]
+    );
+    assert!(lines[0].hyphenated);
+    assert_eq!(lines[0].text_width, 60.);
+    assert!(!lines[1].hyphenated);
+}
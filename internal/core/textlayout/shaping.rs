@@ -4,7 +4,7 @@
 use alloc::vec::Vec;
 use core::ops::Range;
 
-use super::TextLayout;
+use super::{bidi, TextLayout, TextSpan};
 
 /// This struct describes a glyph from shaping to rendering. This includes the relative shaping
 /// offsets, advance (in abstract lengths) and platform specific glyph data.
@@ -19,6 +19,8 @@ pub struct Glyph<Length> {
     /// The byte offset back in the original (Rust) string to the character that
     /// "produced" this glyph. When one character produces multiple glyphs (for example
     /// decomposed ligature), then all glyphs have the same offset.
+    /// [`TextShaper::shape_text`] produces offsets relative to the shaped text, and the
+    /// [`ShapeBuffer`] makes them relative to the start of the whole text.
     pub text_byte_offset: usize,
 }
 
@@ -85,8 +87,8 @@ pub struct ShapeBoundaries<'a> {
     text: &'a str,
     #[cfg(feature = "unicode-script")]
     // TODO: We should do a better analysis to find boundaries for text shaping; including
-    // boundaries when the bidi level changes or an explicit separator like
-    // paragraph/lineseparator/space is encountered.
+    // boundaries when an explicit separator like paragraph/lineseparator/space is encountered.
+    // Boundaries where the bidi level changes are handled by the ShapeBuffer.
     chars: core::str::CharIndices<'a>,
     next_boundary_start: Option<usize>,
     #[cfg(feature = "unicode-script")]
@@ -163,26 +165,74 @@ fn next(&mut self) -> Option<Self::Item> {
 pub struct TextRun {
     pub byte_range: Range<usize>,
     pub glyph_range: Range<usize>,
-    // TODO: direction, etc.
+    /// The bidi embedding level of the run. Odd levels are right-to-left.
+    pub level: u8,
+    /// The bidi embedding level of the paragraph that contains the run
+    pub paragraph_level: u8,
 }
 
 pub struct ShapeBuffer<Length> {
+    /// The shaped glyphs in logical order, also for right-to-left text
     pub glyphs: Vec<Glyph<Length>>,
     pub text_runs: Vec<TextRun>,
+    /// The width of the hyphen drawn at the end of lines broken within a word
+    pub hyphen_width: Length,
 }
 
 impl<Length> ShapeBuffer<Length> {
     pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
     where
         Font: AbstractFont<Length = Length>,
-        Length: Copy + core::ops::AddAssign,
+        Length: Copy + Default + core::ops::AddAssign,
+    {
+        Self::new_with_spans(layout, &[], text)
+    }
+
+    /// Shapes the text like [`Self::new`], but the ranges covered by `spans` are shaped with
+    /// the span's layout instead of `layout`.
+    pub fn new_with_spans<Font>(
+        layout: &TextLayout<Font>,
+        spans: &[TextSpan<Font>],
+        text: &str,
+    ) -> Self
+    where
+        Font: AbstractFont<Length = Length>,
+        Length: Copy + Default + core::ops::AddAssign,
     {
         let mut glyphs = Vec::new();
-        let text_runs = ShapeBoundaries::new(text)
-            .scan(0, |run_start, run_end| {
+        let mut text_runs = Vec::new();
+        for level_run in bidi::level_runs(text) {
+            let mut boundaries = Vec::new();
+            let mut previous_boundary = level_run.byte_range.start;
+            for boundary in ShapeBoundaries::new(&text[level_run.byte_range.clone()]) {
+                let boundary = level_run.byte_range.start + boundary;
+                boundaries.extend(span_boundaries(spans, previous_boundary..boundary));
+                boundaries.push(boundary);
+                previous_boundary = boundary;
+            }
+
+            let mut run_start = level_run.byte_range.start;
+            for run_end in boundaries {
+                let layout = super::layout_at(layout, spans, run_start);
                 let glyphs_start = glyphs.len();
 
-                layout.font.shape_text(&text[*run_start..run_end], &mut glyphs);
+                layout.font.shape_text(&text[run_start..run_end], &mut glyphs);
+
+                let run_glyphs = &mut glyphs[glyphs_start..];
+                // Shapers emit the glyphs of right-to-left text in visual order
+                if run_glyphs.first().map(|g| g.text_byte_offset)
+                    > run_glyphs.last().map(|g| g.text_byte_offset)
+                {
+                    run_glyphs.reverse();
+                }
+                for glyph in run_glyphs.iter_mut() {
+                    glyph.text_byte_offset += run_start;
+                    // Soft hyphens are invisible, the line breaker draws a hyphen when breaking after one
+                    if text[glyph.text_byte_offset..].starts_with(super::hyphenation::SOFT_HYPHEN) {
+                        glyph.glyph_id = None;
+                        glyph.advance = Length::default();
+                    }
+                }
 
                 if let Some(letter_spacing) = layout.letter_spacing {
                     if glyphs.len() > glyphs_start {
@@ -200,18 +250,74 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
                     }
                 }
 
-                let run = TextRun {
-                    byte_range: Range { start: *run_start, end: run_end },
+                text_runs.push(TextRun {
+                    byte_range: Range { start: run_start, end: run_end },
                     glyph_range: Range { start: glyphs_start, end: glyphs.len() },
-                };
-                *run_start = run_end;
+                    level: level_run.level,
+                    paragraph_level: level_run.paragraph_level,
+                });
+                run_start = run_end;
+            }
+        }
 
-                Some(run)
-            })
-            .collect();
+        let hyphen_width = layout
+            .font
+            .glyph_for_char('-')
+            .filter(|glyph| glyph.glyph_id.is_some())
+            .map_or_else(Length::default, |glyph| glyph.advance);
+
+        Self { glyphs, text_runs, hyphen_width }
+    }
 
-        Self { glyphs, text_runs }
+    /// Returns true if the paragraph that contains the given byte offset is right-to-left
+    pub fn is_rtl_paragraph_at(&self, byte_offset: usize) -> bool {
+        let index = self.text_runs.partition_point(|run| run.byte_range.end <= byte_offset);
+        self.text_runs.get(index).is_some_and(|run| {
+            run.byte_range.start <= byte_offset && bidi::is_rtl(run.paragraph_level)
+        })
     }
+
+    /// Returns the bidi embedding level of each glyph in the given range, or None if they are all
+    /// left-to-right. Glyphs at or after `trailing_whitespace_start` get the level of the paragraph.
+    pub fn glyph_levels(
+        &self,
+        glyph_range: Range<usize>,
+        trailing_whitespace_start: usize,
+    ) -> Option<Vec<u8>> {
+        let first_run =
+            self.text_runs.partition_point(|run| run.glyph_range.end <= glyph_range.start);
+        let runs = self.text_runs[first_run..]
+            .iter()
+            .take_while(|run| run.glyph_range.start < glyph_range.end);
+        if runs.clone().all(|run| run.level == 0 && run.paragraph_level == 0) {
+            return None;
+        }
+        let mut levels = Vec::with_capacity(glyph_range.len());
+        for run in runs {
+            let start = run.glyph_range.start.max(glyph_range.start);
+            let end = run.glyph_range.end.min(glyph_range.end);
+            levels.extend(self.glyphs[start..end].iter().map(|glyph| {
+                if glyph.text_byte_offset >= trailing_whitespace_start {
+                    run.paragraph_level
+                } else {
+                    run.level
+                }
+            }));
+        }
+        Some(levels)
+    }
+}
+
+/// Returns the start and end offsets of the spans that are inside the given range, in order
+fn span_boundaries<'a, Font: AbstractFont>(
+    spans: &'a [TextSpan<Font>],
+    range: Range<usize>,
+) -> impl Iterator<Item = usize> + 'a {
+    spans
+        .iter()
+        .flat_map(|span| [span.byte_range.start, span.byte_range.end])
+        .skip_while(move |offset| *offset <= range.start)
+        .take_while(move |offset| *offset < range.end)
 }
 
 #[test]
@@ -281,8 +387,13 @@ fn shape_text<GlyphStorage: std::iter::Extend<Glyph<f32>>>(
         glyphs.extend(output_glyph_generator);
     }
 
-    fn glyph_for_char(&self, _ch: char) -> Option<Glyph<f32>> {
-        todo!()
+    fn glyph_for_char(&self, ch: char) -> Option<Glyph<f32>> {
+        let glyph_id = self.glyph_index(ch)?;
+        Some(Glyph {
+            glyph_id: core::num::NonZeroU16::new(glyph_id.0),
+            advance: self.glyph_hor_advance(glyph_id).unwrap_or_default() as _,
+            ..Default::default()
+        })
     }
 
     fn max_lines(&self, max_height: f32) -> usize {
@@ -12,13 +12,16 @@
 };
 use crate::input::{
     key_codes, ClickState, FocusEvent, FocusReason, InternalKeyboardModifierState, KeyEvent,
-    KeyEventType, MouseEvent, MouseInputState, TextCursorBlinker,
+    KeyEventType, MouseEvent, MouseInputState, TextCursorBlinker, TouchEventType, TouchState,
 };
 use crate::item_tree::{
     ItemRc, ItemTreeRc, ItemTreeRef, ItemTreeVTable, ItemTreeWeak, ItemWeak,
     ParentItemTraversalMode,
 };
-use crate::items::{ColorScheme, InputType, ItemRef, MouseCursor, PopupClosePolicy};
+use crate::items::{
+    ColorScheme, DropEvent, InputType, ItemRef, LayoutDirection, MouseCursor, PointerEventButton,
+    PopupClosePolicy,
+};
 use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, SizeLengths};
 use crate::menus::MenuVTable;
 use crate::properties::{Property, PropertyTracker};
@@ -460,6 +463,9 @@ pub struct WindowInner {
     had_popup_on_press: Cell<bool>,
     close_requested: Callback<(), CloseRequestResponse>,
     click_state: ClickState,
+    touch_state: TouchState,
+    /// The data being dragged, while a drag and drop operation is in progress
+    drag_data: RefCell<Option<DropEvent>>,
     pub(crate) ctx: once_cell::unsync::Lazy<crate::SlintContext>,
 }
 
@@ -518,6 +524,8 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             had_popup_on_press: Default::default(),
             close_requested: Default::default(),
             click_state: ClickState::default(),
+            touch_state: TouchState::default(),
+            drag_data: Default::default(),
             prevent_focus_change: Default::default(),
             // The ctx is lazy so that a Window can be initialized before the backend.
             // (for example in test_empty_window)
@@ -584,6 +592,7 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
 
         // handle multiple press release
         event = self.click_state.check_repeat(event, self.ctx.platform().click_interval());
+        event = self.translate_drag_event(event);
 
         let pressed_event = matches!(event, MouseEvent::Pressed { .. });
         let released_event = matches!(event, MouseEvent::Released { .. });
@@ -591,7 +600,7 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
         let window_adapter = self.window_adapter();
         let mut mouse_input_state = self.mouse_input_state.take();
         let last_top_item = mouse_input_state.top_item_including_delayed();
-        if released_event {
+        if released_event || matches!(event, MouseEvent::PinchGesture { .. }) {
             mouse_input_state =
                 crate::input::process_delayed_event(&window_adapter, mouse_input_state);
         }
@@ -649,6 +658,8 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
         mouse_input_state = if let Some(mut event) =
             crate::input::handle_mouse_grab(event, &window_adapter, &mut mouse_input_state)
         {
+            // The grabber might have started a drag
+            event = self.translate_drag_event(event);
             let mut item_tree = self.component.borrow().upgrade();
             let mut offset = LogicalPoint::default();
             let mut menubar_item = None;
@@ -725,6 +736,10 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
 
         self.mouse_input_state.set(mouse_input_state);
 
+        if matches!(event, MouseEvent::Drop { .. } | MouseEvent::Exit) {
+            self.drag_data.take();
+        }
+
         if let Some(popup_id) = popup_to_close {
             WindowInner::from_pub(root_adapter.window()).close_popup(popup_id);
         }
@@ -732,6 +747,83 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
         crate::properties::ChangeTracker::run_change_handlers();
     }
 
+    /// While a drag and drop operation is in progress, turn the mouse move and release events
+    /// into [`MouseEvent::DragMove`] and [`MouseEvent::Drop`].
+    fn translate_drag_event(&self, event: MouseEvent) -> MouseEvent {
+        if self.drag_data.borrow().is_none() {
+            return event;
+        }
+        match event {
+            MouseEvent::Moved { position } => MouseEvent::DragMove { position },
+            MouseEvent::Released { position, button: PointerEventButton::Left, .. } => {
+                MouseEvent::Drop { position }
+            }
+            _ => event,
+        }
+    }
+
+    /// Start a drag and drop operation with the given data.
+    ///
+    /// Until the data is dropped, the mouse move events are sent to the items as
+    /// [`MouseEvent::DragMove`], and releasing the left button sends a [`MouseEvent::Drop`].
+    /// Backends can also call this function followed by [`Self::process_mouse_input`] with
+    /// these events to forward drag and drop operations from other applications.
+    pub fn start_drag(&self, data: DropEvent) {
+        *self.drag_data.borrow_mut() = Some(data);
+    }
+
+    /// Returns the data of the drag and drop operation in progress, if any.
+    pub fn drag_data(&self) -> Option<DropEvent> {
+        self.drag_data.borrow().clone()
+    }
+
+    /// Returns a position in window coordinates where the data of the drag and drop operation in
+    /// progress can be dropped: the center of the topmost enabled and visible `DropArea` that
+    /// accepts the data.
+    ///
+    /// Backends use this when they don't know the position of the pointer, for example because
+    /// the platform doesn't report it while files are dragged from another application.
+    pub fn find_drop_position(&self) -> Option<LogicalPoint> {
+        let mut drop_event = self.drag_data()?;
+        let root = ItemRc::new(self.try_component()?, 0);
+        let mut result = None;
+        root.visit_descendants(|item| {
+            if let Some(drop_area) = item.downcast::<crate::items::DropArea>() {
+                let drop_area = drop_area.as_pin_ref();
+                if drop_area.enabled() && item.is_visible() {
+                    let geometry = item.geometry();
+                    let center = (geometry.size / 2 as Coord).to_vector().to_point();
+                    drop_event.position = crate::lengths::logical_position_to_api(center);
+                    if crate::items::DropArea::FIELD_OFFSETS
+                        .can_drop
+                        .apply_pin(drop_area)
+                        .call(&(drop_event.clone(),))
+                    {
+                        // Items are visited back to front, so keep the last one
+                        result = Some(item.map_to_window(geometry.origin + center.to_vector()));
+                    }
+                }
+            }
+            core::ops::ControlFlow::<()>::Continue(())
+        });
+        result
+    }
+
+    /// Receive a touch event and pass it to the items of the component as mouse events.
+    ///
+    /// The first touch point is handled like the left mouse button, and moving the first two
+    /// touch points relative to each other produces pinch gesture events.
+    pub fn process_touch_input(
+        &self,
+        touch_id: u64,
+        position: LogicalPoint,
+        event_type: TouchEventType,
+    ) {
+        for event in self.touch_state.process(touch_id, position, event_type) {
+            self.process_mouse_input(event);
+        }
+    }
+
     /// Called by the input code's internal timer to send an event that was delayed
     pub(crate) fn process_delayed_event(&self) {
         self.mouse_input_state.set(crate::input::process_delayed_event(
@@ -1114,6 +1206,12 @@ pub fn color_scheme(&self) -> ColorScheme {
             .map_or(ColorScheme::Unknown, |x| x.color_scheme())
     }
 
+    /// Returns the layout-direction property of the window item, or left-to-right if the root
+    /// of the window is not a `Window`
+    pub fn layout_direction(&self) -> LayoutDirection {
+        self.window_item().map_or(Default::default(), |item| item.as_pin_ref().layout_direction())
+    }
+
     /// Return whether the platform supports native menu bars
     pub fn supports_native_menu_bar(&self) -> bool {
         self.window_adapter()
@@ -1843,6 +1941,15 @@ fn call(&self) -> CloseRequestResponse {
         window_adapter.window().0.window_item().unwrap().as_pin_ref().default_font_size().get()
     }
 
+    /// Return the layout-direction property of the WindowItem
+    #[unsafe(no_mangle)]
+    pub unsafe extern "C" fn slint_windowrc_layout_direction(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> LayoutDirection {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().0.layout_direction()
+    }
+
     /// Dispatch a key pressed or release event
     #[unsafe(no_mangle)]
     pub unsafe extern "C" fn slint_windowrc_dispatch_key_event(
@@ -965,12 +965,16 @@ fn generate_rtti() -> HashMap<&'static str, Rc<ItemRTTI>> {
             rtti_for::<ClippedImage>(),
             rtti_for::<ComplexText>(),
             rtti_for::<SimpleText>(),
+            rtti_for::<StyledText>(),
             rtti_for::<Rectangle>(),
             rtti_for::<BasicBorderRectangle>(),
             rtti_for::<BorderRectangle>(),
             rtti_for::<TouchArea>(),
             rtti_for::<FocusScope>(),
             rtti_for::<SwipeGestureHandler>(),
+            rtti_for::<PinchGestureHandler>(),
+            rtti_for::<DragArea>(),
+            rtti_for::<DropArea>(),
             rtti_for::<Path>(),
             rtti_for::<Flickable>(),
             rtti_for::<WindowItem>(),
@@ -1387,12 +1391,30 @@ macro_rules! match_enum_type {
 pub fn animation_for_property(
     component: InstanceRef,
     animation: &Option<i_slint_compiler::object_tree::PropertyAnimation>,
-) -> AnimatedBindingKind {
+) -> AnimatedBindingKind<Value> {
     match animation {
         Some(i_slint_compiler::object_tree::PropertyAnimation::Static(anim_elem)) => {
-            AnimatedBindingKind::Animation(eval::new_struct_with_bindings(
-                &anim_elem.borrow().bindings,
-                &mut eval::EvalLocalContext::from_component_instance(component),
+            let mut context = eval::EvalLocalContext::from_component_instance(component);
+            let bindings = &anim_elem.borrow().bindings;
+            let keyframes = match bindings.get("keyframes") {
+                Some(keyframes) => {
+                    let keyframes = eval::eval_expression(&keyframes.borrow(), &mut context);
+                    let Value::Model(model) = keyframes else { unreachable!() };
+                    i_slint_core::model::Model::iter(&model)
+                        .filter_map(|keyframe| {
+                            let Value::Struct(keyframe) = keyframe else { return None };
+                            Some(i_slint_core::properties::Keyframe {
+                                offset: keyframe.get_field("offset")?.clone().try_into().ok()?,
+                                value: keyframe.get_field("value")?.clone(),
+                            })
+                        })
+                        .collect()
+                }
+                None => Vec::new(),
+            };
+            AnimatedBindingKind::Animation((
+                eval::new_struct_with_bindings(bindings, &mut context),
+                keyframes,
             ))
         }
         Some(i_slint_compiler::object_tree::PropertyAnimation::Transition {
@@ -4,11 +4,13 @@
 use crate::api::{SetPropertyError, Struct, Value};
 use crate::dynamic_item_tree::{CallbackHandler, InstanceRef};
 use core::pin::Pin;
-use corelib::graphics::{GradientStop, LinearGradientBrush, PathElement, RadialGradientBrush};
-use corelib::items::{ColorScheme, ItemRef, MenuEntry, PropertyAnimation};
+use corelib::graphics::{
+    ConicGradientBrush, GradientStop, LinearGradientBrush, PathElement, RadialGradientBrush,
+};
+use corelib::items::{ColorScheme, ItemRef, MenuEntry};
 use corelib::menus::{Menu, MenuFromItemTree, MenuVTable};
 use corelib::model::{Model, ModelExt, ModelRc, VecModel};
-use corelib::rtti::AnimatedBindingKind;
+use corelib::rtti::{AnimatedBindingKind, AnimationWithKeyframes};
 use corelib::window::WindowInner;
 use corelib::{Brush, Color, PathData, SharedString, SharedVector};
 use i_slint_compiler::expression_tree::{
@@ -31,13 +33,13 @@ fn set(
         &self,
         item: Pin<ItemRef>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: Option<AnimationWithKeyframes<Value>>,
     ) -> Result<(), ()>;
     fn set_binding(
         &self,
         item: Pin<ItemRef>,
         binding: Box<dyn Fn() -> Value>,
-        animation: AnimatedBindingKind,
+        animation: AnimatedBindingKind<Value>,
     );
     fn offset(&self) -> usize;
 
@@ -56,7 +58,7 @@ fn set(
         &self,
         item: Pin<ItemRef>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: Option<AnimationWithKeyframes<Value>>,
     ) -> Result<(), ()> {
         (*self).set(ItemRef::downcast_pin(item).unwrap(), value, animation)
     }
@@ -64,7 +66,7 @@ fn set_binding(
         &self,
         item: Pin<ItemRef>,
         binding: Box<dyn Fn() -> Value>,
-        animation: AnimatedBindingKind,
+        animation: AnimatedBindingKind<Value>,
     ) {
         (*self).set_binding(ItemRef::downcast_pin(item).unwrap(), binding, animation).unwrap();
     }
@@ -293,6 +295,9 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 i_slint_compiler::expression_tree::ImageReference::EmbeddedTexture { .. } => {
                     todo!()
                 }
+                i_slint_compiler::expression_tree::ImageReference::EmbeddedAnimatedTexture { .. } => {
+                    unreachable!("the interpreter doesn't embed images")
+                }
             }.unwrap_or_else(|_| {
                 eprintln!("Could not load image {resource_ref:?}" );
                 Default::default()
@@ -360,6 +365,16 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 GradientStop{ color, position }
             }))))
         }
+        Expression::ConicGradient{from_angle, center_x, center_y, stops} => {
+            let from_angle = eval_expression(from_angle, local_context).try_into().unwrap();
+            let center_x = eval_expression(center_x, local_context).try_into().unwrap();
+            let center_y = eval_expression(center_y, local_context).try_into().unwrap();
+            Value::Brush(Brush::ConicGradient(ConicGradientBrush::new(from_angle, center_x, center_y, stops.iter().map(|(color, stop)| {
+                let color = eval_expression(color, local_context).try_into().unwrap();
+                let position = eval_expression(stop, local_context).try_into().unwrap();
+                GradientStop{ color, position }
+            }))))
+        }
         Expression::EnumerationValue(value) => {
             Value::EnumerationValue(value.enumeration.name.to_string(), value.to_string())
         }
@@ -416,6 +431,10 @@ fn call_builtin_function(
                 window.window_item().unwrap().as_pin_ref().default_font_size().get()
             }) as _)
         }
+        BuiltinFunction::GetWindowLayoutDirection => local_context
+            .component_instance
+            .access_window(|window| window.layout_direction())
+            .into(),
         BuiltinFunction::AnimationTick => {
             Value::Number(i_slint_core::animations::animation_tick() as f64)
         }
@@ -936,6 +955,73 @@ fn call_builtin_function(
                 panic!("Argument not a string");
             }
         }
+        BuiltinFunction::StringContains
+        | BuiltinFunction::StringStartsWith
+        | BuiltinFunction::StringEndsWith
+        | BuiltinFunction::StringFind
+        | BuiltinFunction::StringSplit => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to {f:?}")
+            }
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let other: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            match f {
+                BuiltinFunction::StringContains => Value::Bool(s.contains(other.as_str())),
+                BuiltinFunction::StringStartsWith => Value::Bool(s.starts_with(other.as_str())),
+                BuiltinFunction::StringEndsWith => Value::Bool(s.ends_with(other.as_str())),
+                BuiltinFunction::StringFind => {
+                    Value::Number(corelib::string::shared_string_find(&s, &other) as f64)
+                }
+                _ => Value::Model(ModelRc::new(corelib::model::SharedVectorModel::from(
+                    corelib::string::shared_string_split(&s, &other)
+                        .into_iter()
+                        .map(Value::String)
+                        .collect::<SharedVector<_>>(),
+                ))),
+            }
+        }
+        BuiltinFunction::StringSubstring => {
+            if arguments.len() != 3 {
+                panic!("internal error: incorrect argument count to StringSubstring")
+            }
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let start: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let length: i32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            Value::String(corelib::string::shared_string_substring(&s, start, length))
+        }
+        BuiltinFunction::StringReplace => {
+            if arguments.len() != 3 {
+                panic!("internal error: incorrect argument count to StringReplace")
+            }
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let from: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let to: SharedString =
+                eval_expression(&arguments[2], local_context).try_into().unwrap();
+            Value::String(corelib::string::shared_string_replace(&s, &from, &to))
+        }
+        BuiltinFunction::StringTrim => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to StringTrim")
+            }
+            if let Value::String(s) = eval_expression(&arguments[0], local_context) {
+                Value::String(s.trim().into())
+            } else {
+                panic!("Argument not a string");
+            }
+        }
+        BuiltinFunction::StringPadStart | BuiltinFunction::StringPadEnd => {
+            if arguments.len() != 3 {
+                panic!("internal error: incorrect argument count to {f:?}")
+            }
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let length: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let fill: SharedString =
+                eval_expression(&arguments[2], local_context).try_into().unwrap();
+            let at_start = matches!(f, BuiltinFunction::StringPadStart);
+            Value::String(corelib::string::shared_string_pad(&s, length, &fill, at_start))
+        }
         BuiltinFunction::ColorRgbaStruct => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to ColorRGBAComponents")
@@ -1374,6 +1460,11 @@ fn call_item_member_function(nr: &NamedReference, local_context: &mut EvalLocalC
             "cancel" => s.cancel(&window_adapter, &item_rc),
             _ => panic!("internal: Unknown member function {name} called on SwipeGestureHandler"),
         }
+    } else if let Some(s) = ItemRef::downcast_pin::<corelib::items::PinchGestureHandler>(item_ref) {
+        match name {
+            "cancel" => s.cancel(&window_adapter, &item_rc),
+            _ => panic!("internal: Unknown member function {name} called on PinchGestureHandler"),
+        }
     } else if let Some(s) = ItemRef::downcast_pin::<corelib::items::ContextMenu>(item_ref) {
         match name {
             "close" => s.close(&window_adapter, &item_rc),
@@ -123,6 +123,12 @@ pub(crate) fn solve_layout(
                     spacing,
                     padding,
                     alignment,
+                    direction: match orientation {
+                        Orientation::Horizontal => {
+                            component.access_window(|window| window.layout_direction())
+                        }
+                        Orientation::Vertical => Default::default(),
+                    },
                     cells: Slice::from(cells.as_slice()),
                 },
                 Slice::from(repeated_indices.as_slice()),
@@ -152,6 +152,38 @@ fn parse_stops<'a>(
                             parse_stops(split)?.drain(..),
                         )
                         .into())
+                    } else if let Some(conic) = input.strip_prefix("@conic-gradient(from ") {
+                        let mut split = conic.split(',').map(|p| p.trim());
+
+                        let (from_angle, center_x, center_y) = {
+                            let position = split.next().unwrap_or_default();
+                            let parts = position.split_whitespace().collect::<Vec<_>>();
+                            let [angle, "at", x, y] = parts[..] else {
+                                return Err(
+                                    "A conic gradient must start with 'from <angle> at <x> <y>'"
+                                        .into(),
+                                );
+                            };
+                            let angle = angle
+                                .strip_suffix("deg")
+                                .and_then(|no| no.parse::<f32>().ok())
+                                .ok_or_else(|| format!("Failed to parse angle value '{angle}'"))?;
+                            let percent = |part: &str| {
+                                part.strip_suffix('%')
+                                    .and_then(|no| no.parse::<f32>().ok())
+                                    .map(|no| no / 100.0)
+                                    .ok_or_else(|| format!("Failed to parse position '{part}'"))
+                            };
+                            (angle, percent(x)?, percent(y)?)
+                        };
+
+                        Ok(i_slint_core::graphics::ConicGradientBrush::new(
+                            from_angle,
+                            center_x,
+                            center_y,
+                            parse_stops(split)?.drain(..),
+                        )
+                        .into())
                     } else {
                         Err(format!("Could not parse gradient from '{input}'"))
                     }
@@ -275,6 +307,15 @@ fn gradient_to_string_helper<'a>(
             Brush::RadialGradient(rg) => {
                 Ok(gradient_to_string_helper("@radial-gradient(circle".into(), rg.stops()))
             }
+            Brush::ConicGradient(cg) => Ok(gradient_to_string_helper(
+                format!(
+                    "@conic-gradient(from {}deg at {}% {}%",
+                    cg.from_angle(),
+                    cg.center().x * 100.0,
+                    cg.center().y * 100.0
+                ),
+                cg.stops(),
+            )),
             _ => Err("Cannot serialize an unknown brush type".into()),
         },
         Value::PathData(_) => Err("Cannot serialize path data".into()),
@@ -538,4 +579,25 @@ fn test_to_json() {
     )))
     .unwrap();
     assert_eq!(&v, "\"@radial-gradient(circle, #ff0000ff 0%, #00ff00ff 50%, #0000ffff 100%)\"");
+
+    let brush =
+        Value::Brush(Brush::ConicGradient(i_slint_core::graphics::ConicGradientBrush::new(
+            90.0,
+            0.25,
+            0.5,
+            vec![
+                i_slint_core::graphics::GradientStop {
+                    position: 0.0,
+                    color: Color::from_argb_u8(0xff, 0xff, 0x00, 0x00),
+                },
+                i_slint_core::graphics::GradientStop {
+                    position: 1.0,
+                    color: Color::from_argb_u8(0xff, 0x00, 0x00, 0xff),
+                },
+            ]
+            .drain(..),
+        )));
+    let v = value_to_json_string(&brush).unwrap();
+    assert_eq!(&v, "\"@conic-gradient(from 90deg at 25% 50%, #ff0000ff 0%, #0000ffff 100%)\"");
+    assert_eq!(value_from_json_str(&langtype::Type::Brush, &v).unwrap(), brush);
 }
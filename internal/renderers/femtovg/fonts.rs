@@ -8,7 +8,9 @@
 use i_slint_common::sharedfontdb::{self, fontdb};
 use i_slint_core::graphics::euclid;
 use i_slint_core::graphics::FontRequest;
-use i_slint_core::items::{TextHorizontalAlignment, TextOverflow, TextVerticalAlignment, TextWrap};
+use i_slint_core::items::{
+    SpanStyle, StyledString, TextHorizontalAlignment, TextOverflow, TextVerticalAlignment, TextWrap,
+};
 use i_slint_core::lengths::PointLengths;
 use i_slint_core::lengths::{LogicalLength, LogicalSize, ScaleFactor, SizeLengths};
 use i_slint_core::{SharedString, SharedVector};
@@ -607,6 +609,12 @@ fn check_and_update_script_coverage(
     }
 }
 
+/// Returns the bidi embedding level of each byte of the line, resolved the same way femtovg does when
+/// shaping it: with a left-to-right base direction.
+pub(crate) fn bidi_levels(line: &str) -> Vec<unicode_bidi::Level> {
+    unicode_bidi::BidiInfo::new(line, Some(unicode_bidi::Level::ltr())).levels
+}
+
 /// Layout the given string in lines, and call the `layout_line` callback with the line to draw at position y.
 /// The signature of the `layout_line` function is: `(text, pos, start_index, line_metrics)`.
 /// start index is the starting byte of the text in the string.
@@ -663,7 +671,9 @@ pub(crate) fn layout_text_lines(
     let mut process_line =
         |text_span: &str, y: PhysicalLength, start: usize, line_metrics: &femtovg::TextMetrics| {
             let x = match horizontal_alignment {
-                TextHorizontalAlignment::Left => PhysicalLength::default(),
+                TextHorizontalAlignment::Left | TextHorizontalAlignment::Justify => {
+                    PhysicalLength::default()
+                }
                 TextHorizontalAlignment::Center => {
                     max_width / 2. - max_width.min(PhysicalLength::new(line_metrics.width())) / 2.
                 }
@@ -677,15 +687,19 @@ pub(crate) fn layout_text_lines(
             if let Some(cursor_byte_offset) = cursor_byte_offset {
                 let text_span_range = start..=(start + text_span.len());
                 if text_span_range.contains(&cursor_byte_offset) {
+                    let levels = bidi_levels(text_span);
+                    // The cursor is at the leading edge of the glyph, which is on the right for
+                    // right-to-left text. The glyphs of a right-to-left cluster are reversed.
                     let cursor_x = PhysicalLength::new(
                         line_metrics
                             .glyphs
                             .iter()
-                            .find_map(|glyph| {
-                                if glyph.byte_index == (cursor_byte_offset - start) {
-                                    Some(glyph.x)
+                            .filter(|glyph| glyph.byte_index == (cursor_byte_offset - start))
+                            .fold(None, |cursor_x, glyph| {
+                                if !levels.get(glyph.byte_index).is_some_and(|l| l.is_rtl()) {
+                                    cursor_x.or(Some(glyph.x))
                                 } else {
-                                    None
+                                    Some(glyph.x + glyph.advance_x)
                                 }
                             })
                             .unwrap_or_else(|| line_metrics.width()),
@@ -773,7 +787,9 @@ pub(crate) fn layout_text_lines(
     cursor_point.or_else(|| {
         cursor_byte_offset.map(|_| {
             let x = match horizontal_alignment {
-                TextHorizontalAlignment::Left => PhysicalLength::default(),
+                TextHorizontalAlignment::Left | TextHorizontalAlignment::Justify => {
+                    PhysicalLength::default()
+                }
                 TextHorizontalAlignment::Center => max_size.width_length() / 2.,
                 TextHorizontalAlignment::Right => max_size.width_length(),
             };
@@ -781,3 +797,208 @@ pub(crate) fn layout_text_lines(
         })
     })
 }
+
+/// Returns the paints for the text of a `StyledText` element: the paint for text in the default style
+/// followed by the paint of each span of the styled string. `init_paint` is called with the style
+/// of the text (`None` for the default style) and returns the paint to initialize with the font.
+pub(crate) fn styled_text_paints(
+    font_request: &FontRequest,
+    scale_factor: ScaleFactor,
+    styled_text: &StyledString,
+    mut init_paint: impl FnMut(Option<&SpanStyle>) -> femtovg::Paint,
+) -> Vec<femtovg::Paint> {
+    core::iter::once((font_request.clone(), None))
+        .chain(
+            styled_text
+                .spans
+                .iter()
+                .map(|span| (span.style.font_request(font_request), Some(&span.style))),
+        )
+        .map(|(request, style)| {
+            let letter_spacing = request.letter_spacing.unwrap_or_default() * scale_factor;
+            let font = FONT_CACHE
+                .with(|cache| cache.borrow_mut().font(request, scale_factor, &styled_text.text));
+            let mut paint = font.init_paint(letter_spacing, init_paint(style));
+            // Runs with different font sizes share the baseline of their line
+            paint.set_text_baseline(femtovg::Baseline::Alphabetic);
+            paint
+        })
+        .collect()
+}
+
+/// A part of a line of styled text that is drawn with a single paint
+pub(crate) struct StyledTextRun {
+    pub byte_range: core::ops::Range<usize>,
+    /// Index in the paints returned by [`styled_text_paints`]
+    pub paint_index: usize,
+    pub x: f32,
+    pub width: f32,
+    pub baseline_y: f32,
+    pub line_y: f32,
+    pub line_height: f32,
+}
+
+pub(crate) struct StyledTextLayout {
+    pub runs: Vec<StyledTextRun>,
+    /// The width of the widest line, without trailing white space
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Lays out the text of a `StyledText` element in lines, with a run per line and span. Lines that don't fit
+/// into `max_size` vertically are left out, text that is too wide is not elided.
+pub(crate) fn layout_styled_text(
+    styled_text: &StyledString,
+    paints: &[femtovg::Paint],
+    max_size: PhysicalSize,
+    (horizontal_alignment, vertical_alignment): (TextHorizontalAlignment, TextVerticalAlignment),
+    wrap: TextWrap,
+) -> StyledTextLayout {
+    let text = styled_text.text.as_str();
+    let text_context = FONT_CACHE.with(|cache| cache.borrow().text_context.clone());
+
+    let paint_index_at = |byte_offset: usize| {
+        styled_text
+            .spans
+            .iter()
+            .position(|span| span.byte_range.contains(&byte_offset))
+            .map_or(0, |i| i + 1)
+    };
+    // Splits a range of the text at the boundaries of the spans
+    let style_runs = |range: core::ops::Range<usize>| {
+        let mut runs = Vec::new();
+        let mut start = range.start;
+        while start < range.end {
+            let paint_index = paint_index_at(start);
+            let end = if paint_index == 0 {
+                styled_text
+                    .spans
+                    .iter()
+                    .map(|span| span.byte_range.start)
+                    .find(|span_start| *span_start > start)
+                    .unwrap_or(range.end)
+            } else {
+                styled_text.spans[paint_index - 1].byte_range.end
+            }
+            .min(range.end);
+            runs.push((start..end, paint_index));
+            start = end;
+        }
+        runs
+    };
+    let measure = |range: core::ops::Range<usize>, paint_index: usize| {
+        text_context
+            .measure_text(0., 0., &text[range], &paints[paint_index])
+            .map_or(0., |m| m.width())
+    };
+
+    // The lines, as byte ranges of the text, the trailing new line excluded
+    let mut lines: Vec<core::ops::Range<usize>> = Vec::new();
+    let mut paragraph_start = 0;
+    for paragraph in text.split('\n') {
+        let paragraph_range = paragraph_start..paragraph_start + paragraph.len();
+        paragraph_start = paragraph_range.end + 1;
+        if wrap == TextWrap::NoWrap {
+            lines.push(paragraph_range);
+            continue;
+        }
+        // The break opportunities: after each white space sequence, or between characters
+        let mut words = Vec::new();
+        let mut word_start = paragraph_range.start;
+        let mut previous_whitespace = false;
+        for (i, c) in paragraph.char_indices() {
+            let i = paragraph_range.start + i;
+            let whitespace = c.is_whitespace();
+            let is_break = match wrap {
+                TextWrap::CharWrap => !whitespace,
+                _ => previous_whitespace && !whitespace,
+            };
+            if is_break && i > word_start {
+                words.push(word_start..i);
+                word_start = i;
+            }
+            previous_whitespace = whitespace;
+        }
+        words.push(word_start..paragraph_range.end);
+
+        let mut line_start = paragraph_range.start;
+        let mut line_width = 0.;
+        for word in words {
+            let trimmed_end = word.start + text[word.clone()].trim_end().len();
+            let word_width: f32 = style_runs(word.start..trimmed_end)
+                .into_iter()
+                .map(|(range, paint_index)| measure(range, paint_index))
+                .sum();
+            if word.start > line_start && line_width + word_width > max_size.width {
+                lines.push(line_start..word.start);
+                line_start = word.start;
+                line_width = 0.;
+            }
+            line_width += style_runs(word)
+                .into_iter()
+                .map(|(range, paint_index)| measure(range, paint_index))
+                .sum::<f32>();
+        }
+        lines.push(line_start..paragraph_range.end);
+    }
+
+    let mut layout = StyledTextLayout { runs: Vec::new(), width: 0., height: 0. };
+    // The runs of each line, with the line's width, height and ascent
+    let lines = lines
+        .into_iter()
+        .map(|line| {
+            let mut runs = style_runs(line.clone());
+            if runs.is_empty() {
+                runs.push((line.start..line.start, paint_index_at(line.start)));
+            }
+            let (mut ascent, mut descent, mut height) = (0f32, 0f32, 0f32);
+            for (_, paint_index) in &runs {
+                if let Ok(metrics) = text_context.measure_font(&paints[*paint_index]) {
+                    ascent = ascent.max(metrics.ascender());
+                    descent = descent.min(metrics.descender());
+                    height = height.max(metrics.height());
+                }
+            }
+            let height = height.max(ascent - descent);
+            let trimmed_end = line.start + text[line.clone()].trim_end().len();
+            let width: f32 = style_runs(line.start..trimmed_end)
+                .into_iter()
+                .map(|(range, paint_index)| measure(range, paint_index))
+                .sum();
+            layout.width = layout.width.max(width);
+            layout.height += height;
+            (runs, width, height, ascent)
+        })
+        .collect::<Vec<_>>();
+
+    let mut y = match vertical_alignment {
+        TextVerticalAlignment::Top => 0.,
+        TextVerticalAlignment::Center => (max_size.height - layout.height) / 2.,
+        TextVerticalAlignment::Bottom => max_size.height - layout.height,
+    };
+    for (runs, width, height, ascent) in lines {
+        if y + height > max_size.height {
+            break;
+        }
+        let mut x = match horizontal_alignment {
+            TextHorizontalAlignment::Left | TextHorizontalAlignment::Justify => 0.,
+            TextHorizontalAlignment::Center => (max_size.width - max_size.width.min(width)) / 2.,
+            TextHorizontalAlignment::Right => max_size.width - max_size.width.min(width),
+        };
+        for (byte_range, paint_index) in runs {
+            let width = measure(byte_range.clone(), paint_index);
+            layout.runs.push(StyledTextRun {
+                byte_range,
+                paint_index,
+                x,
+                width,
+                baseline_y: y + ascent,
+                line_y: y,
+                line_height: height,
+            });
+            x += width;
+        }
+        y += height;
+    }
+    layout
+}
@@ -7,7 +7,7 @@
 use i_slint_core::graphics::euclid;
 #[cfg(not(target_arch = "wasm32"))]
 use i_slint_core::graphics::BorrowedOpenGLTexture;
-use i_slint_core::graphics::{ImageCacheKey, IntSize, SharedImageBuffer};
+use i_slint_core::graphics::{ConicGradientBrush, ImageCacheKey, IntSize, SharedImageBuffer};
 use i_slint_core::items::ImageTiling;
 use i_slint_core::lengths::PhysicalPx;
 use i_slint_core::{items::ImageRendering, ImageInner};
@@ -246,13 +246,16 @@ pub fn new(
 
 // Cache used to avoid repeatedly decoding images from disk. Entries with a count
 // of 1 are drained after flushing the renderer commands to the screen.
-pub struct TextureCache<R: femtovg::Renderer + TextureImporter>(
-    HashMap<TextureCacheKey, Rc<Texture<R>>>,
-);
+pub struct TextureCache<R: femtovg::Renderer + TextureImporter> {
+    images: HashMap<TextureCacheKey, Rc<Texture<R>>>,
+    // FemtoVG doesn't support conic gradients, so they're rendered into textures, keyed
+    // on the gradient and the size in pixels.
+    conic_gradients: Vec<(ConicGradientBrush, IntSize, Rc<Texture<R>>)>,
+}
 
 impl<R: femtovg::Renderer + TextureImporter> Default for TextureCache<R> {
     fn default() -> Self {
-        Self(Default::default())
+        Self { images: Default::default(), conic_gradients: Default::default() }
     }
 }
 
@@ -264,7 +267,7 @@ pub(crate) fn lookup_image_in_cache_or_create(
         cache_key: TextureCacheKey,
         image_create_fn: impl Fn() -> Option<Rc<Texture<R>>>,
     ) -> Option<Rc<Texture<R>>> {
-        Some(match self.0.entry(cache_key) {
+        Some(match self.images.entry(cache_key) {
             std::collections::hash_map::Entry::Occupied(existing_entry) => {
                 existing_entry.get().clone()
             }
@@ -276,8 +279,28 @@ pub(crate) fn lookup_image_in_cache_or_create(
         })
     }
 
+    // Look up the texture of the given conic gradient at the given size, otherwise render it
+    // with the given callback.
+    pub(crate) fn lookup_conic_gradient_or_create(
+        &mut self,
+        gradient: &ConicGradientBrush,
+        size: IntSize,
+        texture_create_fn: impl FnOnce() -> Option<Rc<Texture<R>>>,
+    ) -> Option<Rc<Texture<R>>> {
+        if let Some((_, _, texture)) =
+            self.conic_gradients.iter().find(|(g, s, _)| g == gradient && *s == size)
+        {
+            return Some(texture.clone());
+        }
+        let texture = texture_create_fn()?;
+        self.conic_gradients.push((gradient.clone(), size, texture.clone()));
+        Some(texture)
+    }
+
     pub(crate) fn drain(&mut self) {
-        self.0.retain(|_, cached_image| {
+        // Gradient textures used in this frame are still referenced until the renderer is dropped.
+        self.conic_gradients.retain(|(_, _, texture)| Rc::strong_count(texture) > 1);
+        self.images.retain(|_, cached_image| {
             // * Retain images that are used by elements, so that they can be effectively
             // shared (one image element refers to foo.png, another element is created
             // and refers to the same -> share).
@@ -291,7 +314,8 @@ pub(crate) fn drain(&mut self) {
     }
 
     pub(crate) fn clear(&mut self) {
-        self.0.clear();
+        self.images.clear();
+        self.conic_gradients.clear();
     }
 }
 
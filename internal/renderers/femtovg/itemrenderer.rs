@@ -10,7 +10,7 @@
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::euclid::{self};
 use i_slint_core::graphics::rendering_metrics_collector::RenderingMetrics;
-use i_slint_core::graphics::{IntRect, Point, Size};
+use i_slint_core::graphics::{IntRect, IntSize, Point, Size};
 use i_slint_core::item_rendering::{
     CachedRenderingData, ItemCache, ItemRenderer, RenderBorderRectangle, RenderImage,
     RenderRectangle, RenderText,
@@ -404,6 +404,60 @@ fn draw_text(
         );
     }
 
+    fn draw_styled_text(
+        &mut self,
+        styled_text: Pin<&items::StyledText>,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+        _cache: &CachedRenderingData,
+    ) {
+        let max_width = size.width_length() * self.scale_factor;
+        let max_height = size.height_length() * self.scale_factor;
+
+        if max_width.get() <= 0. || max_height.get() <= 0. {
+            return;
+        }
+
+        if self.global_alpha_transparent() {
+            return;
+        }
+
+        let styled_string = styled_text.styled_string();
+        let text_path = rect_to_path((size * self.scale_factor).into());
+        let paints = fonts::styled_text_paints(
+            &RenderText::font_request(styled_text, self_rc),
+            self.scale_factor,
+            &styled_string,
+            |style| {
+                self.brush_to_paint(styled_text.span_color(style), &text_path)
+                    .unwrap_or_else(|| femtovg::Paint::color(femtovg::Color::rgba(0, 0, 0, 0)))
+            },
+        );
+
+        let layout = fonts::layout_styled_text(
+            &styled_string,
+            &paints,
+            PhysicalSize::from_lengths(max_width, max_height),
+            (styled_text.horizontal_alignment(), styled_text.vertical_alignment()),
+            styled_text.wrap(),
+        );
+
+        let mut canvas = self.canvas.borrow_mut();
+        for run in &layout.runs {
+            let paint = &paints[run.paint_index];
+            let run_text = &styled_string.text[run.byte_range.clone()];
+            canvas.fill_text(run.x, run.baseline_y, run_text, paint).unwrap();
+            let is_link = run.paint_index > 0
+                && styled_string.spans[run.paint_index - 1].style.link.is_some();
+            if is_link && !run_text.trim().is_empty() {
+                let thickness = (paint.font_size() / 16.).max(1.);
+                let mut underline = femtovg::Path::new();
+                underline.rect(run.x, run.baseline_y + thickness, run.width, thickness);
+                canvas.fill_path(&underline, paint);
+            }
+        }
+    }
+
     fn draw_text_input(
         &mut self,
         text_input: Pin<&items::TextInput>,
@@ -472,76 +526,58 @@ fn draw_text_input(
                         || range.contains(&max_select)
                         || (min_select..max_select).contains(&start))
                 {
-                    let mut selection_start_x = PhysicalLength::default();
-                    let mut selection_end_x = PhysicalLength::default();
-                    let mut after_selection_x = PhysicalLength::default();
-                    // Determine the first and last (inclusive) glyph of the selection. The anchor
-                    // will always be at the start of a grapheme boundary, so there's at ShapedGlyph
-                    // that has a matching byte index. For the selection end we have to look for the
-                    // visual end of glyph before the cursor, because due to for example ligatures
-                    // (or generally glyph substitution) there may not be a dedicated glyph.
+                    // Collect the visual spans of selected glyphs. With bidirectional text, a
+                    // logical selection can map to several disjoint spans on the line.
                     // FIXME: in the case of ligature, there is currently no way to know the exact
-                    // position of the split. When we know it, we might need to draw in two
-                    // steps with clip to draw each part of the ligature in a different color
+                    // position of the split, so the whole ligature is either selected or not.
+                    let mut selection_spans: Vec<(f32, f32)> = Vec::new();
+                    let mut previous_selected = false;
                     for glyph in &metrics.glyphs {
-                        if glyph.byte_index == min_select.saturating_sub(start) {
-                            selection_start_x = PhysicalLength::new(glyph.x - glyph.bearing_x);
-                        }
-                        if glyph.byte_index == max_select - start
-                            || glyph.byte_index >= to_draw.len()
-                        {
-                            after_selection_x = PhysicalLength::new(glyph.x - glyph.bearing_x);
-                            break;
+                        let selected = glyph.byte_index < to_draw.trim_end().len()
+                            && (min_select..max_select).contains(&(start + glyph.byte_index));
+                        let glyph_start = glyph.x - glyph.bearing_x;
+                        let glyph_end = glyph_start + glyph.advance_x;
+                        if selected {
+                            match selection_spans.last_mut() {
+                                Some(span) if previous_selected => span.1 = glyph_end,
+                                _ => selection_spans.push((glyph_start, glyph_end)),
+                            }
                         }
-                        selection_end_x = PhysicalLength::new(glyph.x + glyph.advance_x);
+                        previous_selected = selected;
                     }
 
-                    let selection_rect = PhysicalRect::new(
-                        pos + PhysicalPoint::from_lengths(
-                            selection_start_x,
-                            PhysicalLength::default(),
+                    let selection_rects = selection_spans.iter().map(|(start_x, end_x)| {
+                        PhysicalRect::new(
+                            pos + euclid::vec2(*start_x, 0.),
+                            PhysicalSize::new(end_x - start_x, font_height.get()),
                         )
-                        .to_vector(),
-                        PhysicalSize::from_lengths(
-                            selection_end_x - selection_start_x,
-                            font_height,
-                        ),
-                    );
-                    canvas.fill_path(
-                        &rect_to_path(selection_rect),
-                        &femtovg::Paint::color(to_femtovg_color(
-                            &text_input.selection_background_color(),
-                        )),
-                    );
+                    });
+                    for selection_rect in selection_rects.clone() {
+                        canvas.fill_path(
+                            &rect_to_path(selection_rect),
+                            &femtovg::Paint::color(to_femtovg_color(
+                                &text_input.selection_background_color(),
+                            )),
+                        );
+                    }
+                    canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+                    // Draw the line again with the selection color, clipped to the selection
                     let mut selected_paint = paint.clone();
                     selected_paint
                         .set_color(to_femtovg_color(&text_input.selection_foreground_color()));
-                    canvas
-                        .fill_text(
-                            pos.x,
-                            pos.y,
-                            to_draw[..min_select.saturating_sub(start)].trim_end(),
-                            &paint,
-                        )
-                        .unwrap();
-                    canvas
-                        .fill_text(
-                            pos.x + selection_start_x.get(),
-                            pos.y,
-                            to_draw[min_select.saturating_sub(start)
-                                ..(max_select - start).min(to_draw.len())]
-                                .trim_end(),
-                            &selected_paint,
-                        )
-                        .unwrap();
-                    canvas
-                        .fill_text(
-                            pos.x + after_selection_x.get(),
-                            pos.y,
-                            to_draw[(max_select - start).min(to_draw.len())..].trim_end(),
-                            &paint,
-                        )
-                        .unwrap();
+                    for selection_rect in selection_rects {
+                        canvas.save();
+                        canvas.intersect_scissor(
+                            selection_rect.min_x(),
+                            selection_rect.min_y(),
+                            selection_rect.width(),
+                            selection_rect.height(),
+                        );
+                        canvas
+                            .fill_text(pos.x, pos.y, to_draw.trim_end(), &selected_paint)
+                            .unwrap();
+                        canvas.restore();
+                    }
                 } else {
                     // no selection on this line
                     canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
@@ -1579,6 +1615,57 @@ fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::
                     stops,
                 )
             }
+            Brush::ConicGradient(gradient) => {
+                // FemtoVG doesn't support conic gradients, so render the gradient into an image
+                let path_bounds = path_bounding_box(&self.canvas, path);
+                let size = euclid::size2(path_bounds.width(), path_bounds.height());
+                let width = size.width.ceil().max(1.) as usize;
+                let height = size.height.ceil().max(1.) as usize;
+                let texture = self.texture_cache.borrow_mut().lookup_conic_gradient_or_create(
+                    &gradient,
+                    IntSize::new(width as u32, height as u32),
+                    || {
+                        let pixels = (0..width * height)
+                            .map(|i| {
+                                let pos = euclid::point2(
+                                    (i % width) as f32 + 0.5,
+                                    (i / width) as f32 + 0.5,
+                                );
+                                let color = gradient.color_at(pos, size);
+                                let premultiply =
+                                    |c: u8| (c as u16 * color.alpha() as u16 / 255) as u8;
+                                rgb::RGBA8::new(
+                                    premultiply(color.red()),
+                                    premultiply(color.green()),
+                                    premultiply(color.blue()),
+                                    color.alpha(),
+                                )
+                            })
+                            .collect::<Vec<_>>();
+                        let image_id = self
+                            .canvas
+                            .borrow_mut()
+                            .create_image(
+                                imgref::Img::new(pixels.as_slice(), width, height),
+                                femtovg::ImageFlags::PREMULTIPLIED,
+                            )
+                            .ok()?;
+                        Some(Texture::adopt(&self.canvas, image_id))
+                    },
+                )?;
+                let paint = femtovg::Paint::image(
+                    texture.id,
+                    path_bounds.min.x,
+                    path_bounds.min.y,
+                    width as f32,
+                    height as f32,
+                    0.,
+                    1.,
+                );
+                // The image is referenced by the paint until the canvas is flushed
+                self.textures_to_delete_after_flush.borrow_mut().push(texture);
+                paint
+            }
             _ => return None,
         })
     }
@@ -315,6 +315,76 @@ fn font_metrics(
         crate::fonts::font_metrics(font_request)
     }
 
+    fn styled_text_size(
+        &self,
+        font_request: FontRequest,
+        styled_text: &i_slint_core::items::StyledString,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        text_wrap: TextWrap,
+    ) -> LogicalSize {
+        let paints =
+            crate::fonts::styled_text_paints(&font_request, scale_factor, styled_text, |_| {
+                Default::default()
+            });
+        let layout = crate::fonts::layout_styled_text(
+            styled_text,
+            &paints,
+            PhysicalSize::new(max_width.map_or(f32::MAX, |w| (w * scale_factor).get()), f32::MAX),
+            Default::default(),
+            if max_width.is_some() { text_wrap } else { TextWrap::NoWrap },
+        );
+        PhysicalSize::new(layout.width, layout.height) / scale_factor
+    }
+
+    fn styled_text_byte_offset_for_position(
+        &self,
+        styled_text: Pin<&i_slint_core::items::StyledText>,
+        pos: LogicalPoint,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        let pos = pos * scale_factor;
+        let width = styled_text.width() * scale_factor;
+        let height = styled_text.height() * scale_factor;
+        if width.get() <= 0. || height.get() <= 0. {
+            return None;
+        }
+
+        let styled_string = styled_text.styled_string();
+        let paints =
+            crate::fonts::styled_text_paints(&font_request, scale_factor, &styled_string, |_| {
+                Default::default()
+            });
+        let layout = crate::fonts::layout_styled_text(
+            &styled_string,
+            &paints,
+            PhysicalSize::from_lengths(width, height),
+            (styled_text.horizontal_alignment(), styled_text.vertical_alignment()),
+            styled_text.wrap(),
+        );
+
+        let run = layout.runs.iter().find(|run| {
+            (run.line_y..run.line_y + run.line_height).contains(&pos.y)
+                && (run.x..run.x + run.width).contains(&pos.x)
+        })?;
+        let text_context =
+            crate::fonts::FONT_CACHE.with(|cache| cache.borrow().text_context.clone());
+        let metrics = text_context
+            .measure_text(
+                run.x,
+                0.,
+                &styled_string.text[run.byte_range.clone()],
+                &paints[run.paint_index],
+            )
+            .ok()?;
+        metrics
+            .glyphs
+            .iter()
+            .find(|glyph| (glyph.x..glyph.x + glyph.advance_x).contains(&pos.x))
+            .map(|glyph| run.byte_range.start + glyph.byte_index)
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
@@ -354,15 +424,34 @@ fn text_input_byte_offset_for_position(
             &paint,
             |line_text, line_pos, start, metrics| {
                 if (line_pos.y..(line_pos.y + font_height)).contains(&pos.y) {
+                    let levels = crate::fonts::bidi_levels(line_text);
+                    let line_end = line_text.trim_end().len();
                     let mut current_x = 0.;
                     for glyph in &metrics.glyphs {
+                        let is_rtl = levels.get(glyph.byte_index).is_some_and(|l| l.is_rtl());
                         if line_pos.x + current_x + glyph.advance_x / 2. >= pos.x {
+                            // The left half of a right-to-left glyph is after it in logical order
+                            result = start
+                                + if is_rtl {
+                                    metrics
+                                        .glyphs
+                                        .iter()
+                                        .map(|g| g.byte_index)
+                                        .filter(|i| *i > glyph.byte_index)
+                                        .min()
+                                        .unwrap_or(line_end)
+                                } else {
+                                    glyph.byte_index
+                                };
+                            return;
+                        }
+                        if is_rtl && line_pos.x + current_x + glyph.advance_x >= pos.x {
                             result = start + glyph.byte_index;
                             return;
                         }
                         current_x += glyph.advance_x;
                     }
-                    result = start + line_text.trim_end().len();
+                    result = start + line_end;
                 }
             },
         );
@@ -102,6 +102,14 @@ pub(crate) fn as_skia_image(
         ),
         #[cfg(feature = "unstable-wgpu-24")]
         ImageInner::WGPUTexture(..) => None,
+        ImageInner::AnimatedImage(animated) => as_skia_image(
+            animated.frame(0),
+            target_size_fn,
+            image_fit,
+            scale_factor,
+            canvas,
+            surface,
+        ),
     }
 }
 
@@ -141,6 +141,26 @@ fn brush_to_shader(
                         as &skia_safe::Matrix,
                 )
             }
+            Brush::ConicGradient(g) => {
+                let (colors, pos): (Vec<_>, Vec<_>) =
+                    g.stops().map(|s| (to_skia_color(&s.color), s.position)).unzip();
+                let center = g.center();
+
+                paint.set_dither(true);
+
+                // Skia's sweep starts at 3 o'clock, while the conic gradient starts at the top
+                skia_safe::gradient_shader::sweep(
+                    skia_safe::Point::new(0., 0.),
+                    skia_safe::gradient_shader::GradientShaderColors::Colors(&colors),
+                    Some(&*pos),
+                    TileMode::Clamp,
+                    None,
+                    skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
+                    skia_safe::Matrix::rotate_deg(g.from_angle() - 90.)
+                        .post_translate((center.x * width.get(), center.y * height.get()))
+                        as &skia_safe::Matrix,
+                )
+            }
             _ => None,
         }
         .map(|shader| (paint, shader))
@@ -599,6 +619,65 @@ fn draw_text(
         };
     }
 
+    fn draw_styled_text(
+        &mut self,
+        styled_text: Pin<&i_slint_core::items::StyledText>,
+        self_rc: &i_slint_core::items::ItemRc,
+        size: LogicalSize,
+        _cache: &CachedRenderingData,
+    ) {
+        let max_width = size.width_length() * self.scale_factor;
+        let max_height = size.height_length() * self.scale_factor;
+
+        if max_width.get() <= 0. || max_height.get() <= 0. {
+            return;
+        }
+
+        let styled_string = styled_text.styled_string();
+        let font_request = RenderText::font_request(styled_text, self_rc);
+
+        let paint = match self.brush_to_paint(styled_text.color(), max_width, max_height) {
+            Some(paint) => paint,
+            None => return,
+        };
+
+        let mut text_style = skia_safe::textlayout::TextStyle::new();
+        text_style.set_foreground_paint(&paint);
+
+        let span_paints = styled_string
+            .spans
+            .iter()
+            .map(|span| {
+                self.brush_to_paint(
+                    styled_text.span_color(Some(&span.style)),
+                    max_width,
+                    max_height,
+                )
+                .unwrap_or_else(|| {
+                    let mut transparent_paint = skia_safe::Paint::default();
+                    transparent_paint.set_alpha(0);
+                    transparent_paint
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let (layout, layout_top_left) = super::textlayout::create_styled_layout(
+            font_request,
+            self.scale_factor,
+            &styled_string,
+            Some(text_style),
+            &span_paints,
+            Some(max_width),
+            max_height,
+            styled_text.horizontal_alignment(),
+            styled_text.vertical_alignment(),
+            styled_text.wrap(),
+            styled_text.overflow(),
+        );
+
+        layout.paint(self.canvas, to_skia_point(layout_top_left));
+    }
+
     fn draw_text_input(
         &mut self,
         text_input: Pin<&i_slint_core::items::TextInput>,
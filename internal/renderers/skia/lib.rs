@@ -778,6 +778,98 @@ fn font_metrics(
         textlayout::font_metrics(font_request, scale_factor)
     }
 
+    fn styled_text_size(
+        &self,
+        font_request: i_slint_core::graphics::FontRequest,
+        styled_text: &i_slint_core::items::StyledString,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        _text_wrap: TextWrap, //TODO: Add support for char-wrap
+    ) -> LogicalSize {
+        let (layout, _) = textlayout::create_styled_layout(
+            font_request,
+            scale_factor,
+            styled_text,
+            None,
+            &[],
+            max_width.map(|w| w * scale_factor),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+
+        PhysicalSize::new(layout.max_intrinsic_width().ceil(), layout.height().ceil())
+            / scale_factor
+    }
+
+    fn styled_text_byte_offset_for_position(
+        &self,
+        styled_text: std::pin::Pin<&i_slint_core::items::StyledText>,
+        pos: LogicalPoint,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        let max_width = styled_text.width() * scale_factor;
+        let max_height = styled_text.height() * scale_factor;
+        let pos = pos * scale_factor;
+
+        if max_width.get() <= 0. || max_height.get() <= 0. {
+            return None;
+        }
+
+        let styled_string = styled_text.styled_string();
+
+        let (layout, layout_top_left) = textlayout::create_styled_layout(
+            font_request,
+            scale_factor,
+            &styled_string,
+            None,
+            &[],
+            Some(max_width),
+            max_height,
+            styled_text.horizontal_alignment(),
+            styled_text.vertical_alignment(),
+            styled_text.wrap(),
+            styled_text.overflow(),
+        );
+
+        let pos = (pos.x, pos.y - layout_top_left.y);
+        if pos.1 < 0. || pos.1 >= layout.height() {
+            return None;
+        }
+        // The position is the one of the cursor nearest to the coordinate, the affinity tells
+        // whether the glyph under the coordinate is before or after it.
+        let position = layout.get_glyph_position_at_coordinate(pos);
+        let utf16_index = match position.affinity {
+            skia_safe::textlayout::Affinity::Downstream => position.position,
+            skia_safe::textlayout::Affinity::Upstream => position.position - 1,
+        };
+        if utf16_index < 0 {
+            return None;
+        }
+
+        let mut utf16_count = 0;
+        let (byte_offset, ch) = styled_string.text.char_indices().find(|(_, x)| {
+            let r = utf16_count >= utf16_index;
+            utf16_count += x.len_utf16() as i32;
+            r
+        })?;
+
+        // Only return the character if its glyph is actually under the coordinate
+        let utf16_range = utf16_index as usize..utf16_index as usize + ch.len_utf16();
+        layout
+            .get_rects_for_range(
+                utf16_range,
+                skia_safe::textlayout::RectHeightStyle::Max,
+                skia_safe::textlayout::RectWidthStyle::Tight,
+            )
+            .iter()
+            .any(|text_box| text_box.rect.contains(skia_safe::Point::new(pos.0, pos.1)))
+            .then_some(byte_offset)
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: std::pin::Pin<&i_slint_core::items::TextInput>,
@@ -70,21 +70,11 @@ fn font_style_for_request(font_request: &FontRequest) -> skia_safe::FontStyle {
     )
 }
 
-pub fn create_layout(
-    font_request: FontRequest,
+fn text_style_for_request(
+    font_request: &FontRequest,
     scale_factor: ScaleFactor,
-    text: &str,
-    text_style: Option<skia_safe::textlayout::TextStyle>,
-    max_width: Option<PhysicalLength>,
-    max_height: PhysicalLength,
-    h_align: items::TextHorizontalAlignment,
-    v_align: TextVerticalAlignment,
-    wrap: items::TextWrap,
-    overflow: items::TextOverflow,
-    selection: Option<&Selection>,
-) -> (skia_safe::textlayout::Paragraph, PhysicalPoint) {
-    let mut text_style = text_style.unwrap_or_default();
-
+    mut text_style: skia_safe::textlayout::TextStyle,
+) -> skia_safe::textlayout::TextStyle {
     if let Some(family_name) = font_request.family.as_ref() {
         text_style.set_font_families(&[family_name.as_str()]);
     }
@@ -95,8 +85,17 @@ pub fn create_layout(
         text_style.set_letter_spacing((letter_spacing * scale_factor).get());
     }
     text_style.set_font_size(pixel_size.get());
-    text_style.set_font_style(font_style_for_request(&font_request));
+    text_style.set_font_style(font_style_for_request(font_request));
+    text_style
+}
 
+fn paragraph_builder(
+    text_style: &skia_safe::textlayout::TextStyle,
+    max_height: PhysicalLength,
+    h_align: items::TextHorizontalAlignment,
+    wrap: items::TextWrap,
+    overflow: items::TextOverflow,
+) -> skia_safe::textlayout::ParagraphBuilder {
     let mut style = skia_safe::textlayout::ParagraphStyle::new();
 
     if overflow == items::TextOverflow::Elide {
@@ -112,16 +111,56 @@ pub fn create_layout(
         items::TextHorizontalAlignment::Left => skia_safe::textlayout::TextAlign::Left,
         items::TextHorizontalAlignment::Center => skia_safe::textlayout::TextAlign::Center,
         items::TextHorizontalAlignment::Right => skia_safe::textlayout::TextAlign::Right,
+        items::TextHorizontalAlignment::Justify => skia_safe::textlayout::TextAlign::Justify,
     });
 
-    style.set_text_style(&text_style);
+    style.set_text_style(text_style);
 
-    let mut builder = FONT_CACHE.with(|font_cache| {
+    FONT_CACHE.with(|font_cache| {
         skia_safe::textlayout::ParagraphBuilder::new(
             &style,
             font_cache.font_collection.borrow().clone(),
         )
-    });
+    })
+}
+
+fn layout_paragraph(
+    mut builder: skia_safe::textlayout::ParagraphBuilder,
+    max_width: Option<PhysicalLength>,
+    max_height: PhysicalLength,
+    v_align: TextVerticalAlignment,
+) -> (skia_safe::textlayout::Paragraph, PhysicalPoint) {
+    let mut paragraph = builder.build();
+    paragraph.layout(max_width.map_or(f32::MAX, |physical_width| physical_width.get()));
+
+    let layout_height = PhysicalLength::new(paragraph.height());
+
+    let layout_top_y = match v_align {
+        i_slint_core::items::TextVerticalAlignment::Top => PhysicalLength::zero(),
+        i_slint_core::items::TextVerticalAlignment::Center => (max_height - layout_height) / 2.,
+        i_slint_core::items::TextVerticalAlignment::Bottom => max_height - layout_height,
+    };
+
+    (paragraph, PhysicalPoint::from_lengths(Default::default(), layout_top_y))
+}
+
+pub fn create_layout(
+    font_request: FontRequest,
+    scale_factor: ScaleFactor,
+    text: &str,
+    text_style: Option<skia_safe::textlayout::TextStyle>,
+    max_width: Option<PhysicalLength>,
+    max_height: PhysicalLength,
+    h_align: items::TextHorizontalAlignment,
+    v_align: TextVerticalAlignment,
+    wrap: items::TextWrap,
+    overflow: items::TextOverflow,
+    selection: Option<&Selection>,
+) -> (skia_safe::textlayout::Paragraph, PhysicalPoint) {
+    let text_style =
+        text_style_for_request(&font_request, scale_factor, text_style.unwrap_or_default());
+
+    let mut builder = paragraph_builder(&text_style, max_height, h_align, wrap, overflow);
 
     if let Some(selection) = selection {
         let before_selection = &text[..selection.range.start];
@@ -159,18 +198,58 @@ pub fn create_layout(
         builder.add_text(text);
     }
 
-    let mut paragraph = builder.build();
-    paragraph.layout(max_width.map_or(f32::MAX, |physical_width| physical_width.get()));
+    layout_paragraph(builder, max_width, max_height, v_align)
+}
 
-    let layout_height = PhysicalLength::new(paragraph.height());
+/// Creates the paragraph for the text of a `StyledText` element, with a text style for each span.
+/// `span_paints` contains the foreground paint of each span. When it's empty, the spans use the
+/// foreground of `text_style`.
+pub fn create_styled_layout(
+    font_request: FontRequest,
+    scale_factor: ScaleFactor,
+    styled_text: &items::StyledString,
+    text_style: Option<skia_safe::textlayout::TextStyle>,
+    span_paints: &[skia_safe::Paint],
+    max_width: Option<PhysicalLength>,
+    max_height: PhysicalLength,
+    h_align: items::TextHorizontalAlignment,
+    v_align: TextVerticalAlignment,
+    wrap: items::TextWrap,
+    overflow: items::TextOverflow,
+) -> (skia_safe::textlayout::Paragraph, PhysicalPoint) {
+    let text_style =
+        text_style_for_request(&font_request, scale_factor, text_style.unwrap_or_default());
 
-    let layout_top_y = match v_align {
-        i_slint_core::items::TextVerticalAlignment::Top => PhysicalLength::zero(),
-        i_slint_core::items::TextVerticalAlignment::Center => (max_height - layout_height) / 2.,
-        i_slint_core::items::TextVerticalAlignment::Bottom => max_height - layout_height,
-    };
+    let mut builder = paragraph_builder(&text_style, max_height, h_align, wrap, overflow);
 
-    (paragraph, PhysicalPoint::from_lengths(Default::default(), layout_top_y))
+    let text = styled_text.text.as_str();
+    let mut unstyled_start = 0;
+    for (index, span) in styled_text.spans.iter().enumerate() {
+        builder.add_text(&text[unstyled_start..span.byte_range.start]);
+
+        let mut span_style = text_style_for_request(
+            &span.style.font_request(&font_request),
+            scale_factor,
+            text_style.clone(),
+        );
+        if let Some(paint) = span_paints.get(index) {
+            span_style.set_foreground_paint(paint);
+        }
+        if span.style.link.is_some() {
+            let mut decoration = skia_safe::textlayout::Decoration::default();
+            decoration.ty = skia_safe::textlayout::TextDecoration::UNDERLINE;
+            decoration.color = span_style.foreground().color();
+            span_style.set_decoration(&decoration);
+        }
+
+        builder.push_style(&span_style);
+        builder.add_text(&text[span.byte_range.clone()]);
+        builder.pop();
+        unstyled_start = span.byte_range.end;
+    }
+    builder.add_text(&text[unstyled_start..]);
+
+    layout_paragraph(builder, max_width, max_height, v_align)
 }
 
 pub fn font_metrics(
@@ -259,7 +338,9 @@ pub fn cursor_rect(
 ) -> PhysicalRect {
     if string.is_empty() {
         let x = match h_align {
-            TextHorizontalAlignment::Left => PhysicalLength::default(),
+            TextHorizontalAlignment::Left | TextHorizontalAlignment::Justify => {
+                PhysicalLength::default()
+            }
             TextHorizontalAlignment::Center => PhysicalLength::new(layout.max_width() / 2.),
             TextHorizontalAlignment::Right => PhysicalLength::new(layout.max_width()),
         };
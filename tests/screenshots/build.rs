@@ -102,6 +102,16 @@ fn main() -> std::io::Result<()> {
         });
         let skip_clipping = source.contains("SKIP_CLIPPING");
 
+        let needle = "ELAPSED_TIME=";
+        let elapsed_time: u64 = source.find(needle).map_or(0, |p| {
+            source[p + needle.len()..]
+                .find(char::is_whitespace)
+                .and_then(|end| source[p + needle.len()..][..end].parse().ok())
+                .unwrap_or_else(|| {
+                    panic!("Cannot parse {needle} for {}", testcase.relative_path.display())
+                })
+        });
+
         let needle = "SIZE=";
         let (size_w, size_h) = source.find(needle).map_or((64, 64), |p| {
             source[p + needle.len()..]
@@ -139,6 +149,7 @@ fn main() -> std::io::Result<()> {
 
     let instance = TestCase::new().unwrap();
     instance.show().unwrap();
+    i_slint_core::tests::slint_mock_elapsed_time({elapsed_time});
 
     testing::assert_with_render(screenshot, window.clone(), &options);
 
@@ -43,7 +43,9 @@ enum Embedding {
 #[command(author, version, about, long_about = None)]
 struct Cli {
     /// Set the output format for generated code.
-    /// Possible values: 'cpp' for C++ code or 'rust' for Rust code.
+    /// Possible values: 'cpp' for C++ code, 'rust' for Rust code, 'typescript' for
+    /// TypeScript declarations (`.d.ts`) of the module loaded by the Node.js API, or 'python'
+    /// for a typed Python module (`.py`) that loads the .slint file next to it.
     #[arg(short = 'f', long = "format", default_value = "cpp")]
     format: generator::OutputFormat,
 
@@ -108,6 +110,16 @@ struct Cli {
     /// If specified multiple times, the definitions are split across multiple `.cpp` files.
     #[arg(long = "cpp-file", name = "output .cpp file", number_of_values = 1)]
     cpp_files: Vec<std::path::PathBuf>,
+
+    /// Warn about unused private properties, functions, callbacks, struct fields and imports.
+    #[arg(long = "warn-unused")]
+    warn_unused: bool,
+
+    /// Write a JSON report with the number of items, properties, bindings, repeaters and
+    /// inlined sub-components of each component, the size of the embedded resources, and the
+    /// time spent in each compiler pass.
+    #[arg(long = "stats", name = "stats file")]
+    stats: Option<std::path::PathBuf>,
 }
 
 fn main() -> std::io::Result<()> {
@@ -184,6 +196,10 @@ fn main() -> std::io::Result<()> {
     if let Some(path) = args.bundle_translations {
         compiler_config.translation_path_bundle = Some(path);
     }
+    if args.warn_unused {
+        compiler_config.warn_unused = true;
+    }
+    compiler_config.collect_statistics = args.stats.is_some();
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
     let (doc, diag, loader) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
@@ -219,6 +235,11 @@ fn main() -> std::io::Result<()> {
 
         writeln!(f)?;
     }
+
+    if let Some(stats) = args.stats {
+        let report = statistics::Statistics::collect(&doc).to_json();
+        std::fs::write(stats, report)?;
+    }
     diag.print_warnings_and_exit_on_error();
     Ok(())
 }
@@ -0,0 +1,94 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use crate::Level;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// The name of the configuration file, looked up in the directory of the linted file and its parents
+pub const CONFIG_FILE_NAME: &str = "slint-lint.toml";
+
+/// The configuration of one rule: a `[rules.<name>]` table
+#[derive(Debug, Clone, Default)]
+pub struct RuleConfig {
+    /// The `level` key, if set
+    pub level: Option<Level>,
+    options: toml_edit::Table,
+}
+
+impl RuleConfig {
+    /// The value of a key that is a list of strings
+    pub fn string_list(&self, key: &str) -> Option<Vec<String>> {
+        let array = self.options.get(key)?.as_array()?;
+        Some(array.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+    }
+
+    /// The value of a key that is a list of numbers
+    pub fn number_list(&self, key: &str) -> Option<Vec<f64>> {
+        let array = self.options.get(key)?.as_array()?;
+        Some(
+            array
+                .iter()
+                .filter_map(|v| v.as_float().or_else(|| v.as_integer().map(|i| i as f64)))
+                .collect(),
+        )
+    }
+}
+
+/// The configuration of the linter
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    rules: HashMap<String, RuleConfig>,
+}
+
+impl Config {
+    /// Parse the content of a configuration file
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let document = source.parse::<toml_edit::DocumentMut>().map_err(|e| e.to_string())?;
+        let mut rules = HashMap::new();
+        for (key, item) in document.iter() {
+            if key != "rules" {
+                return Err(format!("Unknown section '{key}'"));
+            }
+            let table = item.as_table().ok_or("'rules' must be a table")?;
+            for (name, item) in table.iter() {
+                let mut options = item
+                    .as_table()
+                    .ok_or_else(|| format!("'rules.{name}' must be a table"))?
+                    .clone();
+                let level = match options.remove("level") {
+                    Some(level) => Some(
+                        level
+                            .as_str()
+                            .ok_or_else(|| format!("'rules.{name}.level' must be a string"))?
+                            .parse::<Level>()?,
+                    ),
+                    None => None,
+                };
+                rules.insert(name.to_string(), RuleConfig { level, options });
+            }
+        }
+        Ok(Self { rules })
+    }
+
+    /// Load the configuration from a file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let source =
+            std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        Self::parse(&source).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    /// Find the closest `slint-lint.toml` in the directory of the given file or its parents
+    pub fn find_for_file(path: &Path) -> Option<PathBuf> {
+        path.ancestors().skip(1).map(|dir| dir.join(CONFIG_FILE_NAME)).find(|p| p.is_file())
+    }
+
+    /// The configuration of a rule, if it has a `[rules.<name>]` table
+    pub fn rule(&self, name: &str) -> Option<&RuleConfig> {
+        self.rules.get(name)
+    }
+
+    pub(crate) fn rule_names(&self) -> impl Iterator<Item = &str> {
+        self.rules.keys().map(String::as_str)
+    }
+}
@@ -0,0 +1,288 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+Linter for `.slint` files.
+
+The linter checks the conventions of a code base that the compiler doesn't enforce, such as
+using the colors of a palette instead of hardcoded colors. It runs on the object tree of
+documents loaded by the [`TypeLoader`](i_slint_compiler::typeloader::TypeLoader), with only the
+import passes applied, so that the tree stays close to the source.
+
+Each check is a [`Rule`] that visits the elements of a document and reports diagnostics through a
+[`LintContext`]. The rules are enabled, disabled, or configured with a [`Config`], usually loaded
+from a `slint-lint.toml` file:
+
+```toml
+[rules.hardcoded-color]
+level = "error"
+allowed-globals = ["Palette"]
+
+[rules.magic-pixel-value]
+level = "warning"
+allowed-values = [0, 1, 2]
+```
+*/
+
+mod config;
+mod rules;
+
+pub use config::{Config, RuleConfig, CONFIG_FILE_NAME};
+pub use rules::builtin_rules;
+
+use i_slint_compiler::diagnostics::{BuildDiagnostics, DiagnosticLevel, Spanned};
+use i_slint_compiler::expression_tree::Expression;
+use i_slint_compiler::langtype::ElementType;
+use i_slint_compiler::object_tree::{Component, Document, ElementRc};
+use std::rc::Rc;
+
+/// The severity of the diagnostics reported by a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    /// The rule doesn't run
+    Off,
+    Warning,
+    Error,
+}
+
+impl std::str::FromStr for Level {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Self::Off),
+            "warning" => Ok(Self::Warning),
+            "error" => Ok(Self::Error),
+            _ => Err(format!("Unknown level '{s}', expected 'off', 'warning', or 'error'")),
+        }
+    }
+}
+
+/// A check run by the linter on every element of a document
+pub trait Rule {
+    /// The name of the rule, used in the configuration file and in the diagnostics
+    fn name(&self) -> &'static str;
+    /// A short description of what the rule checks
+    fn description(&self) -> &'static str;
+    /// The level of the rule when it is not set in the configuration
+    fn default_level(&self) -> Level {
+        Level::Warning
+    }
+    /// Called for each element of each component of the document, parents before children
+    fn check_element(&self, element: &ElementRc, ctx: &mut LintContext);
+}
+
+/// The state passed to [`Rule::check_element`]
+pub struct LintContext<'a> {
+    /// The component that contains the element
+    pub component: &'a Rc<Component>,
+    /// The parents of the element, starting with the root element of the component
+    pub ancestors: &'a [ElementRc],
+    /// The options of the rule from the configuration
+    pub options: &'a RuleConfig,
+    rule: &'static str,
+    level: DiagnosticLevel,
+    diag: &'a mut BuildDiagnostics,
+}
+
+impl LintContext<'_> {
+    /// The direct parent of the element, if it isn't the root of the component
+    pub fn parent(&self) -> Option<&ElementRc> {
+        self.ancestors.last()
+    }
+
+    /// Report a finding of the rule at the given location
+    pub fn report(&mut self, message: impl std::fmt::Display, span: &dyn Spanned) {
+        self.diag.push_diagnostic(format!("{message} ({})", self.rule), span, self.level);
+    }
+}
+
+/// Runs the rules on documents
+pub struct Linter {
+    rules: Vec<Box<dyn Rule>>,
+    config: Config,
+}
+
+impl Linter {
+    /// A linter with the [builtin rules](builtin_rules)
+    pub fn new(config: Config) -> Self {
+        Self { rules: builtin_rules(), config }
+    }
+
+    /// Add a custom rule
+    pub fn add_rule(&mut self, rule: Box<dyn Rule>) {
+        self.rules.push(rule);
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &dyn Rule> {
+        self.rules.iter().map(|r| r.as_ref())
+    }
+
+    /// Returns an error if the configuration refers to a rule that doesn't exist
+    pub fn check_config(&self) -> Result<(), String> {
+        for name in self.config.rule_names() {
+            if !self.rules.iter().any(|r| r.name() == name) {
+                return Err(format!("Unknown rule '{name}' in the configuration"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Run the enabled rules on all the components declared in the document
+    pub fn lint_document(&self, doc: &Document, diag: &mut BuildDiagnostics) {
+        let default_options = RuleConfig::default();
+        for rule in &self.rules {
+            let options = self.config.rule(rule.name()).unwrap_or(&default_options);
+            let level = match options.level.unwrap_or_else(|| rule.default_level()) {
+                Level::Off => continue,
+                Level::Warning => DiagnosticLevel::Warning,
+                Level::Error => DiagnosticLevel::Error,
+            };
+            for component in &doc.inner_components {
+                let mut ancestors = Vec::new();
+                visit_element(
+                    &component.root_element,
+                    &mut ancestors,
+                    &mut |element, ancestors| {
+                        let mut ctx = LintContext {
+                            component,
+                            ancestors,
+                            options,
+                            rule: rule.name(),
+                            level,
+                            diag,
+                        };
+                        rule.check_element(element, &mut ctx);
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn visit_element(
+    element: &ElementRc,
+    ancestors: &mut Vec<ElementRc>,
+    visitor: &mut impl FnMut(&ElementRc, &[ElementRc]),
+) {
+    visitor(element, ancestors);
+    ancestors.push(element.clone());
+    let children = element.borrow().children.clone();
+    for child in &children {
+        visit_element(child, ancestors, visitor);
+    }
+    ancestors.pop();
+}
+
+/// Call the visitor for the expressions of the bindings and state property changes of the
+/// element, with the name of the property and the location of the binding
+pub fn visit_element_expressions(
+    element: &ElementRc,
+    mut visitor: impl FnMut(&str, &Expression, &dyn Spanned),
+) {
+    let element = element.borrow();
+    for (name, binding) in &element.bindings {
+        let binding = binding.borrow();
+        visitor(name, &binding.expression, &*binding);
+    }
+    for state in &element.states {
+        for (property, expression, node) in &state.property_changes {
+            visitor(property.name(), expression, node);
+        }
+    }
+}
+
+/// Returns true if the property is set on the element, or on the root of the component it inherits
+pub fn has_binding(element: &ElementRc, property: &str) -> bool {
+    let mut element = element.clone();
+    loop {
+        if element.borrow().bindings.contains_key(property) {
+            return true;
+        }
+        let base = match &element.borrow().base_type {
+            ElementType::Component(base) => base.root_element.clone(),
+            _ => return false,
+        };
+        element = base;
+    }
+}
+
+/// Returns the name of the builtin element (eg, `TouchArea`) if the element is a builtin
+pub fn builtin_name(element: &ElementRc) -> Option<smol_str::SmolStr> {
+    match &element.borrow().base_type {
+        ElementType::Builtin(builtin) => Some(builtin.name.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+fn lint_source(source: &str, config: &str) -> Vec<String> {
+    let mut diag = BuildDiagnostics::default();
+    let mut type_loader = i_slint_compiler::typeloader::TypeLoader::new(
+        i_slint_compiler::typeregister::TypeRegister::builtin(),
+        i_slint_compiler::CompilerConfiguration::new(
+            i_slint_compiler::generator::OutputFormat::Llr,
+        ),
+        &mut diag,
+    );
+    let path = std::path::Path::new("/test/main.slint");
+    spin_on::spin_on(type_loader.load_file(path, path, source.into(), false, &mut diag));
+    assert!(!diag.has_errors(), "{:?}", diag.to_string_vec());
+    let doc = type_loader.get_document(path).unwrap();
+    let linter = Linter::new(Config::parse(config).unwrap());
+    linter.check_config().unwrap();
+    let mut diag = BuildDiagnostics::default();
+    linter.lint_document(doc, &mut diag);
+    diag.iter().map(|d| format!("{}:{:?}: {}", d.line_column().0, d.level(), d.message())).collect()
+}
+
+#[test]
+fn test_builtin_rules() {
+    let source = r#"
+export global Palette {
+    out property <color> accent: #3f51b5;
+}
+export component App inherits Window {
+    width: 300px;
+    background: Palette.accent;
+    Rectangle {
+        background: #ff0000;
+        border-width: 1px;
+        TouchArea {}
+    }
+    Rectangle {
+        accessible-role: button;
+        TouchArea {}
+        states [
+            pressed when true: { background: red; }
+        ]
+    }
+}
+"#;
+    assert_eq!(
+        lint_source(source, ""),
+        [
+            "9:Warning: Hardcoded color in the binding of 'background' (hardcoded-color)",
+            "17:Warning: Hardcoded color in the binding of 'background' (hardcoded-color)",
+            "11:Warning: TouchArea without an accessible-role on itself, its parent, or its children (touch-area-accessible-role)",
+        ]
+    );
+    assert_eq!(
+        lint_source(
+            source,
+            r#"
+[rules.hardcoded-color]
+allowed-globals = []
+[rules.touch-area-accessible-role]
+level = "off"
+[rules.magic-pixel-value]
+level = "error"
+"#
+        ),
+        [
+            "3:Warning: Hardcoded color in the binding of 'accent' (hardcoded-color)",
+            "9:Warning: Hardcoded color in the binding of 'background' (hardcoded-color)",
+            "17:Warning: Hardcoded color in the binding of 'background' (hardcoded-color)",
+            "6:Error: Magic pixel value 300px in the binding of 'width' (magic-pixel-value)",
+        ]
+    );
+}
@@ -0,0 +1,114 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Tool to check .slint files against configurable rules
+
+use clap::Parser;
+use i_slint_compiler::diagnostics::BuildDiagnostics;
+use i_slint_compiler::typeloader::TypeLoader;
+use slint_lint::{Config, Linter};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    /// The .slint files to check
+    #[arg(name = "path to .slint file(s)", required_unless_present = "list_rules")]
+    paths: Vec<PathBuf>,
+
+    /// The configuration file.
+    /// By default, the closest `slint-lint.toml` in the directory of each file or its parents.
+    #[arg(long, name = "config file")]
+    config: Option<PathBuf>,
+
+    /// Specify include paths for imported .slint files.
+    #[arg(short = 'I', name = "include path", number_of_values = 1)]
+    include_paths: Vec<PathBuf>,
+
+    /// Define library paths in the format `<library>=<path>`.
+    #[arg(short = 'L', name = "library path", number_of_values = 1)]
+    library_paths: Vec<String>,
+
+    /// The style used to resolve the widgets (e.g., 'native' or 'fluent').
+    #[arg(long, name = "style name")]
+    style: Option<String>,
+
+    /// List the available rules and exit.
+    #[arg(long)]
+    list_rules: bool,
+}
+
+fn main() -> std::io::Result<()> {
+    let args = Cli::parse();
+
+    if args.list_rules {
+        for rule in Linter::new(Config::default()).rules() {
+            println!("{:<30} {}", rule.name(), rule.description());
+        }
+        return Ok(());
+    }
+
+    let explicit_config =
+        args.config.as_ref().map(|path| Config::load(path).unwrap_or_else(|e| exit_with_error(&e)));
+
+    let mut compiler_config = i_slint_compiler::CompilerConfiguration::new(
+        i_slint_compiler::generator::OutputFormat::Llr,
+    );
+    compiler_config.include_paths = args.include_paths;
+    compiler_config.library_paths = args
+        .library_paths
+        .iter()
+        .filter_map(|entry| entry.split_once('=').map(|(k, v)| (k.into(), v.into())))
+        .collect();
+    compiler_config.style = args.style;
+
+    let mut type_loader = TypeLoader::new(
+        i_slint_compiler::typeregister::TypeRegister::builtin(),
+        compiler_config,
+        &mut BuildDiagnostics::default(),
+    );
+
+    let mut has_errors = false;
+    for path in &args.paths {
+        let path = i_slint_compiler::pathutils::clean_path(&std::path::absolute(path)?);
+        let source = std::fs::read_to_string(&path)?;
+
+        let config = match &explicit_config {
+            Some(config) => config.clone(),
+            None => match Config::find_for_file(&path) {
+                Some(config_path) => {
+                    Config::load(&config_path).unwrap_or_else(|e| exit_with_error(&e))
+                }
+                None => Config::default(),
+            },
+        };
+        let linter = Linter::new(config);
+        if let Err(e) = linter.check_config() {
+            exit_with_error(&e);
+        }
+
+        let mut diag = BuildDiagnostics::default();
+        spin_on::spin_on(type_loader.load_file(&path, &path, source, false, &mut diag));
+        if diag.has_errors() {
+            has_errors = true;
+            diag.print();
+            continue;
+        }
+        let Some(doc) = type_loader.get_document(&path) else { continue };
+
+        let mut lint_diag = BuildDiagnostics::default();
+        linter.lint_document(doc, &mut lint_diag);
+        has_errors |= lint_diag.has_errors();
+        lint_diag.print();
+    }
+
+    if has_errors {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("error: {message}");
+    std::process::exit(2);
+}
@@ -0,0 +1,15 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+mod hardcoded_color;
+mod magic_pixel_value;
+mod touch_area_accessible_role;
+
+/// The rules that are part of the linter
+pub fn builtin_rules() -> Vec<Box<dyn crate::Rule>> {
+    vec![
+        Box::new(hardcoded_color::HardcodedColor),
+        Box::new(touch_area_accessible_role::TouchAreaAccessibleRole),
+        Box::new(magic_pixel_value::MagicPixelValue),
+    ]
+}
@@ -0,0 +1,67 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Colors should come from a palette global rather than being written in the elements.
+//!
+//! Options:
+//!  - `allowed-globals`: the globals in which colors may be declared. All globals when not set.
+
+use crate::{visit_element_expressions, LintContext, Rule};
+use i_slint_compiler::expression_tree::{BuiltinFunction, Callable, Expression};
+use i_slint_compiler::langtype::Type;
+use i_slint_compiler::object_tree::ElementRc;
+
+pub struct HardcodedColor;
+
+impl Rule for HardcodedColor {
+    fn name(&self) -> &'static str {
+        "hardcoded-color"
+    }
+
+    fn description(&self) -> &'static str {
+        "Color literals, `Colors.*`, `rgb()`, and `hsv()` outside of the palette globals"
+    }
+
+    fn check_element(&self, element: &ElementRc, ctx: &mut LintContext) {
+        if ctx.component.is_global() {
+            let allowed = ctx.options.string_list("allowed-globals");
+            if allowed.is_none_or(|allowed| allowed.iter().any(|g| *g == ctx.component.id)) {
+                return;
+            }
+        }
+        let mut findings = Vec::new();
+        visit_element_expressions(element, |property, expression, span| {
+            let mut found = false;
+            expression.visit_recursive(&mut |e| found |= is_color_literal(e));
+            if found {
+                findings.push((property.to_string(), span.to_source_location()));
+            }
+        });
+        for (property, span) in findings {
+            ctx.report(format!("Hardcoded color in the binding of '{property}'"), &span);
+        }
+    }
+}
+
+fn is_color_literal(expression: &Expression) -> bool {
+    match expression {
+        Expression::Cast { from, to: Type::Color } => {
+            // Fully transparent colors are fine
+            matches!(**from, Expression::NumberLiteral(argb, _) if (argb as u32) >> 24 != 0)
+        }
+        Expression::FunctionCall {
+            function: Callable::Builtin(BuiltinFunction::Rgb | BuiltinFunction::Hsv),
+            arguments,
+            ..
+        } => arguments.iter().all(is_number),
+        _ => false,
+    }
+}
+
+fn is_number(expression: &Expression) -> bool {
+    match expression {
+        Expression::NumberLiteral(..) => true,
+        Expression::Cast { from, .. } => is_number(from),
+        _ => false,
+    }
+}
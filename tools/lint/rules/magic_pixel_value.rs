@@ -0,0 +1,52 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Sizes and spacings should come from the properties of a global, rather than being written
+//! in the elements. Off by default.
+//!
+//! Options:
+//!  - `allowed-values`: the pixel values that may be used anywhere. `[0, 1]` when not set.
+
+use crate::{visit_element_expressions, Level, LintContext, Rule};
+use i_slint_compiler::expression_tree::{Expression, Unit};
+use i_slint_compiler::object_tree::ElementRc;
+
+pub struct MagicPixelValue;
+
+impl Rule for MagicPixelValue {
+    fn name(&self) -> &'static str {
+        "magic-pixel-value"
+    }
+
+    fn description(&self) -> &'static str {
+        "Pixel values in bindings outside of globals"
+    }
+
+    fn default_level(&self) -> Level {
+        Level::Off
+    }
+
+    fn check_element(&self, element: &ElementRc, ctx: &mut LintContext) {
+        if ctx.component.is_global() {
+            return;
+        }
+        let allowed = ctx.options.number_list("allowed-values").unwrap_or_else(|| vec![0., 1.]);
+        let mut findings = Vec::new();
+        visit_element_expressions(element, |property, expression, span| {
+            expression.visit_recursive(&mut |e| {
+                if let Expression::NumberLiteral(value, unit @ (Unit::Px | Unit::Phx)) = e {
+                    if !allowed.contains(value) {
+                        findings.push((
+                            format!("{value}{unit}"),
+                            property.to_string(),
+                            span.to_source_location(),
+                        ));
+                    }
+                }
+            });
+        });
+        for (value, property, span) in findings {
+            ctx.report(format!("Magic pixel value {value} in the binding of '{property}'"), &span);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! A `TouchArea` makes something interactive, which must be exposed to the accessibility tools.
+
+use crate::{builtin_name, has_binding, LintContext, Rule};
+use i_slint_compiler::object_tree::ElementRc;
+
+pub struct TouchAreaAccessibleRole;
+
+impl Rule for TouchAreaAccessibleRole {
+    fn name(&self) -> &'static str {
+        "touch-area-accessible-role"
+    }
+
+    fn description(&self) -> &'static str {
+        "TouchArea elements without an accessible-role on themselves, their parent, or their children"
+    }
+
+    fn check_element(&self, element: &ElementRc, ctx: &mut LintContext) {
+        if builtin_name(element).is_none_or(|name| name != "TouchArea") {
+            return;
+        }
+        let has_role = |e: &ElementRc| has_binding(e, "accessible-role");
+        if has_role(element)
+            || ctx.parent().is_some_and(has_role)
+            || element.borrow().children.iter().any(has_role)
+        {
+            return;
+        }
+        let element = element.borrow();
+        ctx.report(
+            "TouchArea without an accessible-role on itself, its parent, or its children",
+            &*element,
+        );
+    }
+}
@@ -13,17 +13,37 @@
 #[cfg(feature = "preview-engine")]
 fn builtin_component_info(name: &str) -> ComponentInformation {
     let is_layout = matches!(name, "GridLayout" | "HorizontalLayout" | "VerticalLayout");
-    let is_interactive =
-        matches!(name, "Flickable" | "FocusScope" | "SwipeGestureHandler" | "TouchArea");
+    let is_interactive = matches!(
+        name,
+        "DragArea"
+            | "DropArea"
+            | "Flickable"
+            | "FocusScope"
+            | "PinchGestureHandler"
+            | "SwipeGestureHandler"
+            | "TouchArea"
+    );
 
     let default_properties = match name {
         "Text" | "TextInput" => vec![PropertyChange::new("text", format!("\"{name}\""))],
+        "StyledText" => {
+            vec![PropertyChange::new("text", "\"<b>Styled</b> <i>Text</i>\"".to_string())]
+        }
         "Image" => vec![PropertyChange::new("source", "@image-url(\"EDIT_ME.png\")".to_string())],
         _ => vec![],
     };
 
     let component = {
-        if ["Flickable", "SwipeGestureHandler", "TouchArea"].contains(&name) {
+        if [
+            "DragArea",
+            "DropArea",
+            "Flickable",
+            "PinchGestureHandler",
+            "SwipeGestureHandler",
+            "TouchArea",
+        ]
+        .contains(&name)
+        {
             "Gestures"
         } else if ["FocusScope", "TextInput"].contains(&name) {
             "Keyboard Input"
@@ -49,6 +49,7 @@ pub struct CompilerConfiguration {
     pub open_import_fallback: OpenImportFallback,
     pub resource_url_mapper:
         Option<Rc<dyn Fn(&str) -> Pin<Box<dyn Future<Output = Option<String>>>>>>,
+    pub warn_unused: bool,
 }
 
 impl Default for CompilerConfiguration {
@@ -61,6 +62,7 @@ fn default() -> Self {
             style: std::mem::take(&mut cc.style),
             open_import_fallback: None,
             resource_url_mapper: std::mem::take(&mut cc.resource_url_mapper),
+            warn_unused: cc.warn_unused,
         }
     }
 }
@@ -72,6 +74,7 @@ fn build(mut self) -> (i_slint_compiler::CompilerConfiguration, OpenImportFallba
         result.library_paths = std::mem::take(&mut self.library_paths);
         result.style = std::mem::take(&mut self.style);
         result.resource_url_mapper = std::mem::take(&mut self.resource_url_mapper);
+        result.warn_unused = self.warn_unused;
 
         (result, self.open_import_fallback)
     }
@@ -360,6 +363,7 @@ pub fn compiler_configuration(&self) -> CompilerConfiguration {
             style: self.type_loader.compiler_config.style.clone(),
             open_import_fallback: None, // We need to re-generate this anyway
             resource_url_mapper: self.type_loader.compiler_config.resource_url_mapper.clone(),
+            warn_unused: self.type_loader.compiler_config.warn_unused,
         }
     }
 
@@ -120,6 +120,9 @@ fn format_node(
         SyntaxKind::ReturnStatement => {
             return format_return_statement(node, writer, state);
         }
+        SyntaxKind::LetStatement => {
+            return format_let_statement(node, writer, state);
+        }
         SyntaxKind::AtGradient => {
             return format_at_gradient(node, writer, state);
         }
@@ -803,6 +806,26 @@ fn format_return_statement(
     Ok(())
 }
 
+fn format_let_statement(
+    node: &SyntaxNode,
+    writer: &mut impl TokenWriter,
+    state: &mut FormatState,
+) -> Result<(), std::io::Error> {
+    let mut sub = node.children_with_tokens();
+    whitespace_to(&mut sub, SyntaxKind::Identifier, writer, state, "")?;
+    whitespace_to(&mut sub, SyntaxKind::DeclaredIdentifier, writer, state, " ")?;
+    if node.child_node(SyntaxKind::Type).is_some() {
+        whitespace_to(&mut sub, SyntaxKind::Colon, writer, state, "")?;
+        whitespace_to(&mut sub, SyntaxKind::Type, writer, state, " ")?;
+    }
+    whitespace_to(&mut sub, SyntaxKind::Equal, writer, state, " ")?;
+    whitespace_to(&mut sub, SyntaxKind::Expression, writer, state, " ")?;
+    whitespace_to(&mut sub, SyntaxKind::Semicolon, writer, state, "")?;
+    state.new_line();
+    finish_node(sub, writer, state)?;
+    Ok(())
+}
+
 fn format_at_gradient(
     node: &SyntaxNode,
     writer: &mut impl TokenWriter,
@@ -1873,6 +1896,35 @@ fn code_block() {
         );
     }
 
+    #[test]
+    fn let_statement() {
+        assert_formatting(
+            r#"
+component ABC {
+    function foo() -> int {
+        let   a=42;let b :   int
+            = a+1;
+        if (a > b) { let c=[1,2]; return c[0]; }
+        b
+    }
+}
+"#,
+            r#"
+component ABC {
+    function foo() -> int {
+        let a = 42;
+        let b: int = a + 1;
+        if (a > b) {
+            let c = [1, 2];
+            return c[0];
+        }
+        b
+    }
+}
+"#,
+        );
+    }
+
     #[test]
     fn trailing_comma_array() {
         assert_formatting(
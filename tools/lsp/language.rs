@@ -3,14 +3,20 @@
 
 // cSpell: ignore descr rfind unindented
 
+mod code_actions;
 pub mod completion;
+mod folding_ranges;
 mod formatting;
 mod goto;
 mod hover;
+mod inlay_hints;
+mod references;
+mod selection_ranges;
 mod semantic_tokens;
 mod signature_help;
 #[cfg(test)]
 pub mod test;
+mod workspace_symbols;
 
 use crate::common;
 use crate::util;
@@ -24,8 +30,10 @@
 use i_slint_compiler::{diagnostics::BuildDiagnostics, langtype::Type};
 use lsp_types::request::{
     CodeActionRequest, CodeLensRequest, ColorPresentationRequest, Completion, DocumentColor,
-    DocumentHighlightRequest, DocumentSymbolRequest, ExecuteCommand, Formatting, GotoDefinition,
-    HoverRequest, PrepareRenameRequest, Rename, SemanticTokensFullRequest, SignatureHelpRequest,
+    DocumentHighlightRequest, DocumentSymbolRequest, ExecuteCommand, FoldingRangeRequest,
+    Formatting, GotoDefinition, HoverRequest, InlayHintRequest, OnTypeFormatting,
+    PrepareRenameRequest, RangeFormatting, References, Rename, SelectionRangeRequest,
+    SemanticTokensFullRequest, SignatureHelpRequest, WorkspaceSymbolRequest,
 };
 use lsp_types::{
     ClientCapabilities, CodeActionOrCommand, CodeActionProviderCapability, CodeLens,
@@ -39,7 +47,7 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::future::Future;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::rc::Rc;
 
@@ -256,6 +264,11 @@ pub fn server_initialize_result(client_cap: &ClientCapabilities) -> InitializeRe
                 ..Default::default()
             }),
             document_symbol_provider: Some(OneOf::Left(true)),
+            workspace_symbol_provider: Some(OneOf::Left(true)),
+            references_provider: Some(OneOf::Left(true)),
+            inlay_hint_provider: Some(OneOf::Left(true)),
+            folding_range_provider: Some(true.into()),
+            selection_range_provider: Some(true.into()),
             color_provider: Some(true.into()),
             code_lens_provider: Some(CodeLensOptions { resolve_provider: Some(true) }),
             semantic_tokens_provider: Some(
@@ -287,6 +300,13 @@ pub fn server_initialize_result(client_cap: &ClientCapabilities) -> InitializeRe
                 },
             ),
             document_formatting_provider: Some(OneOf::Left(true)),
+            document_range_formatting_provider: Some(OneOf::Left(true)),
+            document_on_type_formatting_provider: Some(
+                lsp_types::DocumentOnTypeFormattingOptions {
+                    first_trigger_character: "}".into(),
+                    more_trigger_character: Some(vec![";".into()]),
+                },
+            ),
             ..ServerCapabilities::default()
         },
         server_info: Some(ServerInfo {
@@ -356,8 +376,19 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
         let document_cache = &mut ctx.document_cache.borrow_mut();
 
         let result = token_descr(document_cache, &params.text_document.uri, &params.range.start)
-            .and_then(|(token, _)| {
-                get_code_actions(document_cache, token, &ctx.init_param.capabilities)
+            .and_then(|(token, offset)| {
+                let mut actions =
+                    get_code_actions(document_cache, token.clone(), &ctx.init_param.capabilities)
+                        .unwrap_or_default();
+                let selection_end =
+                    util::lsp_position_to_text_size(&token.source_file, params.range.end)
+                        .max(offset);
+                actions.extend(code_actions::get_refactoring_actions(
+                    document_cache,
+                    &token,
+                    TextRange::new(offset, selection_end),
+                ));
+                (!actions.is_empty()).then_some(actions)
             });
         Ok(result)
     });
@@ -405,6 +436,48 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
         let document_cache = &mut ctx.document_cache.borrow_mut();
         Ok(get_document_symbols(document_cache, &params.text_document))
     });
+    rh.register::<InlayHintRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(inlay_hints::get_inlay_hints(document_cache, &params.text_document, &params.range))
+    });
+    rh.register::<FoldingRangeRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(folding_ranges::get_folding_ranges(document_cache, &params.text_document))
+    });
+    rh.register::<SelectionRangeRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(selection_ranges::get_selection_ranges(
+            document_cache,
+            &params.text_document,
+            &params.positions,
+        ))
+    });
+    rh.register::<WorkspaceSymbolRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(workspace_symbols::get_workspace_symbols(document_cache, &params.query))
+    });
+    rh.register::<References, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        let uri = params.text_document_position.text_document.uri;
+        let Some((tk, _)) =
+            token_descr(document_cache, &uri, &params.text_document_position.position)
+        else {
+            return Ok(None);
+        };
+        let p = tk.parent();
+        if let Some(value) = find_element_id_for_highlight(&tk, &p) {
+            return Ok(Some(
+                value
+                    .into_iter()
+                    .map(|r| lsp_types::Location {
+                        uri: uri.clone(),
+                        range: util::text_range_to_lsp_range(&p.source_file, r),
+                    })
+                    .collect(),
+            ));
+        }
+        Ok(references::find_references(document_cache, tk, params.context.include_declaration))
+    });
     rh.register::<CodeLensRequest, _>(|params, ctx| async move {
         let document_cache = &mut ctx.document_cache.borrow_mut();
         Ok(get_code_lenses(document_cache, &params.text_document))
@@ -533,6 +606,14 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
         let document_cache = ctx.document_cache.borrow_mut();
         Ok(formatting::format_document(params, &document_cache))
     });
+    rh.register::<RangeFormatting, _>(|params, ctx| async move {
+        let document_cache = ctx.document_cache.borrow_mut();
+        Ok(formatting::format_document_range(params, &document_cache))
+    });
+    rh.register::<OnTypeFormatting, _>(|params, ctx| async move {
+        let document_cache = ctx.document_cache.borrow_mut();
+        Ok(formatting::format_on_type(params, &document_cache))
+    });
 }
 
 /// extract the parameter at given index. name is used in the error
@@ -751,6 +832,7 @@ enum FileAction {
             }
             let dependencies = document_cache.invalidate_url(&url);
             let _ = document_cache.load_url(&url, version, content, &mut diag).await;
+            lint_document(&path, &url, document_cache, &mut diag);
             dependencies
         }
         FileAction::IgnoreFile => return Default::default(),
@@ -776,6 +858,38 @@ enum FileAction {
     (extra_files, diag)
 }
 
+/// Run the linter on the document if a `slint-lint.toml` is found next to it or in a parent directory
+fn lint_document(
+    path: &Path,
+    url: &Url,
+    document_cache: &common::DocumentCache,
+    diag: &mut BuildDiagnostics,
+) {
+    if diag.has_errors() {
+        return;
+    }
+    let Some(config_path) = slint_lint::Config::find_for_file(path) else { return };
+    let Some(doc) = document_cache.get_document(url) else { return };
+    // The configuration errors are reported at the start of the document that is being linted
+    let location = doc.node.clone().map(SyntaxNode::from);
+    let config = match slint_lint::Config::load(&config_path) {
+        Ok(config) => config,
+        Err(e) => {
+            diag.push_error(format!("Error loading the linter configuration: {e}"), &location);
+            return;
+        }
+    };
+    let linter = slint_lint::Linter::new(config);
+    if let Err(e) = linter.check_config() {
+        diag.push_error(
+            format!("Error in the linter configuration {}: {e}", config_path.display()),
+            &location,
+        );
+        return;
+    }
+    linter.lint_document(doc, diag);
+}
+
 pub async fn open_document(
     ctx: &Rc<Context>,
     content: String,
@@ -0,0 +1,928 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Code actions that refactor the structure of a document: extracting and inlining
+//! components, converting bindings and cleaning up imports.
+
+use crate::common::{
+    self,
+    token_info::{token_info, TokenInfo},
+};
+use crate::util;
+use i_slint_compiler::langtype::{ElementType, Type};
+use i_slint_compiler::object_tree::ElementRc;
+use i_slint_compiler::parser::{
+    identifier_text, normalize_identifier, syntax_nodes, NodeOrToken, SyntaxKind, SyntaxNode,
+    SyntaxToken, TextRange, TextSize,
+};
+use i_slint_compiler::passes::check_unused::{imported_name, unused_imports};
+use lsp_types::{CodeAction, CodeActionKind, CodeActionOrCommand, TextEdit};
+use smol_str::SmolStr;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// Compute the refactoring code actions for the `token` at the start of the `selection`
+pub fn get_refactoring_actions(
+    document_cache: &common::DocumentCache,
+    token: &SyntaxToken,
+    selection: TextRange,
+) -> Vec<CodeActionOrCommand> {
+    let mut result = vec![];
+    let mut push_action = |title: String, kind: CodeActionKind, edits: Option<Vec<TextEdit>>| {
+        let Some(mut edits) = edits else {
+            return;
+        };
+        edits.sort_by_key(|e| e.range.start);
+        result.push(CodeActionOrCommand::CodeAction(CodeAction {
+            title,
+            kind: Some(kind),
+            edit: common::create_workspace_edit_from_path(
+                document_cache,
+                token.source_file.path(),
+                edits,
+            ),
+            ..Default::default()
+        }));
+    };
+
+    push_action(
+        "Extract into component".into(),
+        CodeActionKind::REFACTOR_EXTRACT,
+        extract_component(document_cache, token, selection),
+    );
+    if let Some((name, edits)) = inline_component(document_cache, token) {
+        push_action(format!("Inline `{name}`"), CodeActionKind::REFACTOR_INLINE, Some(edits));
+    }
+    push_action(
+        "Convert to two-way binding".into(),
+        CodeActionKind::REFACTOR_REWRITE,
+        convert_to_two_way_binding(document_cache, token),
+    );
+
+    if let Some(import) = token.parent_ancestors().find_map(syntax_nodes::ImportSpecifier::new) {
+        let document = import.parent().and_then(syntax_nodes::Document::new);
+        let unused = document.as_ref().map(unused_imports).unwrap_or_default();
+        if let Some(identifier) = token
+            .parent_ancestors()
+            .find_map(syntax_nodes::ImportIdentifier::new)
+            .filter(|i| unused.iter().any(|u| is_same(u, i)))
+        {
+            push_action(
+                format!("Remove unused import `{}`", imported_name(&identifier)),
+                CodeActionKind::QUICKFIX,
+                Some(remove_imports(std::slice::from_ref(&identifier))),
+            );
+        }
+        if unused.len() > 1 {
+            push_action(
+                "Remove all unused imports".into(),
+                CodeActionKind::QUICKFIX,
+                Some(remove_imports(&unused)),
+            );
+        }
+        push_action(
+            "Sort imports".into(),
+            CodeActionKind::SOURCE_ORGANIZE_IMPORTS,
+            sort_imports(&import),
+        );
+    }
+
+    result
+}
+
+fn is_same(a: &SyntaxNode, b: &SyntaxNode) -> bool {
+    **a == **b
+}
+
+fn ancestors(node: &SyntaxNode) -> impl Iterator<Item = SyntaxNode> {
+    std::iter::successors(Some(node.clone()), |n| n.parent())
+}
+
+/// The indentation of the line on which `node` starts
+fn indentation(node: &SyntaxNode) -> String {
+    let mut token = node.first_token().and_then(|t| t.prev_token());
+    let mut indent = String::new();
+    while let Some(t) = token {
+        if !matches!(t.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment) {
+            return String::new();
+        }
+        if let Some((_, last_line)) = t.text().rsplit_once('\n') {
+            return format!("{last_line}{indent}");
+        }
+        indent = format!("{}{indent}", t.text());
+        token = t.prev_token();
+    }
+    indent
+}
+
+/// The text of `node`, with the lines after the first one moved from the indentation
+/// `from` to the indentation `to`.
+fn reindent(text: &str, from: &str, to: &str) -> String {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 {
+                line.to_string()
+            } else if line.trim().is_empty() {
+                String::new()
+            } else {
+                format!("{to}{}", line.strip_prefix(from).unwrap_or(line.trim_start()))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The text of `node` within `range`, with the given ranges replaced by a new text
+fn text_with_replacements(
+    node: &SyntaxNode,
+    range: TextRange,
+    replacements: &[(TextRange, String)],
+) -> String {
+    let start = usize::from(range.start());
+    let mut text = node.text().slice(range - node.text_range().start()).to_string();
+    let mut replacements =
+        replacements.iter().filter(|(r, _)| range.contains_range(*r)).collect::<Vec<_>>();
+    replacements.sort_by_key(|(r, _)| std::cmp::Reverse(r.start()));
+    for (r, new_text) in replacements {
+        text.replace_range(usize::from(r.start()) - start..usize::from(r.end()) - start, new_text);
+    }
+    text
+}
+
+fn edit(node: &SyntaxNode, range: TextRange, new_text: String) -> TextEdit {
+    TextEdit::new(util::text_range_to_lsp_range(&node.source_file, range), new_text)
+}
+
+/// Convert a component name like `FooBar` to an element id like `foo-bar`
+fn id_for_component(name: &str) -> String {
+    let mut id = String::new();
+    for c in name.chars() {
+        if c.is_uppercase() && !id.is_empty() && !id.ends_with('-') {
+            id.push('-');
+        }
+        id.extend(c.to_lowercase());
+    }
+    id
+}
+
+/// A reference in an expression to a property, callback or function of an element
+struct PropertyReference {
+    /// From the start of the qualified name to the end of the property name
+    range: TextRange,
+    /// The path to the property, e.g. `parent.width`
+    path: Vec<SmolStr>,
+    element: ElementRc,
+    name: SmolStr,
+    ty: Type,
+    is_written: bool,
+}
+
+/// Find all the references to properties in the expressions within `node`
+fn property_references(
+    document_cache: &common::DocumentCache,
+    node: &SyntaxNode,
+) -> Vec<PropertyReference> {
+    let mut result = vec![];
+    for qualified_name in node.descendants().filter(|n| n.kind() == SyntaxKind::QualifiedName) {
+        let Some(expression) =
+            qualified_name.parent().filter(|p| p.kind() == SyntaxKind::Expression)
+        else {
+            continue;
+        };
+        let mut path = vec![];
+        for token in qualified_name
+            .children_with_tokens()
+            .filter_map(NodeOrToken::into_token)
+            .filter(|t| t.kind() == SyntaxKind::Identifier)
+        {
+            path.push(normalize_identifier(token.text()));
+            if let Some(TokenInfo::NamedReference(nr)) = token_info(document_cache, token.clone()) {
+                let is_written = expression.parent().is_some_and(|p| {
+                    p.kind() == SyntaxKind::TwoWayBinding
+                        || (p.kind() == SyntaxKind::SelfAssignment
+                            && p.first_child().is_some_and(|c| is_same(&c, &expression)))
+                });
+                result.push(PropertyReference {
+                    range: TextRange::new(
+                        qualified_name.text_range().start(),
+                        token.text_range().end(),
+                    ),
+                    path,
+                    element: nr.element(),
+                    name: nr.name().clone(),
+                    ty: nr.ty(),
+                    is_written,
+                });
+                break;
+            }
+        }
+    }
+    result
+}
+
+/// The path of the reference, qualified so that it can be used from another element
+fn qualified_path(r: &PropertyReference) -> String {
+    if r.path.len() > 1 {
+        return r.path.join(".");
+    }
+    let e = r.element.borrow();
+    let is_root =
+        e.enclosing_component.upgrade().is_some_and(|c| Rc::ptr_eq(&c.root_element, &r.element));
+    if is_root {
+        format!("root.{}", r.name)
+    } else if !e.id.is_empty() {
+        format!("{}.{}", e.id, r.name)
+    } else {
+        r.name.to_string()
+    }
+}
+
+/// Whether `element` is declared in one of the `ranges` of `source_file`
+fn is_element_within(element: &ElementRc, node: &SyntaxNode, ranges: &[TextRange]) -> bool {
+    element.borrow().debug.first().is_some_and(|d| {
+        d.node.source_file.path() == node.source_file.path()
+            && ranges.iter().any(|r| r.contains_range(d.node.text_range()))
+    })
+}
+
+fn is_global(element: &ElementRc) -> bool {
+    element.borrow().enclosing_component.upgrade().is_some_and(|c| c.is_global())
+}
+
+/// The declaration of a callback with the signature of `ty`
+fn callback_signature(ty: &Type) -> Option<(String, usize)> {
+    let (Type::Callback(f) | Type::Function(f)) = ty else {
+        return None;
+    };
+    let args = f.args.iter().map(|a| a.to_string()).collect::<Vec<_>>().join(", ");
+    let ret = match &f.return_type {
+        Type::Void => String::new(),
+        ty => format!(" -> {ty}"),
+    };
+    Some((format!("({args}){ret}"), f.args.len()))
+}
+
+/// Extract the selected elements into a new component.
+///
+/// Properties of elements outside of the selection that are used by the selected elements
+/// become `in` properties (or `in-out` if they are assigned to), and the properties of the
+/// selected elements that are used from outside become `out` properties.
+fn extract_component(
+    document_cache: &common::DocumentCache,
+    token: &SyntaxToken,
+    selection: TextRange,
+) -> Option<Vec<TextEdit>> {
+    let is_child_item = |n: &SyntaxNode| {
+        matches!(
+            n.kind(),
+            SyntaxKind::SubElement | SyntaxKind::RepeatedElement | SyntaxKind::ConditionalElement
+        ) && n.parent().is_some_and(|p| p.kind() == SyntaxKind::Element)
+    };
+    let item = token.parent_ancestors().find(is_child_item)?;
+    if selection.is_empty() {
+        // Without a selection, only offer this on the element's type name
+        let on_type_name = token.parent().kind() == SyntaxKind::QualifiedName
+            && token.parent().parent().is_some_and(|e| {
+                e.kind() == SyntaxKind::Element
+                    && e.parent().is_some_and(|p| p.kind() == SyntaxKind::SubElement)
+            });
+        if !on_type_name {
+            return None;
+        }
+    }
+    let container = item.parent()?;
+    let items = container
+        .children()
+        .filter(|n| {
+            is_child_item(n)
+                && (is_same(n, &item)
+                    || n.text_range().intersect(selection).is_some_and(|r| !r.is_empty()))
+        })
+        .collect::<Vec<_>>();
+    let ranges = items.iter().map(util::node_range_without_trailing_ws).collect::<Vec<_>>();
+    let extracted_range = TextRange::new(ranges.first()?.start(), ranges.last()?.end());
+
+    let top_level = ancestors(&container)
+        .find(|n| n.parent().is_some_and(|p| p.kind() == SyntaxKind::Document))?;
+    let component = ancestors(&container).find(|n| n.kind() == SyntaxKind::Component)?;
+
+    let doc = document_cache.get_document_for_source_file(&token.source_file)?;
+    let mut component_name = String::from("NewComponent");
+    let mut counter = 1;
+    while doc.local_registry.lookup_element(&component_name).is_ok() {
+        counter += 1;
+        component_name = format!("NewComponent{counter}");
+    }
+    let instance_id = id_for_component(&component_name);
+
+    let mut declarations: Vec<String> = vec![];
+    let mut use_site_bindings: Vec<String> = vec![];
+    let mut replacements: Vec<(TextRange, String)> = vec![];
+    let mut edits = vec![];
+    let mut known: Vec<(*const (), SmolStr, String)> = vec![];
+    let mut unique_name = |element: &ElementRc, name: &SmolStr, path: &[SmolStr]| {
+        let key = Rc::as_ptr(element) as *const ();
+        if let Some((_, _, n)) = known.iter().find(|(e, p, _)| *e == key && p == name) {
+            return (n.clone(), false);
+        }
+        let mut new_name = path
+            .iter()
+            .skip_while(|p| p.as_str() == "root")
+            .map(|p| p.as_str())
+            .collect::<Vec<_>>()
+            .join("-");
+        if new_name.is_empty() {
+            new_name = name.to_string();
+        }
+        let base = new_name.clone();
+        let mut counter = 1;
+        while known.iter().any(|(_, _, n)| *n == new_name) {
+            counter += 1;
+            new_name = format!("{base}-{counter}");
+        }
+        known.push((key, name.clone(), new_name.clone()));
+        (new_name, true)
+    };
+
+    // References from the extracted elements to the outside
+    let mut in_out = HashSet::new();
+    let references = items
+        .iter()
+        .flat_map(|item| property_references(document_cache, item))
+        .filter(|r| !is_element_within(&r.element, &container, &ranges) && !is_global(&r.element))
+        .collect::<Vec<_>>();
+    for r in references.iter().filter(|r| r.is_written) {
+        in_out.insert((Rc::as_ptr(&r.element) as *const (), r.name.clone()));
+    }
+    for r in &references {
+        let (name, is_new) = unique_name(&r.element, &r.name, &r.path);
+        replacements.push((r.range, format!("root.{name}")));
+        if !is_new {
+            continue;
+        }
+        let original = qualified_path(r);
+        if let Some((signature, arg_count)) = callback_signature(&r.ty) {
+            let args = (0..arg_count).map(|i| format!("arg{i}")).collect::<Vec<_>>().join(", ");
+            declarations.push(format!("callback {name}{signature};"));
+            let args = if arg_count > 0 { format!("({args})") } else { String::new() };
+            let call_args = if arg_count > 0 { args.clone() } else { "()".into() };
+            use_site_bindings.push(format!("{name}{args} => {{ {original}{call_args} }}"));
+        } else if in_out.contains(&(Rc::as_ptr(&r.element) as *const (), r.name.clone())) {
+            declarations.push(format!("in-out property <{}> {name};", r.ty));
+            use_site_bindings.push(format!("{name} <=> {original};"));
+        } else {
+            declarations.push(format!("in property <{}> {name};", r.ty));
+            use_site_bindings.push(format!("{name}: {original};"));
+        }
+    }
+
+    // References from the outside to the extracted elements
+    let mut needs_id = false;
+    for r in property_references(document_cache, &component) {
+        if ranges.iter().any(|range| range.contains_range(r.range))
+            || !is_element_within(&r.element, &container, &ranges)
+        {
+            continue;
+        }
+        let (name, is_new) = unique_name(&r.element, &r.name, &r.path);
+        edits.push(edit(&component, r.range, format!("{instance_id}.{name}")));
+        needs_id = true;
+        if !is_new {
+            continue;
+        }
+        let original = r.path.join(".");
+        if callback_signature(&r.ty).is_some() {
+            declarations.push(format!("callback {name} <=> {original};"));
+        } else if r.is_written {
+            declarations.push(format!("in-out property <{}> {name} <=> {original};", r.ty));
+        } else {
+            declarations.push(format!("out property <{}> {name}: {original};", r.ty));
+        }
+    }
+
+    // The new component
+    let item_indent = indentation(&items[0]);
+    let mut new_component = format!("component {component_name} {{\n");
+    for declaration in &declarations {
+        new_component += &format!("    {declaration}\n");
+    }
+    if !declarations.is_empty() {
+        new_component += "\n";
+    }
+    for (item, range) in items.iter().zip(ranges.iter()) {
+        let text = text_with_replacements(item, *range, &replacements);
+        new_component += &format!("    {}\n", reindent(&text, &item_indent, "    "));
+    }
+    new_component += "}\n\n";
+    edits.push(edit(&top_level, TextRange::empty(top_level.text_range().start()), new_component));
+
+    // The use site
+    let mut use_site = if needs_id {
+        format!("{instance_id} := {component_name} {{")
+    } else {
+        format!("{component_name} {{")
+    };
+    if use_site_bindings.is_empty() {
+        use_site += " }";
+    } else {
+        for binding in &use_site_bindings {
+            use_site += &format!("\n{item_indent}    {binding}");
+        }
+        use_site += &format!("\n{item_indent}}}");
+    }
+    edits.push(edit(&container, extracted_range, use_site));
+
+    Some(edits)
+}
+
+/// Replace the use of a component defined in the same file by its content.
+///
+/// Returns the name of the component and the edits.
+fn inline_component(
+    document_cache: &common::DocumentCache,
+    token: &SyntaxToken,
+) -> Option<(SmolStr, Vec<TextEdit>)> {
+    let qualified_name = token.parent();
+    if token.kind() != SyntaxKind::Identifier || qualified_name.kind() != SyntaxKind::QualifiedName
+    {
+        return None;
+    }
+    let use_site = syntax_nodes::Element::new(qualified_name.parent()?)?;
+    let sub_element = use_site.parent().filter(|p| p.kind() == SyntaxKind::SubElement)?;
+
+    let TokenInfo::ElementType(ElementType::Component(component)) =
+        token_info(document_cache, qualified_name.last_token()?)?
+    else {
+        return None;
+    };
+    let component_node = syntax_nodes::Component::new(component.node.clone()?)?;
+    if component.is_global()
+        || component_node.source_file.path() != token.source_file.path()
+        || component_node.descendants().any(|n| n.kind() == SyntaxKind::ChildrenPlaceholder)
+    {
+        return None;
+    }
+    let root = component_node.Element();
+
+    // The use site has the last word for bindings that are set in both places
+    let overridden = use_site
+        .Binding()
+        .filter_map(|b| identifier_text(&b))
+        .chain(use_site.TwoWayBinding().filter_map(|b| identifier_text(&b)))
+        .chain(use_site.CallbackConnection().filter_map(|b| identifier_text(&b)))
+        .collect::<HashSet<_>>();
+
+    // `root` in the component is the inlined element
+    let mut id = identifier_text(&sub_element);
+    let mut replacements = vec![];
+    for qualified_name in root.descendants().filter(|n| n.kind() == SyntaxKind::QualifiedName) {
+        let Some(first) = qualified_name.first_token() else {
+            continue;
+        };
+        if qualified_name.parent().is_some_and(|p| p.kind() == SyntaxKind::Expression)
+            && first.kind() == SyntaxKind::Identifier
+            && first.text() == "root"
+        {
+            let id = id.get_or_insert_with(|| id_for_component(&component.id).into());
+            replacements.push((first.text_range(), id.to_string()));
+        }
+    }
+
+    let indent = indentation(&sub_element);
+    let member_indent = format!("{indent}    ");
+    let mut members = vec![];
+    for member in root.children() {
+        let name = match member.kind() {
+            SyntaxKind::QualifiedName => continue,
+            SyntaxKind::PropertyDeclaration => {
+                member.child_node(SyntaxKind::DeclaredIdentifier).and_then(|d| identifier_text(&d))
+            }
+            _ => identifier_text(&member),
+        };
+        let is_overridden = name.as_ref().is_some_and(|n| overridden.contains(n));
+        let range = util::node_range_without_trailing_ws(&member);
+        let text = match member.kind() {
+            SyntaxKind::Binding | SyntaxKind::TwoWayBinding | SyntaxKind::CallbackConnection
+                if is_overridden =>
+            {
+                continue
+            }
+            SyntaxKind::PropertyDeclaration if is_overridden => {
+                let declared = member.child_node(SyntaxKind::DeclaredIdentifier)?;
+                let range = TextRange::new(range.start(), declared.text_range().end());
+                format!("{};", text_with_replacements(&member, range, &replacements).trim_end())
+            }
+            _ => text_with_replacements(&member, range, &replacements),
+        };
+        members.push(reindent(&text, &indentation(&member), &member_indent));
+    }
+    for member in use_site.children().filter(|n| n.kind() != SyntaxKind::QualifiedName) {
+        let text = member
+            .text()
+            .slice(util::node_range_without_trailing_ws(&member) - member.text_range().start());
+        members.push(reindent(&text.to_string(), &member_indent, &member_indent));
+    }
+
+    let base = root
+        .QualifiedName()
+        .map(|q| util::node_range_without_trailing_ws(&q))
+        .map(|r| root.text().slice(r - root.text_range().start()).to_string())
+        .unwrap_or_else(|| "Empty".into());
+    let mut text =
+        if let Some(id) = &id { format!("{id} := {base} {{") } else { format!("{base} {{") };
+    if members.is_empty() {
+        text += " }";
+    } else {
+        for member in members {
+            text += &format!("\n{member_indent}{member}");
+        }
+        text += &format!("\n{indent}}}");
+    }
+
+    Some((
+        component.id.clone(),
+        vec![edit(&sub_element, util::node_range_without_trailing_ws(&sub_element), text)],
+    ))
+}
+
+/// Turn `foo: some.property;` into `foo <=> some.property;`
+fn convert_to_two_way_binding(
+    document_cache: &common::DocumentCache,
+    token: &SyntaxToken,
+) -> Option<Vec<TextEdit>> {
+    let binding = token.parent_ancestors().find(|n| {
+        matches!(n.kind(), SyntaxKind::Binding | SyntaxKind::PropertyDeclaration)
+            && n.child_node(SyntaxKind::BindingExpression).is_some()
+    })?;
+    // Only offer this on the name of the property
+    if token.kind() != SyntaxKind::Identifier
+        || !(is_same(&token.parent(), &binding)
+            || (token.parent().kind() == SyntaxKind::DeclaredIdentifier
+                && token.parent().parent().is_some_and(|p| is_same(&p, &binding))))
+    {
+        return None;
+    }
+
+    let expression =
+        binding.child_node(SyntaxKind::BindingExpression)?.child_node(SyntaxKind::Expression)?;
+    let qualified_name = expression.child_node(SyntaxKind::QualifiedName)?;
+    if expression.children().count() != 1 {
+        return None;
+    }
+    let last = qualified_name.last_token()?;
+    let TokenInfo::NamedReference(nr) = token_info(document_cache, last)? else {
+        return None;
+    };
+    if matches!(nr.ty(), Type::Callback(_) | Type::Function(_)) {
+        return None;
+    }
+
+    let name_end = match binding.child_node(SyntaxKind::DeclaredIdentifier) {
+        Some(declared) => util::node_range_without_trailing_ws(&declared).end(),
+        None => token.text_range().end(),
+    };
+    Some(vec![edit(
+        &binding,
+        TextRange::new(name_end, qualified_name.text_range().start()),
+        " <=> ".into(),
+    )])
+}
+
+/// Remove the given imported identifiers, and the import statements that become empty.
+fn remove_imports(identifiers: &[syntax_nodes::ImportIdentifier]) -> Vec<TextEdit> {
+    let mut edits = vec![];
+    let mut lists: Vec<syntax_nodes::ImportIdentifierList> = vec![];
+    for identifier in identifiers {
+        let Some(list) = identifier.parent().and_then(syntax_nodes::ImportIdentifierList::new)
+        else {
+            continue;
+        };
+        if !lists.iter().any(|l| is_same(l, &list)) {
+            lists.push(list);
+        }
+    }
+
+    for list in lists {
+        let all = list.ImportIdentifier().collect::<Vec<_>>();
+        let removed =
+            |i: &syntax_nodes::ImportIdentifier| identifiers.iter().any(|r| is_same(r, i));
+        if all.iter().all(removed) {
+            let Some(specifier) = list.parent() else {
+                continue;
+            };
+            let mut range = util::node_range_without_trailing_ws(&specifier);
+            // Also remove the rest of the line
+            let ws = specifier.last_token().and_then(|t| {
+                if t.kind() == SyntaxKind::Whitespace {
+                    Some(t)
+                } else {
+                    t.next_token()
+                }
+            });
+            if let Some(ws) = ws.filter(|t| t.kind() == SyntaxKind::Whitespace) {
+                let newline = ws.text().find('\n').map_or(ws.text().len(), |p| p + 1);
+                range = TextRange::new(
+                    range.start(),
+                    ws.text_range().start() + TextSize::from(newline as u32),
+                );
+            }
+            edits.push(edit(&specifier, range, String::new()));
+            continue;
+        }
+
+        // Remove runs of consecutive identifiers together with the separating commas
+        let mut index = 0;
+        while index < all.len() {
+            if !removed(&all[index]) {
+                index += 1;
+                continue;
+            }
+            let run_start = index;
+            while index < all.len() && removed(&all[index]) {
+                index += 1;
+            }
+            let range = if let Some(next) = all.get(index) {
+                TextRange::new(all[run_start].text_range().start(), next.text_range().start())
+            } else {
+                TextRange::new(
+                    util::node_range_without_trailing_ws(&all[run_start - 1]).end(),
+                    util::node_range_without_trailing_ws(&all[index - 1]).end(),
+                )
+            };
+            edits.push(edit(&list, range, String::new()));
+        }
+    }
+    edits
+}
+
+/// Sort the imports of the block of import statements containing `import`, as well as the
+/// identifiers within these statements.
+fn sort_imports(import: &syntax_nodes::ImportSpecifier) -> Option<Vec<TextEdit>> {
+    // The block of import statements that are only separated by whitespace
+    let mut blocks: Vec<Vec<syntax_nodes::ImportSpecifier>> = vec![vec![]];
+    for child in import.parent()?.children_with_tokens() {
+        match child.kind() {
+            SyntaxKind::ImportSpecifier => {
+                blocks.last_mut()?.push(syntax_nodes::ImportSpecifier::new(child.into_node()?)?)
+            }
+            SyntaxKind::Whitespace => {}
+            _ if !blocks.last()?.is_empty() => blocks.push(vec![]),
+            _ => {}
+        }
+    }
+    let block = blocks.into_iter().find(|b| b.iter().any(|i| is_same(i, import)))?;
+
+    let file_name = |i: &syntax_nodes::ImportSpecifier| {
+        i.child_token(SyntaxKind::StringLiteral).map(|t| t.text().to_string()).unwrap_or_default()
+    };
+    let mut sorted = block.clone();
+    sorted.sort_by_key(file_name);
+
+    let statements = sorted
+        .iter()
+        .map(|import| {
+            let range = util::node_range_without_trailing_ws(import);
+            let text = import.text().slice(range - import.text_range().start()).to_string();
+            let Some(list) = import.ImportIdentifierList() else {
+                return text;
+            };
+            if list.descendants_with_tokens().any(|t| t.kind() == SyntaxKind::Comment) {
+                return text;
+            }
+            let mut identifiers = list
+                .ImportIdentifier()
+                .map(|i| {
+                    let r = util::node_range_without_trailing_ws(&i);
+                    i.text().slice(r - i.text_range().start()).to_string()
+                })
+                .collect::<Vec<_>>();
+            identifiers.sort_by_key(|i| i.to_lowercase());
+            let list_text = if list.text().contains_char('\n') {
+                format!(
+                    "{{\n{}}}",
+                    identifiers.iter().map(|i| format!("    {i},\n")).collect::<String>()
+                )
+            } else {
+                format!("{{ {} }}", identifiers.join(", "))
+            };
+            let list_range = util::node_range_without_trailing_ws(&list) - range.start();
+            let mut text = text;
+            text.replace_range(
+                usize::from(list_range.start())..usize::from(list_range.end()),
+                &list_text,
+            );
+            text
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let range = TextRange::new(
+        block.first()?.text_range().start(),
+        util::node_range_without_trailing_ws(block.last()?).end(),
+    );
+    let original = import.parent()?.text().slice(range - import.parent()?.text_range().start());
+    if original == statements.as_str() {
+        return None;
+    }
+    Some(vec![edit(import, range, statements)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::loaded_document_cache;
+
+    /// Return the source after applying the code action with the given title, with the
+    /// selection being the text between the two `|` in `source`.
+    #[track_caller]
+    fn apply_action(source: &str, title: &str) -> Option<String> {
+        let start = source.find('|').unwrap();
+        let end = source[start + 1..].find('|').map_or(start, |p| p + start);
+        let source = source.replace('|', "");
+        let (dc, url, diag) = loaded_document_cache(source.clone());
+        assert!(diag.values().all(|d| d.is_empty()), "{diag:?}");
+        let doc = dc.get_document(&url).unwrap().node.clone().unwrap();
+        let token = crate::language::token_at_offset(&doc, (start as u32).into()).unwrap();
+        let selection = TextRange::new((start as u32).into(), (end as u32).into());
+        let actions = get_refactoring_actions(&dc, &token, selection);
+        let action = actions.into_iter().find_map(|a| match a {
+            CodeActionOrCommand::CodeAction(a) if a.title == title => Some(a),
+            _ => None,
+        })?;
+        let edited = common::text_edit::apply_workspace_edit(&dc, &action.edit.unwrap()).unwrap();
+        assert_eq!(edited.len(), 1);
+        Some(edited[0].contents.clone())
+    }
+
+    #[test]
+    fn test_extract_component() {
+        let source = r#"export component Test {
+    in-out property <int> counter;
+    property <string> label: "Hello";
+    VerticalLayout {
+        |Text { text: label; }
+        TouchArea {
+            clicked => { root.counter += 1; }
+        }|
+        Text { text: area.pressed ? "pressed" : ""; }
+    }
+    area := TouchArea { }
+}
+"#;
+        assert_eq!(
+            apply_action(source, "Extract into component").unwrap(),
+            r#"component NewComponent {
+    in property <string> label;
+    in-out property <int> counter;
+
+    Text { text: root.label; }
+    TouchArea {
+        clicked => { root.counter += 1; }
+    }
+}
+
+export component Test {
+    in-out property <int> counter;
+    property <string> label: "Hello";
+    VerticalLayout {
+        NewComponent {
+            label: root.label;
+            counter <=> root.counter;
+        }
+        Text { text: area.pressed ? "pressed" : ""; }
+    }
+    area := TouchArea { }
+}
+"#
+        );
+
+        let source = r#"export component Test {
+    VerticalLayout {
+        |Text { }
+        input := TextInput { }|
+    }
+    Text { text: input.text; }
+}
+"#;
+        assert_eq!(
+            apply_action(source, "Extract into component").unwrap(),
+            r#"component NewComponent {
+    out property <string> input-text: input.text;
+
+    Text { }
+    input := TextInput { }
+}
+
+export component Test {
+    VerticalLayout {
+        new-component := NewComponent { }
+    }
+    Text { text: new-component.input-text; }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_inline_component() {
+        let source = r#"component Label inherits Rectangle {
+    in property <string> label: "default";
+    background: red;
+    Text { text: root.label; }
+}
+export component Test {
+    VerticalLayout {
+        |Label { label: "Hello"; }
+        Label { }
+    }
+}
+"#;
+        assert_eq!(
+            apply_action(source, "Inline `Label`").unwrap(),
+            r#"component Label inherits Rectangle {
+    in property <string> label: "default";
+    background: red;
+    Text { text: root.label; }
+}
+export component Test {
+    VerticalLayout {
+        label := Rectangle {
+            in property <string> label;
+            background: red;
+            Text { text: label.label; }
+            label: "Hello";
+        }
+        Label { }
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_convert_to_two_way_binding() {
+        let source = r#"export component Test {
+    in-out property <int> value;
+    property <int> |copy: value;
+    Text { text: "x"; }
+}
+"#;
+        assert_eq!(
+            apply_action(source, "Convert to two-way binding").unwrap(),
+            r#"export component Test {
+    in-out property <int> value;
+    property <int> copy <=> value;
+    Text { text: "x"; }
+}
+"#
+        );
+        assert_eq!(
+            apply_action(
+                &source.replace("|copy", "copy").replace("text:", "|text:"),
+                "Convert to two-way binding"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_imports() {
+        let source = r#"import { LineEdit, |Button, CheckBox, Slider } from "std-widgets.slint";
+import { AboutSlint } from "std-widgets.slint";
+export component Test {
+    LineEdit { }
+    Slider { }
+}
+"#;
+        assert_eq!(
+            apply_action(source, "Remove unused import `Button`").unwrap(),
+            r#"import { LineEdit, CheckBox, Slider } from "std-widgets.slint";
+import { AboutSlint } from "std-widgets.slint";
+export component Test {
+    LineEdit { }
+    Slider { }
+}
+"#
+        );
+        assert_eq!(
+            apply_action(source, "Remove all unused imports").unwrap(),
+            r#"import { LineEdit, Slider } from "std-widgets.slint";
+export component Test {
+    LineEdit { }
+    Slider { }
+}
+"#
+        );
+        assert_eq!(
+            apply_action(source, "Sort imports").unwrap(),
+            r#"import { Button, CheckBox, LineEdit, Slider } from "std-widgets.slint";
+import { AboutSlint } from "std-widgets.slint";
+export component Test {
+    LineEdit { }
+    Slider { }
+}
+"#
+        );
+    }
+}
@@ -188,6 +188,7 @@ pub(crate) fn completion_at(
         SyntaxKind::BindingExpression
             | SyntaxKind::CodeBlock
             | SyntaxKind::ReturnStatement
+            | SyntaxKind::LetStatement
             | SyntaxKind::Expression
             | SyntaxKind::FunctionCallExpression
             | SyntaxKind::SelfAssignment
@@ -0,0 +1,165 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use crate::common;
+use crate::util;
+use i_slint_compiler::parser::{SyntaxKind, SyntaxNode, SyntaxToken, TextSize};
+use lsp_types::{FoldingRange, FoldingRangeKind};
+
+/// The kind of nodes that can be folded
+const FOLDABLE_NODES: &[SyntaxKind] = &[
+    SyntaxKind::Element,
+    SyntaxKind::StructDeclaration,
+    SyntaxKind::EnumDeclaration,
+    SyntaxKind::States,
+    SyntaxKind::State,
+    SyntaxKind::Transitions,
+    SyntaxKind::Transition,
+    SyntaxKind::PropertyAnimation,
+    SyntaxKind::CallbackDeclaration,
+    SyntaxKind::CodeBlock,
+    SyntaxKind::ObjectLiteral,
+    SyntaxKind::Array,
+    SyntaxKind::ImportSpecifier,
+    SyntaxKind::ExportsList,
+];
+
+/// Compute the folding ranges of a document from its syntax tree
+pub fn get_folding_ranges(
+    document_cache: &common::DocumentCache,
+    text_document: &lsp_types::TextDocumentIdentifier,
+) -> Option<Vec<FoldingRange>> {
+    let doc = document_cache.get_document(&text_document.uri)?;
+    let doc_node = doc.node.as_ref()?;
+
+    let mut result: Vec<FoldingRange> = vec![];
+    let mut push_range = |start_line: u32, end_line: u32, kind: Option<FoldingRangeKind>| {
+        if end_line > start_line
+            && !result.iter().any(|r| r.start_line == start_line && r.end_line == end_line)
+        {
+            result.push(FoldingRange { start_line, end_line, kind, ..Default::default() });
+        }
+    };
+
+    for node in doc_node.descendants() {
+        if !FOLDABLE_NODES.contains(&node.kind()) {
+            continue;
+        }
+        let Some(last_token) = last_non_trivia_token(&node) else {
+            continue;
+        };
+        let start_line = line(&node, node.text_range().start());
+        let mut end_line = line(&node, last_token.text_range().start());
+        // Keep the closing brace visible
+        if matches!(last_token.kind(), SyntaxKind::RBrace | SyntaxKind::RBracket) {
+            end_line = end_line.saturating_sub(1);
+        }
+        let kind =
+            (node.kind() == SyntaxKind::ImportSpecifier).then_some(FoldingRangeKind::Imports);
+        push_range(start_line, end_line, kind);
+    }
+
+    // Consecutive comments
+    let mut comments: Option<(u32, u32)> = None;
+    let mut token = doc_node.first_token();
+    while let Some(t) = token {
+        match t.kind() {
+            SyntaxKind::Comment => {
+                let start_line = line(doc_node, t.text_range().start());
+                let end_line = line(doc_node, t.text_range().end());
+                comments = Some(match comments {
+                    Some((start, end)) if start_line <= end + 1 => (start, end_line),
+                    Some((start, end)) => {
+                        push_range(start, end, Some(FoldingRangeKind::Comment));
+                        (start_line, end_line)
+                    }
+                    None => (start_line, end_line),
+                });
+            }
+            SyntaxKind::Whitespace => {}
+            _ => {
+                if let Some((start, end)) = comments.take() {
+                    push_range(start, end, Some(FoldingRangeKind::Comment));
+                }
+            }
+        }
+        token = t.next_token();
+    }
+    if let Some((start, end)) = comments {
+        push_range(start, end, Some(FoldingRangeKind::Comment));
+    }
+
+    result.sort_by_key(|r| (r.start_line, r.end_line));
+    Some(result)
+}
+
+fn line(node: &SyntaxNode, offset: TextSize) -> u32 {
+    util::text_size_to_lsp_position(&node.source_file, offset).line
+}
+
+fn last_non_trivia_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    let mut token = node.last_token();
+    while let Some(t) = token {
+        if !matches!(t.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment) {
+            return node.text_range().contains_range(t.text_range()).then_some(t);
+        }
+        token = t.prev_token();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::loaded_document_cache;
+
+    #[test]
+    fn test_folding_ranges() {
+        let (dc, uri, _) = loaded_document_cache(
+            r#"import {
+    Button,
+    LineEdit,
+} from "std-widgets.slint";
+
+// A comment
+// spanning several lines
+struct Item {
+    name: string,
+}
+
+export component Test {
+    property <[Item]> items: [
+        { name: "a" },
+        { name: "b" },
+    ];
+    Button { text: "single line"; }
+    Rectangle {
+        Text { }
+    }
+    states [
+        pressed when false: {
+            background: red;
+        }
+    ]
+}
+"#
+            .into(),
+        );
+        let result = get_folding_ranges(&dc, &lsp_types::TextDocumentIdentifier { uri }).unwrap();
+        let ranges =
+            result.iter().map(|r| (r.start_line, r.end_line, r.kind.clone())).collect::<Vec<_>>();
+        assert_eq!(
+            ranges,
+            vec![
+                (0, 3, Some(FoldingRangeKind::Imports)),
+                (5, 6, Some(FoldingRangeKind::Comment)),
+                (7, 8, None),
+                (11, 24, None),
+                (12, 14, None),
+                (17, 18, None),
+                (20, 23, None),
+                (21, 22, None),
+            ]
+        );
+    }
+}
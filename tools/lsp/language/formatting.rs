@@ -3,29 +3,62 @@
 
 use crate::common::DocumentCache;
 use crate::fmt::{fmt, writer};
-use crate::util::text_range_to_lsp_range;
+use crate::util::{lsp_position_to_text_size, text_range_to_lsp_range, text_size_to_lsp_position};
 use dissimilar::Chunk;
-use i_slint_compiler::parser::{SyntaxToken, TextRange, TextSize};
-use lsp_types::{DocumentFormattingParams, TextEdit};
+use i_slint_compiler::parser::{syntax_nodes, SyntaxKind, SyntaxToken, TextRange, TextSize};
+use lsp_types::{
+    DocumentFormattingParams, DocumentOnTypeFormattingParams, DocumentRangeFormattingParams,
+    Position, TextEdit,
+};
 
 struct StringWriter {
     text: String,
+    /// Only the tokens intersecting this range are written
+    range: TextRange,
+    /// The range in the original document of the tokens that were written
+    written_range: Option<TextRange>,
+}
+
+impl StringWriter {
+    fn new(range: TextRange) -> Self {
+        Self { text: String::new(), range, written_range: None }
+    }
+
+    fn should_write(&mut self, token: &SyntaxToken) -> bool {
+        let token_range = token.text_range();
+        let write = if token_range.is_empty() {
+            self.range.contains_range(token_range)
+        } else {
+            self.range.intersect(token_range).is_some_and(|r| !r.is_empty())
+        };
+        if write {
+            self.written_range =
+                Some(self.written_range.map_or(token_range, |r| r.cover(token_range)));
+        }
+        write
+    }
 }
 
 impl writer::TokenWriter for StringWriter {
     fn no_change(&mut self, token: SyntaxToken) -> std::io::Result<()> {
-        self.text += token.text();
+        if self.should_write(&token) {
+            self.text += token.text();
+        }
         Ok(())
     }
 
-    fn with_new_content(&mut self, _token: SyntaxToken, contents: &str) -> std::io::Result<()> {
-        self.text += contents;
+    fn with_new_content(&mut self, token: SyntaxToken, contents: &str) -> std::io::Result<()> {
+        if self.should_write(&token) {
+            self.text += contents;
+        }
         Ok(())
     }
 
     fn insert_before(&mut self, token: SyntaxToken, contents: &str) -> std::io::Result<()> {
-        self.text += contents;
-        self.text += token.text();
+        if self.should_write(&token) {
+            self.text += contents;
+            self.text += token.text();
+        }
         Ok(())
     }
 }
@@ -36,14 +69,80 @@ pub fn format_document(
 ) -> Option<Vec<TextEdit>> {
     let doc = document_cache.get_document(&params.text_document.uri)?;
     let doc = doc.node.as_ref()?;
+    format_range(doc, doc.text_range())
+}
 
-    let mut writer = StringWriter { text: String::new() };
+/// Format the tokens of the document that are within the given range
+pub fn format_document_range(
+    params: DocumentRangeFormattingParams,
+    document_cache: &DocumentCache,
+) -> Option<Vec<TextEdit>> {
+    let doc = document_cache.get_document(&params.text_document.uri)?;
+    let doc = doc.node.as_ref()?;
+    let range = TextRange::new(
+        lsp_position_to_text_size(&doc.source_file, params.range.start),
+        lsp_position_to_text_size(&doc.source_file, params.range.end),
+    );
+    format_range(doc, range)
+}
+
+/// Re-format after a `}` or a `;` was typed:
+///  * `}` formats the node it closes, so that the whole block is re-indented
+///  * `;` formats the line it ends
+pub fn format_on_type(
+    params: DocumentOnTypeFormattingParams,
+    document_cache: &DocumentCache,
+) -> Option<Vec<TextEdit>> {
+    let position = params.text_document_position.position;
+    let doc = document_cache.get_document(&params.text_document_position.text_document.uri)?;
+    let doc = doc.node.as_ref()?;
+    let offset = lsp_position_to_text_size(&doc.source_file, position);
+
+    let token = doc.token_at_offset(offset).left_biased()?;
+    if token.text() != params.ch {
+        return None;
+    }
+    let range = match token.kind() {
+        SyntaxKind::RBrace => {
+            // Include the indentation of the first line of the node
+            let start =
+                text_size_to_lsp_position(&doc.source_file, token.parent().text_range().start());
+            let line_start =
+                lsp_position_to_text_size(&doc.source_file, Position::new(start.line, 0));
+            TextRange::new(line_start, token.text_range().end())
+        }
+        SyntaxKind::Semicolon => {
+            let line_start =
+                lsp_position_to_text_size(&doc.source_file, Position::new(position.line, 0));
+            TextRange::new(line_start, token.text_range().end())
+        }
+        _ => return None,
+    };
+    format_range(doc, range)
+}
+
+fn format_range(doc: &syntax_nodes::Document, mut range: TextRange) -> Option<Vec<TextEdit>> {
+    // The formatter emits the whitespace before a token together with that token, so the
+    // whitespace at the end of the range must be left alone
+    if range.end() < doc.text_range().end() {
+        if let Some(ws) = doc
+            .token_at_offset(range.end())
+            .left_biased()
+            .filter(|t| t.kind() == SyntaxKind::Whitespace)
+        {
+            range = TextRange::new(range.start(), ws.text_range().start().max(range.start()));
+        }
+    }
+    let mut writer = StringWriter::new(range);
     fmt::format_document(doc.clone(), &mut writer).ok()?;
 
-    let original: String = doc.text().into();
+    let Some(written_range) = writer.written_range else {
+        return Some(Vec::new());
+    };
+    let original = doc.text().slice(written_range).to_string();
     let diff = dissimilar::diff(&original, &writer.text);
 
-    let mut pos = TextSize::default();
+    let mut pos = written_range.start();
     let mut last_was_deleted = false;
     let mut edits: Vec<TextEdit> = Vec::new();
 
@@ -81,7 +180,7 @@ pub fn format_document(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use lsp_types::{Position, Range};
+    use lsp_types::Range;
 
     /// Given an unformatted source text, return text edits that will turn the source into formatted text
     fn get_formatting_edits(source: &str) -> Option<Vec<TextEdit>> {
@@ -125,4 +224,65 @@ macro_rules! text_edit {
             assert_eq!(actual, expected);
         }
     }
+
+    /// Apply the edits (which are sorted and don't overlap) to the source
+    fn apply_edits(source: &str, edits: &[TextEdit]) -> String {
+        let sf = std::rc::Rc::new(i_slint_compiler::diagnostics::SourceFileInner::new(
+            Default::default(),
+            source.into(),
+        ));
+        let mut result = source.to_string();
+        for edit in edits.iter().rev() {
+            let start = usize::from(lsp_position_to_text_size(&sf, edit.range.start));
+            let end = usize::from(lsp_position_to_text_size(&sf, edit.range.end));
+            result.replace_range(start..end, &edit.new_text);
+        }
+        result
+    }
+
+    #[test]
+    fn test_range_formatting() {
+        let source = "component A {\n  Rectangle {\n width:10px; }\n      Text {text:\"a\";}\n}\n";
+        let (dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+        let params = lsp_types::DocumentRangeFormattingParams {
+            text_document: lsp_types::TextDocumentIdentifier { uri },
+            range: Range::new(Position::new(3, 0), Position::new(4, 0)),
+            options: lsp_types::FormattingOptions::default(),
+            work_done_progress_params: lsp_types::WorkDoneProgressParams::default(),
+        };
+        let edits = format_document_range(params, &dc).unwrap();
+        assert_eq!(
+            apply_edits(source, &edits),
+            "component A {\n  Rectangle {\n width:10px; }\n\n    Text {\n        text: \"a\";\n    }\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_on_type_formatting() {
+        let on_type = |source: &str, ch: &str, position: Position| {
+            let (dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+            let params = lsp_types::DocumentOnTypeFormattingParams {
+                text_document_position: lsp_types::TextDocumentPositionParams {
+                    text_document: lsp_types::TextDocumentIdentifier { uri },
+                    position,
+                },
+                ch: ch.into(),
+                options: lsp_types::FormattingOptions::default(),
+            };
+            let edits = format_on_type(params, &dc).unwrap_or_default();
+            apply_edits(source, &edits)
+        };
+
+        let source = "component A {\n    Rectangle {\n  width:10px;\n          }\n    Text {text:\"a\";}\n}\n";
+        assert_eq!(
+            on_type(source, "}", Position::new(3, 11)),
+            "component A {\n    Rectangle {\n        width: 10px;\n    }\n    Text {text:\"a\";}\n}\n"
+        );
+        assert_eq!(
+            on_type(source, ";", Position::new(2, 13)),
+            "component A {\n    Rectangle {\n        width: 10px;\n          }\n    Text {text:\"a\";}\n}\n"
+        );
+        // Nothing to do if the character isn't at the position
+        assert_eq!(on_type(source, "}", Position::new(2, 13)), source);
+    }
 }
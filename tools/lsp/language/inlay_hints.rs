@@ -0,0 +1,293 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use crate::common::{
+    self,
+    token_info::{token_info, TokenInfo},
+};
+use crate::util;
+use i_slint_compiler::langtype::{ElementType, Type};
+use i_slint_compiler::object_tree::{recurse_elem_including_sub_components, ElementRc};
+use i_slint_compiler::parser::{syntax_nodes, SyntaxKind, SyntaxNode, TextSize};
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Range};
+use smol_str::SmolStr;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Compute the inlay hints of the document in the given `range`:
+///
+///  * The type of property declarations that don't spell out their type
+///  * The name of the parameters in callback and function invocations
+///  * The component a property set in a binding is inherited from
+///  * The properties that are two-way bound to a property declaration
+pub fn get_inlay_hints(
+    document_cache: &common::DocumentCache,
+    text_document: &lsp_types::TextDocumentIdentifier,
+    range: &Range,
+) -> Option<Vec<InlayHint>> {
+    let doc = document_cache.get_document(&text_document.uri)?;
+    let doc_node = doc.node.as_ref()?;
+    let source_file = &doc_node.source_file;
+
+    let mut result = vec![];
+    let mut push_hint = |offset: TextSize, label: String, kind, padding_left, padding_right| {
+        let position = util::text_size_to_lsp_position(source_file, offset);
+        if position < range.start || position > range.end {
+            return;
+        }
+        result.push(InlayHint {
+            position,
+            label: InlayHintLabel::String(label),
+            kind,
+            text_edits: None,
+            tooltip: None,
+            padding_left: Some(padding_left),
+            padding_right: Some(padding_right),
+            data: None,
+        });
+    };
+
+    let mut elements = vec![];
+    for component in &doc.inner_components {
+        recurse_elem_including_sub_components(component, &(), &mut |element, _| {
+            elements.push(element.clone());
+        });
+    }
+
+    // (element, property) -> the properties that are two-way bound to it
+    let mut two_way_partners: HashMap<(*const (), SmolStr), Vec<String>> = HashMap::new();
+    for element in &elements {
+        let e = element.borrow();
+        for (name, binding) in &e.bindings {
+            for nr in &binding.borrow().two_way_bindings {
+                two_way_partners
+                    .entry((Rc::as_ptr(&nr.element()) as *const (), nr.name().clone()))
+                    .or_default()
+                    .push(format!("{}.{name}", element_label(element)));
+            }
+        }
+    }
+
+    for element in &elements {
+        let e = element.borrow();
+        let Some(node) = e.debug.first().map(|d| d.node.clone()) else {
+            continue;
+        };
+        if node.source_file.path() != source_file.path() {
+            continue;
+        }
+
+        for declaration in node.PropertyDeclaration() {
+            let identifier = declaration.DeclaredIdentifier();
+            let Some(name) = i_slint_compiler::parser::identifier_text(&identifier) else {
+                continue;
+            };
+            if declaration.Type().is_none() {
+                if let Some(ty) = e
+                    .property_declarations
+                    .get(&name)
+                    .map(|d| &d.property_type)
+                    .filter(|ty| !matches!(ty, Type::Invalid | Type::InferredProperty))
+                {
+                    push_hint(
+                        identifier.text_range().start(),
+                        format!("<{ty}>"),
+                        Some(InlayHintKind::TYPE),
+                        false,
+                        true,
+                    );
+                }
+            }
+            if let Some(partners) =
+                two_way_partners.get(&(Rc::as_ptr(element) as *const (), name.clone()))
+            {
+                push_hint(
+                    util::node_range_without_trailing_ws(&identifier).end(),
+                    format!("<=> {}", partners.join(", ")),
+                    None,
+                    true,
+                    false,
+                );
+            }
+        }
+
+        let bindings = node
+            .Binding()
+            .map(|b| (*b).clone())
+            .chain(node.TwoWayBinding().map(|b| (*b).clone()))
+            .chain(node.CallbackConnection().map(|b| (*b).clone()));
+        for binding in bindings {
+            let Some(name) = i_slint_compiler::parser::identifier_text(&binding) else {
+                continue;
+            };
+            if let Some(origin) = inherited_from(element, &name) {
+                let Some(identifier) = binding
+                    .children_with_tokens()
+                    .filter_map(|t| t.into_token())
+                    .find(|t| t.kind() == SyntaxKind::Identifier)
+                else {
+                    continue;
+                };
+                push_hint(
+                    identifier.text_range().end(),
+                    format!("from {origin}"),
+                    None,
+                    true,
+                    false,
+                );
+            }
+        }
+    }
+
+    for node in doc_node.descendants() {
+        let Some(call) = syntax_nodes::FunctionCallExpression::new(node) else {
+            continue;
+        };
+        let mut arguments = call.Expression();
+        let Some(arg_names) =
+            arguments.next().and_then(|callee| callee_arg_names(document_cache, &callee))
+        else {
+            continue;
+        };
+        for (argument, arg_name) in arguments.zip(arg_names.iter()) {
+            if arg_name.is_empty()
+                || i_slint_compiler::parser::normalize_identifier(
+                    argument.text().to_string().trim(),
+                ) == *arg_name
+            {
+                continue;
+            }
+            push_hint(
+                argument.text_range().start(),
+                format!("{arg_name}:"),
+                Some(InlayHintKind::PARAMETER),
+                false,
+                true,
+            );
+        }
+    }
+
+    result.sort_by_key(|hint| hint.position);
+    Some(result)
+}
+
+/// A short name for the element: its id, or the name of its type
+fn element_label(element: &ElementRc) -> String {
+    let e = element.borrow();
+    let is_root =
+        e.enclosing_component.upgrade().is_some_and(|c| Rc::ptr_eq(&c.root_element, element));
+    if is_root {
+        "root".into()
+    } else if !e.id.is_empty() {
+        e.id.to_string()
+    } else {
+        e.base_type.to_string()
+    }
+}
+
+/// Returns the name of the component from which `element` inherits the property `name`, if
+/// it isn't declared by the element itself or by a builtin element.
+fn inherited_from(element: &ElementRc, name: &SmolStr) -> Option<SmolStr> {
+    if element.borrow().property_declarations.contains_key(name) {
+        return None;
+    }
+    let mut base = element.borrow().base_type.clone();
+    while let ElementType::Component(component) = base {
+        if component.root_element.borrow().property_declarations.contains_key(name) {
+            let is_builtin = component
+                .node
+                .as_ref()
+                .is_some_and(|n| n.source_file.path().starts_with("builtin:/"));
+            return (!is_builtin).then(|| component.id.clone());
+        }
+        base = component.root_element.borrow().base_type.clone();
+    }
+    None
+}
+
+/// The names of the arguments of the callback or function called by `callee`
+fn callee_arg_names(
+    document_cache: &common::DocumentCache,
+    callee: &SyntaxNode,
+) -> Option<Vec<SmolStr>> {
+    let token = callee
+        .child_node(SyntaxKind::QualifiedName)?
+        .children_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Identifier)
+        .last()?;
+    let TokenInfo::NamedReference(nr) = token_info(document_cache, token)? else {
+        return None;
+    };
+    match nr.ty() {
+        Type::Callback(f) | Type::Function(f) => Some(f.arg_names.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::loaded_document_cache;
+
+    fn hints(source: &str) -> Vec<String> {
+        let (dc, url, diag) = loaded_document_cache(source.into());
+        assert!(diag.values().all(|d| d.is_empty()), "{diag:?}");
+        let range = Range::new(Default::default(), lsp_types::Position::new(u32::MAX, 0));
+        let result =
+            get_inlay_hints(&dc, &lsp_types::TextDocumentIdentifier { uri: url.clone() }, &range)
+                .unwrap();
+        let doc = dc.get_document(&url).unwrap().node.clone().unwrap();
+        result
+            .into_iter()
+            .map(|hint| {
+                let InlayHintLabel::String(label) = hint.label else { unreachable!() };
+                let offset: usize =
+                    util::lsp_position_to_text_size(&doc.source_file, hint.position).into();
+                let line_start = source[..offset].rfind('\n').map_or(0, |p| p + 1);
+                let line_end = source[offset..].find('\n').map_or(source.len(), |p| p + offset);
+                format!(
+                    "{}[{label}]{}",
+                    source[line_start..offset].trim_start(),
+                    &source[offset..line_end]
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_inlay_hints() {
+        let source = r#"
+component Base {
+    in-out property <int> counter;
+    callback clicked(count: int, text: string);
+}
+export component Test {
+    property <int> value;
+    property name <=> base.counter;
+    base := Base {
+        counter <=> root.value;
+        clicked(count, text) => {}
+    }
+    function greet(count: int, greeting: string) {}
+    init => {
+        greet(3, "hello");
+        base.clicked(value, "x");
+    }
+}
+"#;
+        assert_eq!(
+            hints(source),
+            vec![
+                "property <int> value[<=> base.counter];",
+                "property [<int>]name <=> base.counter;",
+                "counter[from Base] <=> root.value;",
+                "clicked[from Base](count, text) => {}",
+                "greet([count:]3, \"hello\");",
+                "greet(3, [greeting:]\"hello\");",
+                "base.clicked([count:]value, \"x\");",
+                "base.clicked(value, [text:]\"x\");",
+            ]
+        );
+    }
+}
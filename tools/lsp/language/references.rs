@@ -0,0 +1,325 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use crate::common::{
+    self,
+    token_info::{token_info, TokenInfo},
+};
+use crate::util;
+use i_slint_compiler::langtype::{ElementType, Type};
+use i_slint_compiler::parser::{identifier_text, SyntaxKind, SyntaxNode, SyntaxToken};
+use lsp_types::Location;
+use smol_str::SmolStr;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// Find all the places in the loaded documents that refer to the symbol at `token`.
+///
+/// This works for properties, callbacks, functions, globals, structs, enums and components.
+/// Every identifier with the same name, or with a name given to the symbol by a renaming
+/// import, is resolved and kept if it resolves to the same declaration as `token`.
+pub fn find_references(
+    document_cache: &common::DocumentCache,
+    token: SyntaxToken,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    if token.kind() != SyntaxKind::Identifier {
+        return None;
+    }
+    let declaration = find_declaration(document_cache, &token)?;
+    let names =
+        known_names(document_cache, i_slint_compiler::parser::normalize_identifier(token.text()));
+
+    let mut result = vec![];
+    for (url, document_node) in document_cache.all_url_documents() {
+        if url.scheme() == "builtin" {
+            continue;
+        }
+        let mut current_token = document_node.first_token();
+        while let Some(current) = current_token {
+            if current.kind() == SyntaxKind::Identifier
+                && names.contains(&i_slint_compiler::parser::normalize_identifier(current.text()))
+                && (include_declaration || !is_declared_identifier_of(&current, &declaration))
+                && find_declaration(document_cache, &current)
+                    .is_some_and(|d| is_same_node(&d, &declaration))
+            {
+                result
+                    .push(Location { uri: url.clone(), range: util::token_to_lsp_range(&current) });
+            }
+            current_token = current.next_token();
+        }
+    }
+
+    (!result.is_empty()).then_some(result)
+}
+
+/// Returns `name` together with all the names it is known under through `import { A as B }`
+/// statements, in any of the loaded documents.
+fn known_names(document_cache: &common::DocumentCache, name: SmolStr) -> HashSet<SmolStr> {
+    let renames = document_cache
+        .all_url_documents()
+        .flat_map(|(_, document_node)| document_node.ImportSpecifier())
+        .filter_map(|import| import.ImportIdentifierList())
+        .flat_map(|list| list.ImportIdentifier())
+        .filter_map(|identifier| {
+            let external = identifier_text(&identifier.ExternalName())?;
+            let internal = identifier.InternalName().and_then(|n| identifier_text(&n))?;
+            Some((external, internal))
+        })
+        .collect::<Vec<_>>();
+
+    // Follow the chains of renames, in both directions
+    let mut names = HashSet::from([name]);
+    loop {
+        let count = names.len();
+        for (external, internal) in &renames {
+            if names.contains(external) || names.contains(internal) {
+                names.insert(external.clone());
+                names.insert(internal.clone());
+            }
+        }
+        if names.len() == count {
+            return names;
+        }
+    }
+}
+
+/// Returns the node declaring the symbol `token` refers to.
+fn find_declaration(
+    document_cache: &common::DocumentCache,
+    token: &SyntaxToken,
+) -> Option<SyntaxNode> {
+    let parent = token.parent();
+    if parent.kind() == SyntaxKind::DeclaredIdentifier {
+        let declaration = parent.parent()?;
+        if matches!(
+            declaration.kind(),
+            SyntaxKind::Component
+                | SyntaxKind::StructDeclaration
+                | SyntaxKind::EnumDeclaration
+                | SyntaxKind::PropertyDeclaration
+                | SyntaxKind::CallbackDeclaration
+                | SyntaxKind::Function
+        ) {
+            return Some(declaration);
+        }
+    }
+
+    match token_info(document_cache, token.clone())? {
+        TokenInfo::Type(Type::Struct(s)) => s.node.as_ref()?.parent(),
+        TokenInfo::Type(Type::Enumeration(e)) => e.node.clone().map(Into::into),
+        TokenInfo::Type(_) => None,
+        TokenInfo::ElementType(ElementType::Component(c)) => c.node.clone(),
+        TokenInfo::ElementType(_) => None,
+        TokenInfo::NamedReference(nr) => {
+            let mut element = nr.element();
+            loop {
+                if let Some(p) = element.borrow().property_declarations.get(nr.name()) {
+                    return p.node.clone();
+                }
+                let base = element.borrow().base_type.clone();
+                match base {
+                    ElementType::Component(c) => element = c.root_element.clone(),
+                    _ => return None,
+                }
+            }
+        }
+        TokenInfo::IncompleteNamedReference(mut element_type, name) => {
+            while let ElementType::Component(c) = element_type {
+                if let Some(p) = c.root_element.borrow().property_declarations.get(&name) {
+                    return p.node.clone();
+                }
+                element_type = c.root_element.borrow().base_type.clone();
+            }
+            None
+        }
+        TokenInfo::LocalProperty(p) => Some(p.into()),
+        TokenInfo::LocalCallback(c) => Some(c.into()),
+        TokenInfo::ElementRc(element) => {
+            // Globals are referred to by their root element in expressions
+            let component = element.borrow().enclosing_component.upgrade()?;
+            (component.is_global() && Rc::ptr_eq(&component.root_element, &element))
+                .then(|| component.node.clone())
+                .flatten()
+        }
+        TokenInfo::EnumerationValue(_) | TokenInfo::FileName(_) | TokenInfo::Image(_) => None,
+    }
+}
+
+fn is_same_node(a: &SyntaxNode, b: &SyntaxNode) -> bool {
+    a.source_file.path() == b.source_file.path() && a.text_range() == b.text_range()
+}
+
+fn is_declared_identifier_of(token: &SyntaxToken, declaration: &SyntaxNode) -> bool {
+    let parent = token.parent();
+    parent.kind() == SyntaxKind::DeclaredIdentifier
+        && parent.parent().is_some_and(|p| is_same_node(&p, declaration))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::loaded_document_cache;
+
+    #[track_caller]
+    fn references_at(source: &str, marker: &str, include_declaration: bool) -> Vec<String> {
+        let (dc, url, _) = loaded_document_cache(source.into());
+        let doc = dc.get_document(&url).unwrap().node.clone().unwrap();
+        let offset = source.find(marker).unwrap() as u32;
+        let token = crate::language::token_at_offset(&doc, offset.into()).unwrap();
+        let mut result = find_references(&dc, token, include_declaration)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|location| {
+                assert_eq!(location.uri, url);
+                let sf = &doc.source_file;
+                let start = util::lsp_position_to_text_size(sf, location.range.start);
+                let end = util::lsp_position_to_text_size(sf, location.range.end);
+                let line_start = source[..usize::from(start)].rfind('\n').map_or(0, |p| p + 1);
+                format!(
+                    "{}|{}",
+                    &source[line_start..usize::from(start)].trim_start(),
+                    &source[usize::from(start)..usize::from(end)]
+                )
+            })
+            .collect::<Vec<_>>();
+        result.sort();
+        result
+    }
+
+    #[test]
+    fn test_property_references() {
+        let source = r#"
+component Abc {
+    in property <int> the-value;
+    Text { text: root.the-value; }
+}
+export component Test {
+    property <int> the_value: 42;
+    abc := Abc { the-value: root.the-value; }
+    changed the-value => { abc.the-value += 1; }
+}
+"#;
+        assert_eq!(
+            references_at(source, "the-value;\n    Text", true),
+            vec![
+                "Text { text: root.|the-value",
+                "abc := Abc { |the-value",
+                "changed the-value => { abc.|the-value",
+                "in property <int> |the-value",
+            ]
+        );
+        assert_eq!(
+            references_at(source, "the_value: 42", false),
+            vec!["abc := Abc { the-value: root.|the-value", "changed |the-value"]
+        );
+    }
+
+    #[test]
+    fn test_callback_and_function_references() {
+        let source = r#"
+export component Test {
+    callback clicked(int);
+    function do-it() { clicked(42); }
+    clicked(x) => { do-it(); }
+    TouchArea { clicked => { do-it(); root.clicked(1); } }
+}
+"#;
+        assert_eq!(
+            references_at(source, "clicked(int)", true),
+            vec![
+                "TouchArea { clicked => { do-it(); root.|clicked",
+                "callback |clicked",
+                "function do-it() { |clicked",
+                "|clicked",
+            ]
+        );
+        assert_eq!(
+            references_at(source, "do-it() {", false),
+            vec!["TouchArea { clicked => { |do-it", "clicked(x) => { |do-it"]
+        );
+    }
+
+    #[test]
+    fn test_type_references() {
+        let source = r#"
+struct Point { x: int, y: int }
+enum Direction { up, down }
+global Settings {
+    in-out property <Direction> direction: Direction.up;
+    in-out property <Point> origin;
+}
+component Marker {
+    in property <Point> position: Settings.origin;
+}
+export component Test {
+    property <Direction> dir: Settings.direction;
+    Marker { }
+    Marker { position: { x: 1, y: 2 }; }
+}
+"#;
+        assert_eq!(
+            references_at(source, "Point {", true),
+            vec!["in property <|Point", "in-out property <|Point", "struct |Point",]
+        );
+        assert_eq!(
+            references_at(source, "Direction {", false),
+            vec![
+                "in-out property <Direction> direction: |Direction",
+                "in-out property <|Direction",
+                "property <|Direction",
+            ]
+        );
+        assert_eq!(
+            references_at(source, "Settings {", false),
+            vec!["in property <Point> position: |Settings", "property <Direction> dir: |Settings",]
+        );
+        assert_eq!(references_at(source, "Marker {\n", false), vec!["|Marker", "|Marker"]);
+    }
+    #[test]
+    fn test_renamed_import_references() {
+        let source1 = r#"
+export component Button {
+    in property <string> text;
+}
+"#;
+        let (mut dc, url1, _) = loaded_document_cache(source1.into());
+        let url2 = url1.join("../file2.slint").unwrap();
+        let source2 = format!(
+            r#"
+import {{ Button as MyButton }} from "{}";
+export component Test {{
+    MyButton {{ text: "Hello"; }}
+}}
+"#,
+            url1.to_file_path().unwrap().display()
+        );
+        let (_, diag) = spin_on::spin_on(crate::language::reload_document_impl(
+            None,
+            source2.clone(),
+            url2.clone(),
+            Some(1),
+            &mut dc,
+        ));
+        assert!(!diag.has_errors());
+
+        let count_in = |locations: &[Location], url: &lsp_types::Url| {
+            locations.iter().filter(|l| l.uri == *url).count()
+        };
+
+        let doc1 = dc.get_document(&url1).unwrap().node.clone().unwrap();
+        let offset = source1.find("Button {").unwrap() as u32;
+        let token = crate::language::token_at_offset(&doc1, offset.into()).unwrap();
+        let result = find_references(&dc, token, false).unwrap();
+        // `Button` and `MyButton` in the import, and the `MyButton` element
+        assert_eq!(count_in(&result, &url1), 0);
+        assert_eq!(count_in(&result, &url2), 3);
+
+        let doc2 = dc.get_document(&url2).unwrap().node.clone().unwrap();
+        let offset = source2.find("MyButton {").unwrap() as u32;
+        let token = crate::language::token_at_offset(&doc2, offset.into()).unwrap();
+        let result = find_references(&dc, token, true).unwrap();
+        assert_eq!(count_in(&result, &url1), 1);
+        assert_eq!(count_in(&result, &url2), 3);
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use crate::common;
+use crate::util;
+use i_slint_compiler::parser::{SyntaxKind, TextRange};
+use lsp_types::{Position, SelectionRange};
+
+/// Compute the selection ranges for each of the `positions`.
+///
+/// The ranges go from the token at the position up to the whole document, following the
+/// nodes of the syntax tree.
+pub fn get_selection_ranges(
+    document_cache: &common::DocumentCache,
+    text_document: &lsp_types::TextDocumentIdentifier,
+    positions: &[Position],
+) -> Option<Vec<SelectionRange>> {
+    let doc = document_cache.get_document(&text_document.uri)?;
+    let doc_node = doc.node.as_ref()?;
+    let source_file = &doc_node.source_file;
+
+    let selection_range = |position: Position| {
+        let offset = util::lsp_position_to_text_size(source_file, position);
+        let token = crate::language::token_at_offset(doc_node, offset)?;
+
+        let mut ranges: Vec<TextRange> = vec![];
+        if !matches!(token.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment) {
+            ranges.push(token.text_range());
+        }
+        for node in token.parent_ancestors() {
+            let range = util::node_range_without_trailing_ws(&node);
+            if ranges.last().is_none_or(|last| *last != range && range.contains_range(*last)) {
+                ranges.push(range);
+            }
+        }
+
+        ranges.into_iter().rev().fold(None, |parent, range| {
+            Some(SelectionRange {
+                range: util::text_range_to_lsp_range(source_file, range),
+                parent: parent.map(Box::new),
+            })
+        })
+    };
+
+    // The result must have one entry per position, so fall back to an empty range at the position
+    Some(
+        positions
+            .iter()
+            .map(|position| {
+                selection_range(*position).unwrap_or_else(|| SelectionRange {
+                    range: lsp_types::Range::new(*position, *position),
+                    parent: None,
+                })
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::loaded_document_cache;
+
+    #[test]
+    fn test_selection_ranges() {
+        let source = r#"export component Test {
+    Rectangle {
+        Text { text: "Hello " + 42; }
+    }
+}
+"#;
+        let (dc, uri, _) = loaded_document_cache(source.into());
+        let sf = dc.get_document(&uri).unwrap().node.clone().unwrap().source_file.clone();
+        let offset = source.find("42").unwrap() as u32;
+        let position = util::text_size_to_lsp_position(&sf, offset.into());
+
+        let result =
+            get_selection_ranges(&dc, &lsp_types::TextDocumentIdentifier { uri }, &[position])
+                .unwrap();
+        assert_eq!(result.len(), 1);
+
+        let mut selected = vec![];
+        let mut current = Some(&result[0]);
+        while let Some(r) = current {
+            let start = usize::from(util::lsp_position_to_text_size(&sf, r.range.start));
+            let end = usize::from(util::lsp_position_to_text_size(&sf, r.range.end));
+            selected.push(&source[start..end]);
+            current = r.parent.as_deref();
+        }
+        assert_eq!(
+            selected,
+            vec![
+                "42",
+                "\"Hello \" + 42",
+                "\"Hello \" + 42;",
+                "text: \"Hello \" + 42;",
+                "Text { text: \"Hello \" + 42; }",
+                "Rectangle {\n        Text { text: \"Hello \" + 42; }\n    }",
+                "{\n    Rectangle {\n        Text { text: \"Hello \" + 42; }\n    }\n}",
+                "component Test {\n    Rectangle {\n        Text { text: \"Hello \" + 42; }\n    }\n}",
+                "export component Test {\n    Rectangle {\n        Text { text: \"Hello \" + 42; }\n    }\n}",
+            ]
+        );
+    }
+    #[test]
+    fn test_selection_ranges_empty_document() {
+        let (dc, uri, _) = loaded_document_cache(String::new());
+        let positions = [Position::new(0, 0), Position::new(1, 0)];
+
+        let result =
+            get_selection_ranges(&dc, &lsp_types::TextDocumentIdentifier { uri }, &positions)
+                .unwrap();
+        assert_eq!(result.len(), positions.len());
+        assert!(result.iter().all(|r| r.range.start == r.range.end && r.parent.is_none()));
+    }
+}
@@ -53,6 +53,7 @@ pub fn get_semantic_tokens(
                 SyntaxKind::CallbackConnection => Some((self::FUNCTION, 0)),
                 SyntaxKind::PropertyDeclaration => Some((self::KEYWORD, 0)),
                 SyntaxKind::Function => Some((self::KEYWORD, 0)),
+                SyntaxKind::LetStatement => Some((self::KEYWORD, 0)),
                 SyntaxKind::PropertyAnimation => Some((self::KEYWORD, 0)),
                 SyntaxKind::EnumValue => Some((self::ENUM_MEMBER, 1 << self::DEFINITION)),
                 SyntaxKind::QualifiedName => match token.parent().parent()?.kind() {
@@ -80,6 +81,7 @@ pub fn get_semantic_tokens(
                         SyntaxKind::PropertyDeclaration => {
                             Some((self::PROPERTY, 1 << self::DEFINITION))
                         }
+                        SyntaxKind::LetStatement => Some((self::VARIABLE, 1 << self::DEFINITION)),
                         SyntaxKind::State | SyntaxKind::Transition => {
                             // This is the state name, but what semantic type is that?
                             None
@@ -0,0 +1,158 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use crate::common;
+use crate::util;
+use i_slint_compiler::langtype::Type;
+use i_slint_compiler::parser::{SyntaxKind, SyntaxNode};
+use lsp_types::{Location, OneOf, SymbolKind, WorkspaceSymbol, WorkspaceSymbolResponse};
+
+/// Search the components, globals, structs and enums of all loaded documents, as well as the
+/// properties, callbacks and functions they declare, for symbols matching `query`.
+///
+/// Documents that are part of Slint itself (like the widget styles) are not searched.
+pub fn get_workspace_symbols(
+    document_cache: &common::DocumentCache,
+    query: &str,
+) -> Option<WorkspaceSymbolResponse> {
+    let query = i_slint_compiler::parser::normalize_identifier(query).to_lowercase();
+    let matches = |name: &str| {
+        i_slint_compiler::parser::normalize_identifier(name).to_lowercase().contains(&query)
+    };
+
+    let mut result = vec![];
+    let mut push_symbol =
+        |name: String, kind, container_name: Option<String>, node: &SyntaxNode| {
+            if !matches(&name) {
+                return;
+            }
+            let Some((uri, range)) = util::node_to_url_and_lsp_range(node) else {
+                return;
+            };
+            result.push(WorkspaceSymbol {
+                name,
+                kind,
+                tags: None,
+                container_name,
+                location: OneOf::Left(Location { uri, range }),
+                data: None,
+            });
+        };
+
+    for url in document_cache.all_urls() {
+        if url.scheme() == "builtin" {
+            continue;
+        }
+        let Some(doc) = document_cache.get_document(&url) else {
+            continue;
+        };
+
+        for component in &doc.inner_components {
+            let Some(component_node) = component.node.as_ref() else {
+                continue;
+            };
+            if component.id.is_empty() {
+                continue;
+            }
+            push_symbol(
+                component.id.to_string(),
+                if component.is_global() { SymbolKind::OBJECT } else { SymbolKind::CLASS },
+                None,
+                component_node,
+            );
+
+            for node in component_node.descendants() {
+                let kind = match node.kind() {
+                    SyntaxKind::PropertyDeclaration => SymbolKind::PROPERTY,
+                    SyntaxKind::CallbackDeclaration => SymbolKind::EVENT,
+                    SyntaxKind::Function => SymbolKind::FUNCTION,
+                    _ => continue,
+                };
+                let Some(name) = node
+                    .child_node(SyntaxKind::DeclaredIdentifier)
+                    .and_then(|id| i_slint_compiler::parser::identifier_text(&id))
+                else {
+                    continue;
+                };
+                push_symbol(name.to_string(), kind, Some(component.id.to_string()), &node);
+            }
+        }
+
+        for ty in &doc.inner_types {
+            match ty {
+                Type::Struct(s) => {
+                    let (Some(name), Some(node)) =
+                        (s.name.as_ref(), s.node.as_ref().and_then(|n| n.parent()))
+                    else {
+                        continue;
+                    };
+                    push_symbol(name.to_string(), SymbolKind::STRUCT, None, &node);
+                }
+                Type::Enumeration(e) => {
+                    let Some(node) = e.node.as_ref() else {
+                        continue;
+                    };
+                    push_symbol(e.name.to_string(), SymbolKind::ENUM, None, node);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Some(WorkspaceSymbolResponse::Nested(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::loaded_document_cache;
+
+    fn symbol_names(dc: &common::DocumentCache, query: &str) -> Vec<(String, Option<String>)> {
+        let Some(WorkspaceSymbolResponse::Nested(symbols)) = get_workspace_symbols(dc, query)
+        else {
+            panic!("Expected nested workspace symbols");
+        };
+        let mut names = symbols.into_iter().map(|s| (s.name, s.container_name)).collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_workspace_symbols() {
+        let (dc, _, _) = loaded_document_cache(
+            r#"import { Button } from "std-widgets.slint";
+struct TodoItem { title: string, checked: bool }
+enum Filter { all, done }
+global TodoSettings {
+    in-out property <Filter> filter;
+}
+export component TodoList {
+    in property <[TodoItem]> todo_items;
+    callback todo-added(string);
+    function clear-todos() {}
+    Button { text: "Add"; }
+}
+"#
+            .into(),
+        );
+
+        assert_eq!(
+            symbol_names(&dc, "todo"),
+            vec![
+                ("TodoItem".into(), None),
+                ("TodoList".into(), None),
+                ("TodoSettings".into(), None),
+                ("clear-todos".into(), Some("TodoList".into())),
+                ("todo-added".into(), Some("TodoList".into())),
+                ("todo-items".into(), Some("TodoList".into())),
+            ]
+        );
+        assert_eq!(
+            symbol_names(&dc, "FILTER"),
+            vec![("Filter".into(), None), ("filter".into(), Some("TodoSettings".into()))]
+        );
+        // Symbols from the widget styles are not listed
+        assert!(symbol_names(&dc, "Button").is_empty());
+        assert_eq!(symbol_names(&dc, "").len(), 8);
+    }
+}
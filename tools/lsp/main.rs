@@ -82,6 +82,10 @@ pub struct Cli {
     #[arg(long, action)]
     no_toolbar: bool,
 
+    /// Warn about unused private properties, functions, callbacks, struct fields and imports
+    #[arg(long, action)]
+    warn_unused: bool,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -341,7 +345,7 @@ fn main_loop(connection: Connection, init_param: InitializeParams, cli_args: Cli
     preview::set_server_notifier(server_notifier.clone());
 
     let server_notifier_ = server_notifier.clone();
-    let compiler_config = CompilerConfiguration {
+    let mut compiler_config = CompilerConfiguration {
         style: Some(if cli_args.style.is_empty() { "native".into() } else { cli_args.style }),
         include_paths: cli_args.include_paths,
         library_paths: cli_args
@@ -372,6 +376,9 @@ fn main_loop(connection: Connection, init_param: InitializeParams, cli_args: Cli
         })),
         ..Default::default()
     };
+    if cli_args.warn_unused {
+        compiler_config.warn_unused = true;
+    }
 
     let ctx = Rc::new(Context {
         document_cache: RefCell::new(crate::common::DocumentCache::new(compiler_config)),
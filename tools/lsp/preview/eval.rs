@@ -192,6 +192,25 @@ fn eval_expression(
                 },
             )),
         )),
+        Expression::ConicGradient { from_angle, center_x, center_y, stops } => {
+            let mut eval_f32 = |e| eval_expression(e, local_context).try_into().unwrap_or_default();
+            let (from_angle, center_x, center_y) =
+                (eval_f32(from_angle), eval_f32(center_x), eval_f32(center_y));
+            Value::Brush(slint::Brush::ConicGradient(
+                i_slint_core::graphics::ConicGradientBrush::new(
+                    from_angle,
+                    center_x,
+                    center_y,
+                    stops.iter().map(|(color, stop)| {
+                        let color =
+                            eval_expression(color, local_context).try_into().unwrap_or_default();
+                        let position =
+                            eval_expression(stop, local_context).try_into().unwrap_or_default();
+                        i_slint_core::graphics::GradientStop { color, position }
+                    }),
+                ),
+            ))
+        }
         Expression::EnumerationValue(value) => {
             Value::EnumerationValue(value.enumeration.name.to_string(), value.to_string())
         }
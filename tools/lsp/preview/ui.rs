@@ -657,6 +657,27 @@ fn map_color(
                         ..Default::default()
                     });
                 }
+                // The brush editor can't move the center, so only centered conic gradients are editable
+                slint::Brush::ConicGradient(cg) if cg.center() == (0.5, 0.5).into() => {
+                    mapping.headers.push(mapping.name_prefix.clone());
+                    mapping.current_values.push(PropertyValue {
+                        display_string: SharedString::from("Conic Gradient"),
+                        kind: PropertyValueKind::Brush,
+                        value_kind: PropertyValueKind::Brush,
+                        brush_kind: BrushKind::Conic,
+                        value_float: cg.from_angle(),
+                        value_brush: slint::Brush::ConicGradient(cg.clone()),
+                        gradient_stops: Rc::new(VecModel::from(
+                            cg.stops()
+                                .map(|gs| GradientStop { color: gs.color, position: gs.position })
+                                .collect::<Vec<_>>(),
+                        ))
+                        .into(),
+                        accessor_path: mapping.name_prefix.clone(),
+                        code: get_code(value),
+                        ..Default::default()
+                    });
+                }
                 _ => {
                     mapping.headers.push(mapping.name_prefix.clone());
                     mapping.current_values.push(PropertyValue {
@@ -100,6 +100,9 @@ fn stops_as_string(stops: slint::ModelRc<ui::GradientStop>) -> String {
         ui::BrushKind::Radial => {
             format!("@radial-gradient(circle{})", stops_as_string(stops)).into()
         }
+        ui::BrushKind::Conic => {
+            format!("@conic-gradient(from {angle}deg{})", stops_as_string(stops)).into()
+        }
     }
 }
 
@@ -131,6 +134,9 @@ pub fn create_brush(
         ui::BrushKind::Radial => slint::Brush::RadialGradient(
             i_slint_core::graphics::RadialGradientBrush::new_circle(stops.drain(..)),
         ),
+        ui::BrushKind::Conic => slint::Brush::ConicGradient(
+            i_slint_core::graphics::ConicGradientBrush::new(angle, 0.5, 0.5, stops.drain(..)),
+        ),
     }
 }
 
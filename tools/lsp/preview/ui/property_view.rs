@@ -1029,6 +1029,22 @@ fn test_property_brush() {
         assert_eq!(result.value_kind, ui::PropertyValueKind::Brush);
         assert_eq!(result.kind, ui::PropertyValueKind::Code);
         assert!(matches!(result.brush_kind, ui::BrushKind::Radial));
+
+        let result = property_conversion_test(
+            r#"export component Test { in property <brush> test1: @conic-gradient(from 90deg, #f00 0%, #0f0 50%, #00f 100%); }"#,
+            1,
+        );
+        assert_eq!(result.value_kind, ui::PropertyValueKind::Brush);
+        assert_eq!(result.kind, ui::PropertyValueKind::Brush);
+        assert!(matches!(result.brush_kind, ui::BrushKind::Conic));
+        assert_eq!(result.value_float, 90.0);
+        assert_eq!(result.gradient_stops.row_count(), 3);
+
+        let result = property_conversion_test(
+            r#"export component Test { in property <brush> test1: @conic-gradient(from 90deg at 25% 50%, #f00 0%, #0f0 50%, #00f 100%); }"#,
+            1,
+        );
+        assert_eq!(result.kind, ui::PropertyValueKind::Code);
     }
 
     #[test]
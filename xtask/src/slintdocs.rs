@@ -169,6 +169,9 @@ macro_rules! map_type {
         (Image) => {
             "image"
         };
+        (LogicalPosition) => {
+            "Point"
+        };
         ($pub_type:ident) => {
             stringify!($pub_type)
         };